@@ -0,0 +1,44 @@
+.intel_syntax noprefix
+.global az_args
+.global az_env
+.text
+
+# list<str>: ilk 8 bayt uzunluq, ardınca yazıların ünvanları.
+# Kernelin stekdə saxladığı argc və argv bu quruluşla üst-üstə düşür.
+az_args:
+    mov rax, qword ptr [rip + az_argv_base]
+    ret
+
+# rdi: dəyişənin adı. Nəticə envp-dəki yazının içinə göstəricidir, onu azad etmək olmaz.
+# Tapılmadıqda boş yazı qaytarılır.
+az_env:
+    mov rsi, qword ptr [rip + az_envp]
+.Lenv_next:
+    mov rdx, qword ptr [rsi]
+    test rdx, rdx
+    jz .Lenv_missing
+    mov rcx, rdi
+.Lenv_compare:
+    mov al, byte ptr [rcx]
+    test al, al
+    jz .Lenv_name_end
+    cmp al, byte ptr [rdx]
+    jne .Lenv_skip
+    inc rcx
+    inc rdx
+    jmp .Lenv_compare
+.Lenv_name_end:
+    cmp byte ptr [rdx], '='
+    jne .Lenv_skip
+    lea rax, [rdx + 1]
+    ret
+.Lenv_skip:
+    add rsi, 8
+    jmp .Lenv_next
+.Lenv_missing:
+    lea rax, [rip + az_env_empty]
+    ret
+
+.section .rodata
+az_env_empty:
+    .byte 0
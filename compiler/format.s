@@ -0,0 +1,541 @@
+.intel_syntax noprefix
+.global az_fmt_begin
+.global az_fmt_end
+.global az_fmt_str
+.global az_fmt_char
+.global az_fmt_bool
+.global az_fmt_int
+.global az_fmt_float
+
+# Şablon yazıları brk ilə böyüyən yaddaşda bir-birinin ardınca qurulur:
+# az_fmt_begin yeni yazıya başlayır, az_fmt_* hissələri əlavə edir,
+# az_fmt_end isə sonuna 0 yazıb yazının ünvanını qaytarır. Yazılar azad edilmir.
+#
+# Bütün az_fmt_* funksiyaları en, düzləndirmə (1 sola, 2 sağa, 3 ortaya) və
+# doldurma simvolunu qəbul edir. En baytla yox, simvolla ölçülür.
+
+.bss
+.balign 8
+az_fmt_start:
+    .skip 8
+az_fmt_top:
+    .skip 8
+az_fmt_limit:
+    .skip 8
+
+.section .rodata
+az_fmt_true:
+    .ascii "true"
+az_fmt_false:
+    .ascii "false"
+az_fmt_minus:
+    .ascii "-"
+az_fmt_nan:
+    .ascii "NaN"
+az_fmt_inf:
+    .ascii "-inf"
+.balign 8
+az_fmt_half:
+    .double 0.5
+az_fmt_ten:
+    .double 10.0
+az_fmt_big:
+    .double 1e18
+
+.text
+
+az_fmt_begin:
+    mov rax, qword ptr [rip + az_fmt_top]
+    test rax, rax
+    jnz .Lbegin_ready
+    mov rax, 12
+    xor edi, edi
+    syscall
+    mov qword ptr [rip + az_fmt_top], rax
+    mov qword ptr [rip + az_fmt_limit], rax
+.Lbegin_ready:
+    mov qword ptr [rip + az_fmt_start], rax
+    ret
+
+az_fmt_end:
+    mov edi, 1
+    call az_fmt_reserve
+    mov byte ptr [rax], 0
+    inc rax
+    mov qword ptr [rip + az_fmt_top], rax
+    mov rax, qword ptr [rip + az_fmt_start]
+    ret
+
+# rdi: lazım olan bayt sayı. rax: yazılacaq yer. Yer çatmadıqda brk 64 KiB artıq böyüdülür.
+az_fmt_reserve:
+    mov rax, qword ptr [rip + az_fmt_top]
+    lea rdx, [rax + rdi]
+    cmp rdx, qword ptr [rip + az_fmt_limit]
+    jbe .Lreserve_done
+    lea rdi, [rdx + 65536]
+    push rdi
+    mov rax, 12
+    syscall
+    pop rdi
+    cmp rax, rdi
+    jb .Lreserve_failed
+    mov qword ptr [rip + az_fmt_limit], rax
+    mov rax, qword ptr [rip + az_fmt_top]
+.Lreserve_done:
+    ret
+.Lreserve_failed:
+    mov rax, 60
+    mov rdi, 102
+    syscall
+
+# rdi: baytlar, rsi: onların sayı
+az_fmt_put:
+    push r12
+    push r13
+    sub rsp, 8
+    mov r12, rdi
+    mov r13, rsi
+    mov rdi, rsi
+    call az_fmt_reserve
+    mov rdi, rax
+    mov rsi, r12
+    mov rcx, r13
+    rep movsb
+    mov qword ptr [rip + az_fmt_top], rdi
+    add rsp, 8
+    pop r13
+    pop r12
+    ret
+
+# edi: simvol, rsi: 4 baytlıq yer. rax: UTF-8 baytlarının sayı. Yalnız rax dəyişir.
+az_fmt_utf8:
+    cmp edi, 0x80
+    jae .Lutf8_two
+    mov byte ptr [rsi], dil
+    mov eax, 1
+    ret
+.Lutf8_two:
+    cmp edi, 0x800
+    jae .Lutf8_three
+    mov eax, edi
+    shr eax, 6
+    or eax, 0xC0
+    mov byte ptr [rsi], al
+    mov eax, edi
+    and eax, 0x3F
+    or eax, 0x80
+    mov byte ptr [rsi + 1], al
+    mov eax, 2
+    ret
+.Lutf8_three:
+    cmp edi, 0x10000
+    jae .Lutf8_four
+    mov eax, edi
+    shr eax, 12
+    or eax, 0xE0
+    mov byte ptr [rsi], al
+    mov eax, edi
+    shr eax, 6
+    and eax, 0x3F
+    or eax, 0x80
+    mov byte ptr [rsi + 1], al
+    mov eax, edi
+    and eax, 0x3F
+    or eax, 0x80
+    mov byte ptr [rsi + 2], al
+    mov eax, 3
+    ret
+.Lutf8_four:
+    mov eax, edi
+    shr eax, 18
+    or eax, 0xF0
+    mov byte ptr [rsi], al
+    mov eax, edi
+    shr eax, 12
+    and eax, 0x3F
+    or eax, 0x80
+    mov byte ptr [rsi + 1], al
+    mov eax, edi
+    shr eax, 6
+    and eax, 0x3F
+    or eax, 0x80
+    mov byte ptr [rsi + 2], al
+    mov eax, edi
+    and eax, 0x3F
+    or eax, 0x80
+    mov byte ptr [rsi + 3], al
+    mov eax, 4
+    ret
+
+# edi: doldurma simvolu, esi: neçə dəfə
+az_fmt_fill:
+    push rbx
+    push r12
+    sub rsp, 8
+    mov ebx, esi
+    mov rsi, rsp
+    call az_fmt_utf8
+    mov r12, rax
+.Lfill_next:
+    test ebx, ebx
+    jle .Lfill_done
+    mov rdi, rsp
+    mov rsi, r12
+    call az_fmt_put
+    dec ebx
+    jmp .Lfill_next
+.Lfill_done:
+    add rsp, 8
+    pop r12
+    pop rbx
+    ret
+
+# rdi: mətn, rsi: bayt sayı, edx: en, ecx: düzləndirmə, r8d: doldurma simvolu
+az_fmt_emit:
+    push rbx
+    push r12
+    push r13
+    push r14
+    push r15
+    mov r12, rdi
+    mov r13, rsi
+    mov r15d, r8d
+    # UTF-8 davam baytları (10xxxxxx) simvol sayılmır.
+    xor eax, eax
+    xor r9d, r9d
+.Lemit_count:
+    cmp r9, r13
+    jae .Lemit_counted
+    movzx r10d, byte ptr [r12 + r9]
+    and r10d, 0xC0
+    cmp r10d, 0x80
+    je .Lemit_continuation
+    inc eax
+.Lemit_continuation:
+    inc r9
+    jmp .Lemit_count
+.Lemit_counted:
+    xor r14d, r14d
+    xor ebx, ebx
+    cmp edx, eax
+    jbe .Lemit_body
+    sub edx, eax
+    cmp ecx, 2
+    je .Lemit_right
+    cmp ecx, 3
+    je .Lemit_center
+    mov ebx, edx
+    jmp .Lemit_body
+.Lemit_right:
+    mov r14d, edx
+    jmp .Lemit_body
+.Lemit_center:
+    mov r14d, edx
+    shr r14d, 1
+    mov ebx, edx
+    sub ebx, r14d
+.Lemit_body:
+    mov edi, r15d
+    mov esi, r14d
+    call az_fmt_fill
+    mov rdi, r12
+    mov rsi, r13
+    call az_fmt_put
+    mov edi, r15d
+    mov esi, ebx
+    call az_fmt_fill
+    pop r15
+    pop r14
+    pop r13
+    pop r12
+    pop rbx
+    ret
+
+# rdi: işarəsiz rəqəmlər (önündə bir boş bayt olmalıdır), rsi: uzunluq, eax: 1 mənfi ədəd üçün,
+# edx: en, ecx: düzləndirmə, r8d: doldurma, r9d: bit 0 enin işarədən sonra sıfırlarla doldurulması
+az_fmt_number:
+    test r9d, 1
+    jnz .Lnumber_zero
+    test eax, eax
+    jz az_fmt_emit
+    dec rdi
+    mov byte ptr [rdi], '-'
+    inc rsi
+    jmp az_fmt_emit
+.Lnumber_zero:
+    push rbx
+    push r12
+    push r13
+    mov r12, rdi
+    mov r13, rsi
+    mov ebx, edx
+    sub ebx, esi
+    test eax, eax
+    jz .Lnumber_pad
+    dec ebx
+    lea rdi, [rip + az_fmt_minus]
+    mov esi, 1
+    call az_fmt_put
+.Lnumber_pad:
+    mov edi, '0'
+    mov esi, ebx
+    call az_fmt_fill
+    mov rdi, r12
+    mov rsi, r13
+    call az_fmt_put
+    pop r13
+    pop r12
+    pop rbx
+    ret
+
+# rdi: yazı, esi: en, edx: düzləndirmə, ecx: doldurma
+az_fmt_str:
+    mov r8d, ecx
+    mov ecx, edx
+    mov edx, esi
+    xor esi, esi
+.Lstr_length:
+    cmp byte ptr [rdi + rsi], 0
+    je az_fmt_emit
+    inc rsi
+    jmp .Lstr_length
+
+# edi: simvol, esi: en, edx: düzləndirmə, ecx: doldurma
+az_fmt_char:
+    sub rsp, 24
+    mov r8d, ecx
+    mov r9d, esi
+    mov r10d, edx
+    mov rsi, rsp
+    call az_fmt_utf8
+    mov rdi, rsp
+    mov rsi, rax
+    mov edx, r9d
+    mov ecx, r10d
+    call az_fmt_emit
+    add rsp, 24
+    ret
+
+# edi: 0 və ya 1, esi: en, edx: düzləndirmə, ecx: doldurma
+az_fmt_bool:
+    mov r8d, ecx
+    mov ecx, edx
+    mov edx, esi
+    test edi, edi
+    jz .Lbool_false
+    lea rdi, [rip + az_fmt_true]
+    mov esi, 4
+    jmp az_fmt_emit
+.Lbool_false:
+    lea rdi, [rip + az_fmt_false]
+    mov esi, 5
+    jmp az_fmt_emit
+
+# rdi: ədəd (64 bit), esi: en, edx: düzləndirmə, ecx: doldurma, r8d: say sistemi,
+# r9d: bit 0 sıfırla doldurma, bit 1 böyük hərflər, bit 2 işarəsiz
+az_fmt_int:
+    push rbx
+    push r12
+    push r13
+    push r14
+    push r15
+    sub rsp, 80
+    mov r12d, esi
+    mov r13d, edx
+    mov r14d, ecx
+    mov r15d, r9d
+    mov rax, rdi
+    xor ebx, ebx
+    test r15d, 4
+    jnz .Lint_digits
+    test rax, rax
+    jns .Lint_digits
+    neg rax
+    mov ebx, 1
+.Lint_digits:
+    lea rdi, [rsp + 80]
+    mov ecx, r8d
+.Lint_next:
+    xor edx, edx
+    div rcx
+    add edx, '0'
+    cmp edx, '9'
+    jbe .Lint_store
+    add edx, 'a' - '9' - 1
+    test r15d, 2
+    jz .Lint_store
+    sub edx, 'a' - 'A'
+.Lint_store:
+    dec rdi
+    mov byte ptr [rdi], dl
+    test rax, rax
+    jnz .Lint_next
+    lea rsi, [rsp + 80]
+    sub rsi, rdi
+    mov eax, ebx
+    mov edx, r12d
+    mov ecx, r13d
+    mov r8d, r14d
+    mov r9d, r15d
+    call az_fmt_number
+    add rsp, 80
+    pop r15
+    pop r14
+    pop r13
+    pop r12
+    pop rbx
+    ret
+
+# xmm0: ədəd, edi: en, esi: düzləndirmə, edx: doldurma, ecx: vergüldən sonra rəqəm sayı
+# (-1: ən çox 6, sondakı sıfırlar atılır), r8d: bit 0 sıfırla doldurma
+az_fmt_float:
+    push rbx
+    push r12
+    push r13
+    push r14
+    push r15
+    sub rsp, 416
+    mov r12d, edi
+    mov r13d, esi
+    mov r14d, edx
+    mov r15d, r8d
+    movq rax, xmm0
+    btr rax, 63
+    setc bl
+    movzx ebx, bl
+    movq xmm0, rax
+    ucomisd xmm0, xmm0
+    jp .Lfloat_nan
+    mov rdx, 0x7FF0000000000000
+    cmp rax, rdx
+    je .Lfloat_inf
+
+    mov r9d, ecx
+    xor r10d, r10d
+    cmp ecx, -1
+    jne .Lfloat_precision
+    mov r9d, 6
+    mov r10d, 1
+.Lfloat_precision:
+    cmp r9d, 17
+    jbe .Lfloat_scale
+    mov r9d, 17
+.Lfloat_scale:
+    mov eax, 1
+    mov ecx, r9d
+.Lfloat_power:
+    test ecx, ecx
+    jz .Lfloat_split
+    imul rax, rax, 10
+    dec ecx
+    jmp .Lfloat_power
+.Lfloat_split:
+    mov r11, rax
+    # 1e18-dən böyük ədədlər 10-a bölünür, atılan rəqəmlər sıfır kimi yazılır.
+    mov qword ptr [rsp], 0
+    movsd xmm3, qword ptr [rip + az_fmt_big]
+.Lfloat_shrink:
+    ucomisd xmm0, xmm3
+    jb .Lfloat_whole
+    divsd xmm0, qword ptr [rip + az_fmt_ten]
+    inc qword ptr [rsp]
+    jmp .Lfloat_shrink
+.Lfloat_whole:
+    cvttsd2si r8, xmm0
+    xor esi, esi
+    cmp qword ptr [rsp], 0
+    jne .Lfloat_trim
+    cvtsi2sd xmm1, r8
+    subsd xmm0, xmm1
+    cvtsi2sd xmm2, r11
+    mulsd xmm0, xmm2
+    addsd xmm0, qword ptr [rip + az_fmt_half]
+    cvttsd2si rsi, xmm0
+    cmp rsi, r11
+    jb .Lfloat_trim
+    sub rsi, r11
+    inc r8
+.Lfloat_trim:
+    mov r11d, 10
+    test r10d, r10d
+    jz .Lfloat_render
+.Lfloat_trim_next:
+    cmp r9d, 1
+    jbe .Lfloat_render
+    mov rax, rsi
+    xor edx, edx
+    div r11
+    test rdx, rdx
+    jnz .Lfloat_render
+    mov rsi, rax
+    dec r9d
+    jmp .Lfloat_trim_next
+.Lfloat_render:
+    lea rdi, [rsp + 416]
+    test r9d, r9d
+    jz .Lfloat_zeros
+    mov ecx, r9d
+.Lfloat_fraction:
+    mov rax, rsi
+    xor edx, edx
+    div r11
+    mov rsi, rax
+    add dl, '0'
+    dec rdi
+    mov byte ptr [rdi], dl
+    dec ecx
+    jnz .Lfloat_fraction
+    dec rdi
+    mov byte ptr [rdi], '.'
+.Lfloat_zeros:
+    mov rcx, qword ptr [rsp]
+.Lfloat_zero_next:
+    test rcx, rcx
+    jz .Lfloat_integer
+    dec rdi
+    mov byte ptr [rdi], '0'
+    dec rcx
+    jmp .Lfloat_zero_next
+.Lfloat_integer:
+    mov rax, r8
+.Lfloat_digit:
+    xor edx, edx
+    div r11
+    add dl, '0'
+    dec rdi
+    mov byte ptr [rdi], dl
+    test rax, rax
+    jnz .Lfloat_digit
+    lea rsi, [rsp + 416]
+    sub rsi, rdi
+    mov eax, ebx
+    mov r9d, r15d
+    jmp .Lfloat_emit
+.Lfloat_nan:
+    lea rdi, [rip + az_fmt_nan]
+    mov esi, 3
+    xor eax, eax
+    xor r9d, r9d
+    jmp .Lfloat_emit
+.Lfloat_inf:
+    lea rdi, [rip + az_fmt_inf]
+    mov esi, 4
+    test ebx, ebx
+    jnz .Lfloat_special
+    inc rdi
+    dec esi
+.Lfloat_special:
+    xor eax, eax
+    xor r9d, r9d
+.Lfloat_emit:
+    mov edx, r12d
+    mov ecx, r13d
+    mov r8d, r14d
+    call az_fmt_number
+    add rsp, 416
+    pop r15
+    pop r14
+    pop r13
+    pop r12
+    pop rbx
+    ret
@@ -0,0 +1,116 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use file_system::{read_file, write_file};
+use transpiler::mangle::demangle;
+
+use crate::errors::{BackendError, CompilerError};
+
+/// Hər proqramla birlikdə linklənən runtime faylları.
+const RUNTIME: [(&str, &str); 6] = [
+    ("starter", include_str!("../starter.s")),
+    ("exit", include_str!("../exit.s")),
+    ("print", include_str!("../print.s")),
+    ("trap", include_str!("../trap.s")),
+    ("env", include_str!("../env.s")),
+    ("format", include_str!("../format.s")),
+];
+
+pub fn build_executable(
+    source: &Path,
+    ssa: String,
+    linker: &str,
+    link_files: &[String],
+) -> Result<(), CompilerError> {
+    let output = source.with_extension("");
+    let asm_file = compile_ssa(&output, ssa)?;
+    let object_file = output.with_extension("o");
+
+    run(
+        Command::new("as")
+            .arg(&asm_file)
+            .arg("-o")
+            .arg(&object_file),
+        "as",
+    )?;
+
+    let mut objects = vec![object_file];
+    for (name, code) in RUNTIME {
+        let asm = output.with_file_name(format!("azrt_{name}.s"));
+        let object = asm.with_extension("o");
+        write_file(&asm, code.to_string())?;
+        run(Command::new("as").arg(&asm).arg("-o").arg(&object), "as")?;
+        objects.push(object);
+    }
+    // `@link("exit.o")` kimi runtime-ın artıq verdiyi obyektlər ikinci dəfə linklənmir.
+    objects.extend(
+        link_files
+            .iter()
+            .map(PathBuf::from)
+            .filter(|file| !is_runtime_object(file)),
+    );
+
+    run(
+        Command::new(linker).args(&objects).arg("-o").arg(&output),
+        linker,
+    )
+}
+
+/// `--emit=asm`: yalnız `.s` faylını yaradır, kodlanmış adları olan sətirlərə
+/// AzLang adlarını şərh kimi əlavə edir.
+pub fn emit_assembly(source: &Path, ssa: String) -> Result<(), CompilerError> {
+    let asm_file = compile_ssa(&source.with_extension(""), ssa)?;
+    let asm = read_file(&asm_file.to_string_lossy())?;
+    write_file(&asm_file, annotate_assembly(&asm))?;
+    Ok(())
+}
+
+fn compile_ssa(output: &Path, ssa: String) -> Result<PathBuf, CompilerError> {
+    let ssa_file = output.with_extension("ssa");
+    let asm_file = output.with_extension("s");
+    write_file(&ssa_file, ssa)?;
+    run(
+        Command::new("qbe").arg("-o").arg(&asm_file).arg(&ssa_file),
+        "qbe",
+    )?;
+    Ok(asm_file)
+}
+
+pub(crate) fn annotate_assembly(asm: &str) -> String {
+    let mut out = String::with_capacity(asm.len());
+    for line in asm.lines() {
+        out.push_str(line);
+        let names: Vec<String> = line
+            .split(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_'))
+            .filter_map(demangle)
+            .collect();
+        if !names.is_empty() {
+            out.push_str(" # ");
+            out.push_str(&names.join(", "));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn is_runtime_object(file: &Path) -> bool {
+    file.extension().is_some_and(|ext| ext == "o")
+        && RUNTIME
+            .iter()
+            .any(|(name, _)| file.file_stem().is_some_and(|stem| stem == *name))
+}
+
+fn run(command: &mut Command, tool: &str) -> Result<(), CompilerError> {
+    let status = command
+        .status()
+        .map_err(|_| CompilerError::Backend(BackendError::ToolFailed(tool.to_string())))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(CompilerError::Backend(BackendError::ToolFailed(
+            tool.to_string(),
+        )))
+    }
+}
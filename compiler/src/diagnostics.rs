@@ -0,0 +1,334 @@
+use std::{
+    fmt::Write,
+    io::{self, IsTerminal},
+    rc::Rc,
+};
+
+use locale::t;
+use parser::ast::SourceSpan;
+use tokenizer::source::{SourceFile, get_file};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    /// Başlıqda göstərilən ad, cari dildə.
+    pub fn name(self) -> String {
+        match self {
+            Severity::Error => t!("severity.error"),
+            Severity::Warning => t!("severity.warning"),
+        }
+    }
+}
+
+/// Mənbədə işarələnən yer. Əsas etiket `^`, köməkçi etiket `-` ilə çəkilir.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    pub span: SourceSpan,
+    pub message: String,
+    pub primary: bool,
+}
+
+/// Avtomatik tətbiq oluna bilən düzəliş: `span` mətnini `replacement` ilə əvəz etmək.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub span: SourceSpan,
+    pub replacement: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Dəyişməz xəta kodu, başlıqda `xəta[AZ0209]` kimi göstərilir.
+    pub code: Option<&'static str>,
+    pub message: String,
+    pub labels: Vec<Label>,
+    pub notes: Vec<String>,
+    pub help: Vec<String>,
+    /// Mətnlə göstərildikdə `kömək` sətri kimi çıxır.
+    pub suggestions: Vec<Suggestion>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            code: None,
+            message: message.into(),
+            labels: Vec::new(),
+            notes: Vec::new(),
+            help: Vec::new(),
+            suggestions: Vec::new(),
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    pub fn with_code(mut self, code: Option<&'static str>) -> Self {
+        self.code = code;
+        self
+    }
+
+    pub fn with_primary(mut self, span: &SourceSpan, message: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            span: span.clone(),
+            message: message.into(),
+            primary: true,
+        });
+        self
+    }
+
+    pub fn with_secondary(mut self, span: &SourceSpan, message: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            span: span.clone(),
+            message: message.into(),
+            primary: false,
+        });
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help.push(help.into());
+        self
+    }
+
+    pub fn with_suggestion(
+        mut self,
+        span: &SourceSpan,
+        replacement: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        self.suggestions.push(Suggestion {
+            span: span.clone(),
+            replacement: replacement.into(),
+            message: message.into(),
+        });
+        self
+    }
+
+    /// Diaqnostikanı mənbə parçaları ilə birlikdə mətnə çevirir.
+    pub fn render(&self, color: bool) -> String {
+        let style = Style { color };
+        let mut out = String::new();
+        let header = match self.code {
+            Some(code) => format!("{}[{code}]", self.severity.name()),
+            None => self.severity.name(),
+        };
+        let severity = match self.severity {
+            Severity::Error => style.error(&header),
+            Severity::Warning => style.warning(&header),
+        };
+        let _ = writeln!(
+            out,
+            "{severity}{}",
+            style.bold(&format!(": {}", self.message))
+        );
+
+        let snippets = self.snippets();
+        let width = snippets
+            .iter()
+            .flat_map(|snippet| snippet.lines.iter().map(|(line, _)| line.to_string().len()))
+            .max()
+            .unwrap_or(1);
+        let pad = " ".repeat(width);
+
+        for (index, snippet) in snippets.iter().enumerate() {
+            let file = &snippet.file;
+            let location = file.location(snippet.header.span.start);
+            let arrow = if index == 0 { "-->" } else { ":::" };
+            let _ = writeln!(
+                out,
+                "{pad}{} {}:{}:{}",
+                style.gutter(arrow),
+                file.name,
+                location.line,
+                location.column
+            );
+            let _ = writeln!(out, "{pad} {}", style.gutter("|"));
+            let mut previous = snippet.lines[0].0;
+            for (line, labels) in &snippet.lines {
+                if *line > previous + 1 {
+                    let _ = writeln!(out, "{}", style.gutter("..."));
+                }
+                previous = *line;
+                let text = file.line_text(*line).unwrap_or_default();
+                let _ = writeln!(
+                    out,
+                    "{} {} {text}",
+                    style.gutter(&format!("{line:>width$}")),
+                    style.gutter("|")
+                );
+                for label in labels {
+                    let (column, length) = underline(file, text, &label.span);
+                    let marker = if label.primary { "^" } else { "-" };
+                    let mut mark = marker.repeat(length);
+                    if !label.message.is_empty() {
+                        mark = format!("{mark} {}", label.message);
+                    }
+                    let mark = match (label.primary, self.severity) {
+                        (false, _) => style.secondary(&mark),
+                        (true, Severity::Error) => style.error(&mark),
+                        (true, Severity::Warning) => style.warning(&mark),
+                    };
+                    let _ = writeln!(
+                        out,
+                        "{pad} {} {}{mark}",
+                        style.gutter("|"),
+                        " ".repeat(column)
+                    );
+                }
+            }
+        }
+
+        let has_footer =
+            !self.notes.is_empty() || !self.help.is_empty() || !self.suggestions.is_empty();
+        if !snippets.is_empty() && has_footer {
+            let _ = writeln!(out, "{pad} {}", style.gutter("|"));
+        }
+        for note in &self.notes {
+            let _ = writeln!(
+                out,
+                "{pad} {} {}: {note}",
+                style.gutter("="),
+                style.bold(&t!("diagnostic.note"))
+            );
+        }
+        let suggestions = self.suggestions.iter().map(|s| &s.message);
+        for help in self.help.iter().chain(suggestions) {
+            let _ = writeln!(
+                out,
+                "{pad} {} {}: {help}",
+                style.gutter("="),
+                style.bold(&t!("diagnostic.help"))
+            );
+        }
+        out
+    }
+
+    /// Etiketləri fayl və sətirə görə qruplaşdırır. Əsas etiketin faylı birinci gəlir,
+    /// mənbə xəritəsində olmayan span-lar isə atılır.
+    fn snippets(&self) -> Vec<Snippet<'_>> {
+        let mut labels: Vec<&Label> = self.labels.iter().collect();
+        labels.sort_by_key(|label| !label.primary);
+        let mut snippets: Vec<Snippet> = Vec::new();
+        for label in labels {
+            let Some(file) = get_file(label.span.file) else {
+                continue;
+            };
+            let line = file.location(label.span.start).line;
+            let snippet = match snippets.iter().position(|s| s.file.id == file.id) {
+                Some(index) => &mut snippets[index],
+                None => {
+                    snippets.push(Snippet {
+                        file,
+                        header: label,
+                        lines: Vec::new(),
+                    });
+                    snippets.last_mut().unwrap()
+                }
+            };
+            match snippet.lines.iter_mut().find(|(l, _)| *l == line) {
+                Some((_, group)) => group.push(label),
+                None => snippet.lines.push((line, vec![label])),
+            }
+        }
+        for snippet in &mut snippets {
+            snippet.lines.sort_by_key(|(line, _)| *line);
+            for (_, group) in &mut snippet.lines {
+                group.sort_by_key(|label| label.span.start);
+            }
+        }
+        snippets
+    }
+}
+
+/// Bir faylın göstəriləcək sətirləri. `header` başlıqdakı yeri verir.
+struct Snippet<'a> {
+    file: Rc<SourceFile>,
+    header: &'a Label,
+    lines: Vec<(u32, Vec<&'a Label>)>,
+}
+
+/// Etiketin sətirdəki sütunu və uzunluğu, simvollarla. Çox sətirli span-lar
+/// birinci sətrin sonunda kəsilir.
+fn underline(file: &SourceFile, text: &str, span: &SourceSpan) -> (usize, usize) {
+    let start = file.location(span.start);
+    let column = start.column as usize - 1;
+    let end = file.location(span.end);
+    let length = if end.line == start.line {
+        end.column.saturating_sub(start.column) as usize
+    } else {
+        text.chars().count().saturating_sub(column)
+    };
+    (column, length.max(1))
+}
+
+/// `--color` seçimi. `Auto` terminal olmayan çıxışda və `NO_COLOR` təyin edildikdə rəngi söndürür.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+                    && io::stdout().is_terminal()
+            }
+        }
+    }
+}
+
+/// `--message-format` seçimi: insan üçün mətn və ya hər sətirdə bir JSON obyekti.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MessageFormat {
+    #[default]
+    Human,
+    Json,
+}
+
+struct Style {
+    color: bool,
+}
+
+impl Style {
+    fn paint(&self, code: &str, text: &str) -> String {
+        if self.color {
+            format!("\x1b[{code}m{text}\x1b[0m")
+        } else {
+            text.to_string()
+        }
+    }
+    fn error(&self, text: &str) -> String {
+        self.paint("1;31", text)
+    }
+    fn warning(&self, text: &str) -> String {
+        self.paint("1;33", text)
+    }
+    fn secondary(&self, text: &str) -> String {
+        self.paint("1;34", text)
+    }
+    fn gutter(&self, text: &str) -> String {
+        self.paint("1;34", text)
+    }
+    fn bold(&self, text: &str) -> String {
+        self.paint("1", text)
+    }
+}
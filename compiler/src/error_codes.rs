@@ -0,0 +1,25 @@
+macro_rules! explanations {
+    ($($code:literal),* $(,)?) => {
+        &[$(($code, include_str!(concat!("error_codes/", $code, ".md")))),*]
+    };
+}
+
+/// Hər kod və onun `error_codes/` qovluğundakı izahı.
+pub const EXPLANATIONS: &[(&str, &str)] = explanations![
+    "AZ0001", "AZ0003", "AZ0007", "AZ0008", "AZ0009", "AZ0010", "AZ0011", "AZ0012", "AZ0013",
+    "AZ0014", "AZ0015", "AZ0016", "AZ0017", "AZ0018", "AZ0019", "AZ0020", "AZ0021", "AZ0022",
+    "AZ0023", "AZ0101", "AZ0102", "AZ0103", "AZ0104", "AZ0105", "AZ0108", "AZ0109", "AZ0110",
+    "AZ0111", "AZ0112", "AZ0117", "AZ0125", "AZ0201", "AZ0202", "AZ0203", "AZ0204", "AZ0205",
+    "AZ0207", "AZ0208", "AZ0209", "AZ0211", "AZ0212", "AZ0213", "AZ0222", "AZ0226", "AZ0227",
+    "AZ0230", "AZ0231", "AZ0232", "AZ0233", "AZ0234", "AZ0235", "AZ0236", "AZ0301", "AZ0302",
+    "AZ0303", "AZ0304",
+];
+
+/// Kodun izahını qaytarır; kod böyük-kiçik hərfdən asılı olmadan axtarılır.
+pub fn explain(code: &str) -> Option<&'static str> {
+    let code = code.trim().to_ascii_uppercase();
+    EXPLANATIONS
+        .iter()
+        .find(|(known, _)| *known == code)
+        .map(|(_, text)| *text)
+}
@@ -1,19 +1,38 @@
 use std::fmt::Display;
 
 use file_system::errors::FileSystemError;
-use parser::errors::ParserError;
-use validator::errors::ValidatorError;
+use locale::t;
+use parser::{ast::SourceSpan, errors::ParserError};
+use tokenizer::{errors::LexerError, source::get_file, tokens::Token};
+use transpiler::errors::TranspileError;
+use validator::{errors::ValidatorError, lint::Lint};
+
+use crate::diagnostics::{ColorChoice, Diagnostic, Severity};
 
 #[derive(Debug, PartialEq)]
 pub enum BackendError {
     Qbe,
     BinUtils,
+    Transpile(TranspileError),
+    ToolFailed(String),
+}
+impl BackendError {
+    /// Dəyişməz xəta kodu, bax: `azcli explain`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            BackendError::Qbe => "AZ0301",
+            BackendError::BinUtils => "AZ0302",
+            BackendError::Transpile(_) => "AZ0303",
+            BackendError::ToolFailed(_) => "AZ0304",
+        }
+    }
 }
 impl Display for BackendError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            BackendError::Qbe => writeln!(f, "QBE doesn't installed"),
-            BackendError::BinUtils => writeln!(f, "binutils doesn't installed"),
+            BackendError::Qbe | BackendError::BinUtils => writeln!(f, "{}", t!(self.code())),
+            BackendError::Transpile(e) => writeln!(f, "{}", t!(self.code(), error = e)),
+            BackendError::ToolFailed(tool) => writeln!(f, "{}", t!(self.code(), tool = tool)),
         }
     }
 }
@@ -26,14 +45,62 @@ pub enum CompilerError {
     Backend(BackendError),
 }
 impl CompilerError {
-    pub fn display(&self) {
+    pub fn display(&self, color: ColorChoice) {
+        print!("{}", self.render(color.enabled()));
+    }
+    /// Hər diaqnostika üçün bir sətirlik JSON obyekti; ümumi say və izah sətirləri yoxdur.
+    pub fn render_json(&self) -> String {
+        self.diagnostics()
+            .iter()
+            .map(|diagnostic| diagnostic.to_json() + "\n")
+            .collect()
+    }
+    /// Bütün diaqnostikaları boş sətirlə ayırır. Mənbə xətalarından sonra ümumi say göstərilir.
+    pub fn render(&self, color: bool) -> String {
+        let diagnostics = self.diagnostics();
+        let mut out = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.render(color))
+            .collect::<Vec<_>>()
+            .join("\n");
+        if matches!(self, CompilerError::Parser(_) | CompilerError::Validator(_)) {
+            let summary = locale::lang().tr_count("summary.errors", diagnostics.len());
+            out.push('\n');
+            out.push_str(&Diagnostic::error(summary).render(color));
+        }
+        let mut codes: Vec<&str> = Vec::new();
+        for code in diagnostics.iter().filter_map(|diagnostic| diagnostic.code) {
+            if !codes.contains(&code) {
+                codes.push(code);
+            }
+        }
+        if codes.len() > 1 {
+            out.push_str(&t!("explain.codes", codes = codes.join(", ")));
+            out.push('\n');
+        }
+        if let Some(code) = codes.first() {
+            out.push_str(&t!("explain.hint", code = code));
+            out.push('\n');
+        }
+        out
+    }
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
         match self {
-            CompilerError::IO(e) => {
-                println!("\x1b[1;31m[Big Brother]:\x1b[0m {} ", e);
+            CompilerError::IO(e) => vec![Diagnostic::error(e.to_string())],
+            CompilerError::Parser(e) => match e {
+                ParserError::Multiple(errors) => errors.iter().map(parser_diagnostic).collect(),
+                e => vec![parser_diagnostic(e)],
+            },
+            CompilerError::Validator(e) => match e {
+                ValidatorError::Multiple(errors) => errors
+                    .iter()
+                    .map(|e| validator_diagnostic(e, Severity::Error))
+                    .collect(),
+                e => vec![validator_diagnostic(e, Severity::Error)],
+            },
+            CompilerError::Backend(e) => {
+                vec![Diagnostic::error(e.to_string().trim_end()).with_code(Some(e.code()))]
             }
-            CompilerError::Parser(e) => println!("\x1b[31m[Big Brother]:\x1b[0m {}", e),
-            CompilerError::Validator(e) => println!("\x1b[33m[Validator]:\x1b[0m {}", e),
-            CompilerError::Backend(e) => println!("\x1b[34m[Compiler]:\x1b[0m {}", e),
         }
     }
     pub fn code(&self) -> i32 {
@@ -57,8 +124,143 @@ impl From<ValidatorError> for CompilerError {
         CompilerError::Validator(e)
     }
 }
+impl From<TranspileError> for CompilerError {
+    fn from(e: TranspileError) -> Self {
+        CompilerError::Backend(BackendError::Transpile(e))
+    }
+}
 impl From<ParserError> for CompilerError {
     fn from(e: ParserError) -> Self {
         CompilerError::Parser(e)
     }
 }
+
+fn parser_diagnostic(error: &ParserError) -> Diagnostic {
+    let diagnostic = Diagnostic::error(error.message()).with_code(error.code());
+    let Some(span) = error.span() else {
+        return diagnostic;
+    };
+    let diagnostic = diagnostic.with_primary(span, "");
+    match error {
+        ParserError::LexerError(LexerError::ConfusableCharacter(_, _, expected)) => {
+            let help = t!("help.replace-char", expected = expected);
+            diagnostic.with_suggestion(span, expected.to_string(), help)
+        }
+        ParserError::UnexpectedToken(_, Token::Identifier(word), similar) => {
+            did_you_mean(diagnostic, span, word, similar.as_deref())
+        }
+        _ => diagnostic,
+    }
+}
+
+/// Xəbərdarlıqları və onların sayını göstərir; xəbərdarlıq yoxdursa boş mətn qaytarır.
+pub fn render_warnings(warnings: &[ValidatorError], color: bool) -> String {
+    if warnings.is_empty() {
+        return String::new();
+    }
+    let mut out = String::new();
+    for warning in warnings {
+        out.push_str(&validator_diagnostic(warning, Severity::Warning).render(color));
+        out.push('\n');
+    }
+    let summary = locale::lang().tr_count("summary.warnings", warnings.len());
+    out.push_str(&Diagnostic::new(Severity::Warning, summary).render(color));
+    out
+}
+
+/// Xəbərdarlıqların JSON sətirləri; `render_warnings` kimi, amma ümumi say olmadan.
+pub fn render_warnings_json(warnings: &[ValidatorError]) -> String {
+    warnings
+        .iter()
+        .map(|warning| validator_diagnostic(warning, Severity::Warning).to_json() + "\n")
+        .collect()
+}
+
+/// `span` `keyword` sözü ilə başlayırsa, həmin sözün span-ı.
+fn keyword_span(span: &SourceSpan, keyword: &str) -> Option<SourceSpan> {
+    let file = get_file(span.file)?;
+    let end = span.start + keyword.len() as u32;
+    let text = file.text.get(span.start as usize..end as usize)?;
+    (text == keyword).then(|| SourceSpan::new(span.file, span.start, end))
+}
+
+/// `name` əvəzinə `similar` təklifi. Ad span-ın mətnində tapılırsa avtomatik düzəliş,
+/// tapılmırsa kömək mətni kimi əlavə olunur.
+fn did_you_mean(
+    diagnostic: Diagnostic,
+    span: &SourceSpan,
+    name: &str,
+    similar: Option<&str>,
+) -> Diagnostic {
+    let Some(similar) = similar else {
+        return diagnostic;
+    };
+    let message = t!("help.did-you-mean", name = similar);
+    match name_span(span, name) {
+        Some(name) => diagnostic.with_suggestion(&name, similar, message),
+        None => diagnostic.with_help(message),
+    }
+}
+
+/// `span` mətnində `name`-in ilk rast gəlindiyi yer.
+fn name_span(span: &SourceSpan, name: &str) -> Option<SourceSpan> {
+    let file = get_file(span.file)?;
+    let text = file.text.get(span.start as usize..span.end as usize)?;
+    let start = span.start + text.find(name)? as u32;
+    Some(SourceSpan::new(span.file, start, start + name.len() as u32))
+}
+
+fn validator_diagnostic(error: &ValidatorError, severity: Severity) -> Diagnostic {
+    let diagnostic = Diagnostic::new(severity, error.message()).with_code(error.code());
+    let Some(span) = error.span() else {
+        return diagnostic;
+    };
+    match error {
+        ValidatorError::FunctionAlreadyDefined(_, name, first) => {
+            let diagnostic = diagnostic.with_primary(span, t!("label.redefined"));
+            match first {
+                Some(first) => diagnostic.with_secondary(first, t!("label.first-defined")),
+                None => diagnostic.with_note(t!("note.builtin-function", name = name)),
+            }
+        }
+        ValidatorError::AssignmentToImmutableVariable(_, name) => diagnostic
+            .with_primary(span, t!("label.immutable-write"))
+            .with_help(t!("help.declare-var", name = name)),
+        ValidatorError::NeverChangedMuttableVariable(_, _) => {
+            let diagnostic = diagnostic.with_primary(span, "");
+            match keyword_span(span, "var") {
+                Some(var) => diagnostic.with_suggestion(&var, "const", t!("help.use-const")),
+                None => diagnostic.with_help(t!("help.use-const")),
+            }
+        }
+        ValidatorError::NotUsedVariable(_, _) => diagnostic
+            .with_primary(span, "")
+            .with_help(t!("help.remove-unused")),
+        ValidatorError::DeclTypeMismatch { found, .. }
+        | ValidatorError::AssignmentTypeMismatch { found, .. }
+        | ValidatorError::InvalidArgumentType { found, .. } => {
+            diagnostic.with_primary(span, t!("label.has-type", typ = found))
+        }
+        ValidatorError::TypeMismatch { found, .. } => {
+            diagnostic.with_primary(span, t!("label.has-type", typ = found))
+        }
+        ValidatorError::UnknownLint(_, _) => {
+            let names: Vec<_> = Lint::ALL.iter().map(|lint| lint.name()).collect();
+            diagnostic
+                .with_primary(span, "")
+                .with_help(t!("help.lint-names", names = names.join(", ")))
+        }
+        ValidatorError::UndefinedVariable(_, name, similar) => {
+            let diagnostic = diagnostic.with_primary(span, t!("label.undeclared"));
+            did_you_mean(diagnostic, span, name, similar.as_deref())
+        }
+        ValidatorError::UnknownType(_, name, similar)
+        | ValidatorError::FunctionNotFound(_, name, similar) => did_you_mean(
+            diagnostic.with_primary(span, ""),
+            span,
+            name,
+            similar.as_deref(),
+        ),
+        _ => diagnostic.with_primary(span, ""),
+    }
+}
@@ -0,0 +1,99 @@
+use parser::ast::SourceSpan;
+use tokenizer::source::get_file;
+
+use crate::diagnostics::{Diagnostic, Severity};
+
+/// JSON çıxışının sxem versiyası. Sahələr silindikdə və ya mənası dəyişdikdə artırılır.
+pub const SCHEMA_VERSION: u32 = 1;
+
+impl Diagnostic {
+    /// Diaqnostikanı bir sətirlik JSON obyektinə çevirir. Sxem `docs/message-format.md`-dədir.
+    pub fn to_json(&self) -> String {
+        let spans = self.labels.iter().filter_map(|label| {
+            let mut fields = location(&label.span)?;
+            fields.push(("primary", label.primary.to_string()));
+            let text = (!label.message.is_empty()).then_some(label.message.as_str());
+            fields.push(("label", optional_string(text)));
+            Some(object(&fields))
+        });
+        let suggestions = self.suggestions.iter().filter_map(|suggestion| {
+            let mut fields = location(&suggestion.span)?;
+            fields.push(("replacement", string(&suggestion.replacement)));
+            fields.push(("message", string(&suggestion.message)));
+            Some(object(&fields))
+        });
+        object(&[
+            ("version", SCHEMA_VERSION.to_string()),
+            ("code", optional_string(self.code)),
+            ("severity", string(self.severity.id())),
+            ("message", string(&self.message)),
+            ("spans", array(spans)),
+            ("notes", array(self.notes.iter().map(|note| string(note)))),
+            ("help", array(self.help.iter().map(|help| string(help)))),
+            ("suggestions", array(suggestions)),
+            ("rendered", string(&self.render(false))),
+        ])
+    }
+}
+
+impl Severity {
+    /// JSON-dakı dəyişməz ad; `--lang` seçimindən asılı deyil.
+    pub fn id(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+/// Span-ın fayl adı, bayt aralığı və `1`-dən başlayan sətir/sütun aralığı.
+/// Mənbə xəritəsində olmayan span-lar üçün `None`.
+fn location(span: &SourceSpan) -> Option<Vec<(&'static str, String)>> {
+    let file = get_file(span.file)?;
+    let start = file.location(span.start);
+    let end = file.location(span.end);
+    Some(vec![
+        ("file", string(&file.name)),
+        ("byte_start", span.start.to_string()),
+        ("byte_end", span.end.to_string()),
+        ("line_start", start.line.to_string()),
+        ("column_start", start.column.to_string()),
+        ("line_end", end.line.to_string()),
+        ("column_end", end.column.to_string()),
+    ])
+}
+
+fn object(fields: &[(&str, String)]) -> String {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|(name, value)| format!("{}:{value}", string(name)))
+        .collect();
+    format!("{{{}}}", fields.join(","))
+}
+
+fn array(items: impl Iterator<Item = String>) -> String {
+    format!("[{}]", items.collect::<Vec<_>>().join(","))
+}
+
+fn optional_string(value: Option<&str>) -> String {
+    value.map_or_else(|| "null".to_string(), string)
+}
+
+/// JSON yazısı: dırnaq, tərs xətt və idarəetmə simvolları qaçırılır.
+pub fn string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -1,29 +1,95 @@
-use std::{
-    path::{Path, PathBuf},
-    process::Command,
-};
+use std::path::{Path, PathBuf};
 
-use parser::parser;
+use parser::{errors::ParserError, parse_file_with_keywords};
+use tokenizer::{convert::convert_keywords, source::add_file};
+use transpiler::transpile_program;
 use which::which;
+mod backend;
+mod diagnostics;
+mod error_codes;
 mod errors;
+mod json;
 mod libc_checker;
 #[cfg(test)]
 mod tests;
 
-use crate::errors::{BackendError, CompilerError};
+pub use diagnostics::{ColorChoice, MessageFormat};
+pub use error_codes::{EXPLANATIONS, explain};
+pub use locale::{Lang, set_lang, t};
+pub use tokenizer::words::KeywordSet;
+pub use transpiler::{OverflowMode, mangle::demangle_text};
+pub use validator::lint::{Level, Lint, LintLevels};
 
-pub fn compiler(path: &str) -> Result<(), CompilerError> {
+use crate::errors::{BackendError, CompilerError, render_warnings, render_warnings_json};
+
+/// `build` əmrinin nəticəsi.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Emit {
+    /// İcra olunan fayl.
+    #[default]
+    Exe,
+    /// Yalnız assembler faylı (`.s`), AzLang adları şərhlərdə.
+    Asm,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CompilerOptions {
+    pub overflow: OverflowMode,
+    pub lints: LintLevels,
+    pub color: ColorChoice,
+    pub message_format: MessageFormat,
+    /// Layihənin açar söz dəsti; `# keywords: ...` direktivi olan fayllar öz dəstini seçir.
+    pub keywords: KeywordSet,
+    pub emit: Emit,
+}
+
+pub fn compiler(path: &str, options: &CompilerOptions) -> Result<(), CompilerError> {
     let source = file_system::read_file(path)?;
 
-    let parsed_program = parser(source)?;
+    let parsed_program = parse_file_with_keywords(path, &source, options.keywords)?;
 
-    let validator = validator::Validator::default();
-    let (_, program) = validator.validate(parsed_program)?;
+    let validator = validator::Validator::with_lints(options.lints.clone());
+    let (context, program) = validator.validate(parsed_program)?;
+    match options.message_format {
+        MessageFormat::Human => print!(
+            "{}",
+            render_warnings(&context.warnings, options.color.enabled())
+        ),
+        MessageFormat::Json => print!("{}", render_warnings_json(&context.warnings)),
+    }
 
     which("qbe").map_err(|_| CompilerError::Backend(BackendError::Qbe))?;
+    let source = Path::new(path);
+    let ssa = transpile_program(program, &module_name(source), options.overflow)?;
+    if options.emit == Emit::Asm {
+        return backend::emit_assembly(source, ssa);
+    }
+
     which("as").map_err(|_| CompilerError::Backend(BackendError::BinUtils))?;
     which("ld").map_err(|_| CompilerError::Backend(BackendError::BinUtils))?;
-    let linker = libc_checker::libc_link_checker().expect("Error");
+    let linker = libc_checker::libc_link_checker().unwrap_or("ld");
+    backend::build_executable(source, ssa, linker, &context.link_files)
+}
 
+/// Kodlanmış adların modul hissəsi faylın adıdır: `src/salam.az` → `salam`.
+fn module_name(source: &Path) -> String {
+    source
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .filter(|stem| !stem.is_empty())
+        .unwrap_or_else(|| "main".to_string())
+}
+
+/// Faylın açar sözlərini `to` dəstinə çevirib faylı yenidən yazır.
+pub fn format_keywords(
+    path: &str,
+    to: KeywordSet,
+    options: &CompilerOptions,
+) -> Result<(), CompilerError> {
+    let source = file_system::read_file(path)?;
+    let file = add_file(path, &source);
+    let converted = convert_keywords(&source, file, options.keywords, to)
+        .map_err(|error| CompilerError::Parser(ParserError::LexerError(error)))?;
+    file_system::write_file(&PathBuf::from(path), converted)?;
     Ok(())
 }
@@ -1,12 +1,14 @@
 use std::{path::PathBuf, process::Command};
 
 use file_system::write_file;
-use transpiler::transpile_program;
+use parser::parse_file;
+use transpiler::{OverflowMode, transpile_program};
 use which::which;
 
 use crate::{
+    backend::annotate_assembly,
     errors::{BackendError, CompilerError},
-    libc_checker, parser,
+    libc_checker,
 };
 /*
 *
@@ -22,16 +24,11 @@ fn dependencies_test() -> Result<(), CompilerError> {
 }
 #[test]
 fn compiler_output_file() -> Result<(), CompilerError> {
-    let parsed_program = parser(String::from(
-        "
-@link(\"../../exit.o\") 
-func exit(const int val): void
-exit(50)",
-    ))?;
+    let parsed_program = parse_file("main.az", "exit(50)")?;
 
     let validator = validator::Validator::default();
     let (context, program) = validator.validate(parsed_program)?;
-    let transpiled_code = transpile_program(program);
+    let transpiled_code = transpile_program(program, "main", OverflowMode::Trap)?;
 
     let linker = libc_checker::libc_link_checker().expect("Error");
 
@@ -59,10 +56,36 @@ exit(50)",
         .args(["print.s", "-o", "print.o"])
         .status()
         .expect("Assembler  can't compile to object ");
+    Command::new("as")
+        .args(["trap.s", "-o", "trap.o"])
+        .status()
+        .expect("Assembler  can't compile to object ");
+    Command::new("as")
+        .args(["env.s", "-o", "env.o"])
+        .status()
+        .expect("Assembler  can't compile to object ");
 
     Command::new(linker)
-        .args(["starter.o", "main.o", "exit.o", "print.o", "-o", "app"])
+        .args([
+            "starter.o",
+            "main.o",
+            "exit.o",
+            "print.o",
+            "trap.o",
+            "env.o",
+            "-o",
+            "app",
+        ])
         .status()
         .expect("Linker Error");
     Ok(())
 }
+
+#[test]
+fn test_emitted_assembly_names_azlang_functions() {
+    let asm = ".text\n.globl _AZ5salam8salamVerE\n_AZ5salam8salamVerE:\n\tcallq _AZ5salamu6_e7_apE\n\tcallq exit\n";
+    assert_eq!(
+        annotate_assembly(asm),
+        ".text\n.globl _AZ5salam8salamVerE # salam::salamVer\n_AZ5salam8salamVerE: # salam::salamVer\n\tcallq _AZ5salamu6_e7_apE # salam::çap\n\tcallq exit\n"
+    );
+}
@@ -0,0 +1,226 @@
+use locale::{Lang, set_lang};
+use parser::{ast::SourceSpan, parse_file};
+use tokenizer::source::add_file;
+use validator::Validator;
+
+use crate::{
+    diagnostics::{ColorChoice, Diagnostic},
+    errors::{CompilerError, render_warnings},
+};
+
+#[test]
+fn test_render_primary_label_with_caret() {
+    let source = "const int a = 1\nexit(b)\n";
+    let file = add_file("main.az", source);
+    let start = source.rfind('b').unwrap() as u32;
+    let diagnostic = Diagnostic::error("'b' Dəyəri bildirilməyib.")
+        .with_primary(&SourceSpan::new(file, start, start + 1), "")
+        .with_help("əvvəlcə elan edin");
+
+    assert_eq!(
+        diagnostic.render(false),
+        "xəta: 'b' Dəyəri bildirilməyib.\n \
+         --> main.az:2:6\n  \
+         |\n\
+         2 | exit(b)\n  \
+         |      ^\n  \
+         |\n  \
+         = kömək: əvvəlcə elan edin\n"
+    );
+}
+
+#[test]
+fn test_render_secondary_label_on_earlier_line() {
+    let source = "func f(): int\n    return 1\n\nfunc f(): int\n    return 2\n";
+    let ast = parse_file("dup.az", source).expect("parse edilmədi");
+    let error = Validator::default()
+        .validate(ast)
+        .expect_err("təkrar funksiya qəbul edilməməlidir");
+
+    let rendered = CompilerError::Validator(error).diagnostics()[0].render(false);
+    let expected = "xəta[AZ0227]: Funksiya 'f' onsuzda var.\n \
+                    --> dup.az:4:1\n  \
+                    |\n\
+                    1 | func f(): int\n  \
+                    | ------------- ilk dəfə burada təyin olunub\n\
+                    ...\n\
+                    4 | func f(): int\n  \
+                    | ^^^^^^^^^^^^^ yenidən təyin edilib\n";
+    assert_eq!(rendered, expected);
+}
+
+#[test]
+fn test_render_without_source_has_only_header_and_notes() {
+    let diagnostic = Diagnostic::error("Fayl tapılmadı")
+        .with_primary(&SourceSpan::default(), "")
+        .with_note("yalnız .az faylları");
+
+    assert_eq!(
+        diagnostic.render(false),
+        "xəta: Fayl tapılmadı\n  = qeyd: yalnız .az faylları\n"
+    );
+}
+
+#[test]
+fn test_render_color_codes_only_when_enabled() {
+    let diagnostic = Diagnostic::error("səhv");
+
+    assert!(diagnostic.render(true).contains("\x1b[1;31mxəta\x1b[0m"));
+    assert!(!diagnostic.render(false).contains('\x1b'));
+    assert!(!ColorChoice::Never.enabled());
+    assert!(ColorChoice::Always.enabled());
+}
+
+#[test]
+fn test_render_every_error_with_final_count() {
+    let source = "exit(b)\nexit(c)\n";
+    let ast = parse_file("many.az", source).expect("parse edilmədi");
+    let error = Validator::default()
+        .validate(ast)
+        .expect_err("təyin edilməmiş dəyərlər qəbul edilməməlidir");
+
+    let rendered = CompilerError::Validator(error).render(false);
+    assert!(rendered.contains("--> many.az:1:6"), "{rendered}");
+    assert!(rendered.contains("--> many.az:2:6"), "{rendered}");
+    assert!(rendered.ends_with(
+        "\nxəta: 2 xəta səbəbindən tərtib dayandırıldı\n\
+         Xəta haqqında ətraflı məlumat üçün `azcli explain AZ0209` yazın.\n"
+    ));
+}
+
+#[test]
+fn test_render_parser_errors_from_every_line() {
+    let source = "const int = 1\nconst int = 2\n";
+    let error = parse_file("syntax.az", source).expect_err("sintaksis xətası gözlənilirdi");
+
+    let error = CompilerError::Parser(error);
+    assert_eq!(error.diagnostics().len(), 2);
+    assert!(
+        error
+            .render(false)
+            .contains("xəta: 2 xəta səbəbindən tərtib dayandırıldı\n")
+    );
+}
+
+#[test]
+fn test_render_warnings_with_count() {
+    let source = "const int a = 1\n";
+    let ast = parse_file("warn.az", source).expect("parse edilmədi");
+    let (validator, _) = Validator::default()
+        .validate(ast)
+        .expect("xəbərdarlıq tərtibi dayandırmamalıdır");
+
+    let rendered = render_warnings(&validator.warnings, false);
+    assert!(rendered.starts_with("xəbərdarlıq[AZ0230]: 'a' dəyəri heç istifadə olunmayıb."));
+    assert!(rendered.contains("--> warn.az:1:1"));
+    assert!(rendered.ends_with("\nxəbərdarlıq: 1 xəbərdarlıq yarandı\n"));
+    assert_eq!(render_warnings(&[], false), "");
+}
+
+#[test]
+fn test_render_lists_every_distinct_code() {
+    let source = "exit(b)\nconst int a = \"x\"\nexit(a)\nexit(c)\n";
+    let ast = parse_file("codes.az", source).expect("parse edilmədi");
+    let error = Validator::default()
+        .validate(ast)
+        .expect_err("xətalar gözlənilirdi");
+
+    let rendered = CompilerError::Validator(error).render(false);
+    assert!(rendered.starts_with("xəta[AZ0209]: "), "{rendered}");
+    assert!(rendered.ends_with(
+        "Ətraflı izahı olan xətalar: AZ0209, AZ0205.\n\
+         Xəta haqqında ətraflı məlumat üçün `azcli explain AZ0209` yazın.\n"
+    ));
+}
+
+#[test]
+fn test_render_in_english() {
+    let source = "var int a = 1\nexit(b)\n";
+    let ast = parse_file("lang.az", source).expect("parse edilmədi");
+    let error = Validator::default()
+        .validate(ast)
+        .expect_err("təyin edilməmiş dəyər qəbul edilməməlidir");
+
+    set_lang(Lang::En);
+    let rendered = CompilerError::Validator(error).render(false);
+    set_lang(Lang::Az);
+    assert_eq!(
+        rendered,
+        "error[AZ0209]: 'b' is not declared\n \
+         --> lang.az:2:6\n  \
+         |\n\
+         2 | exit(b)\n  \
+         |      ^ this name is not declared\n\
+         \n\
+         error: could not compile due to 1 error\n\
+         For more information about an error, try `azcli explain AZ0209`.\n"
+    );
+}
+
+#[test]
+fn test_render_suggests_similar_name() {
+    let source = "const int total = 1\nexit(totl)\n";
+    let ast = parse_file("similar.az", source).expect("parse edilmədi");
+    let error = Validator::default()
+        .validate(ast)
+        .expect_err("təyin edilməmiş dəyər qəbul edilməməlidir");
+
+    let error = CompilerError::Validator(error);
+    let rendered = error.render(false);
+    assert!(
+        rendered.contains("  = kömək: bunu nəzərdə tutdunuz: 'total'?\n"),
+        "{rendered}"
+    );
+    let suggestion = &error.diagnostics()[0].suggestions[0];
+    assert_eq!(suggestion.replacement, "total");
+    assert_eq!((suggestion.span.start, suggestion.span.end), (25, 29));
+}
+
+#[test]
+fn test_render_parser_errors_point_at_the_token() {
+    let cases = [
+        ("func (): int\n    return 1\n", "AZ0109", "1:6"),
+        ("const int = 5\n", "AZ0117", "1:11"),
+        ("func f(a int): int\n    return 1\n", "AZ0110", "1:10"),
+    ];
+    for (source, code, location) in cases {
+        let error = parse_file("span.az", source).expect_err("sintaksis xətası gözlənilirdi");
+        let rendered = CompilerError::Parser(error).render(false);
+        assert!(
+            rendered.starts_with(&format!("xəta[{code}]: ")),
+            "{rendered}"
+        );
+        assert!(
+            rendered.contains(&format!("--> span.az:{location}\n")),
+            "{rendered}"
+        );
+        assert!(rendered.contains('^'), "{rendered}");
+    }
+}
+
+#[test]
+fn test_render_suggests_keyword() {
+    let source = "cosnt int a = 1\nexit(a)\n";
+    let error = parse_file("keyword.az", source).expect_err("sintaksis xətası gözlənilirdi");
+
+    let rendered = CompilerError::Parser(error).render(false);
+    assert!(rendered.starts_with("xəta[AZ0101]: "), "{rendered}");
+    assert!(rendered.contains("= kömək: bunu nəzərdə tutdunuz: 'const'?"));
+}
+
+#[test]
+fn test_confusable_character_suggests_ascii() {
+    let source = "const int a = 2\nexit(a \u{2212} 1)\n";
+    let error = parse_file("confusable.az", source).expect_err("leksik xəta gözlənilirdi");
+
+    let error = CompilerError::Parser(error);
+    let diagnostic = &error.diagnostics()[0];
+    assert_eq!(diagnostic.code, Some("AZ0021"));
+    let suggestion = &diagnostic.suggestions[0];
+    assert_eq!(suggestion.replacement, "-");
+    assert_eq!(
+        &source[suggestion.span.start as usize..suggestion.span.end as usize],
+        "\u{2212}"
+    );
+    assert!(error.render(false).contains("= kömək: '-' ilə əvəz edin"));
+}
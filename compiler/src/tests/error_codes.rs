@@ -0,0 +1,121 @@
+use locale::Lang;
+use parser::parse_file;
+use tokenizer::{convert::convert_keywords, source::add_file, words::KeywordSet};
+use validator::Validator;
+
+use crate::{EXPLANATIONS, errors::CompilerError, explain};
+
+/// Nümunəni tərtib edib yaranan bütün xəta və xəbərdarlıq kodlarını qaytarır.
+fn codes_of(name: &str, source: &str) -> (Vec<&'static str>, Vec<&'static str>) {
+    let ast = match parse_file(name, source) {
+        Ok(ast) => ast,
+        Err(error) => return (codes(CompilerError::Parser(error)), Vec::new()),
+    };
+    match Validator::default().validate(ast) {
+        Ok((validator, _)) => (
+            Vec::new(),
+            validator.warnings.iter().filter_map(|w| w.code()).collect(),
+        ),
+        Err(error) => (codes(CompilerError::Validator(error)), Vec::new()),
+    }
+}
+
+/// Nümunəni `azcli fmt --to az` kimi çevirib yaranan xəta kodunu qaytarır.
+fn fmt_codes(name: &str, source: &str) -> (Vec<&'static str>, Vec<&'static str>) {
+    let file = add_file(name, source);
+    match convert_keywords(source, file, KeywordSet::En, KeywordSet::Az) {
+        Ok(_) => (Vec::new(), Vec::new()),
+        Err(error) => (vec![error.code()], Vec::new()),
+    }
+}
+
+fn codes(error: CompilerError) -> Vec<&'static str> {
+    error.diagnostics().iter().filter_map(|d| d.code).collect()
+}
+
+fn example<'a>(text: &'a str, heading: &str) -> Option<&'a str> {
+    let rest = &text[text.find(heading)?..];
+    let start = rest.find("```az\n")? + "```az\n".len();
+    let end = rest[start..].find("```")?;
+    Some(&rest[start..start + end])
+}
+
+#[test]
+fn test_every_code_has_an_explanation() {
+    let ranges = [(1, 23), (101, 125), (201, 236), (301, 304)];
+    let expected: Vec<String> = ranges
+        .iter()
+        .flat_map(|&(from, to)| (from..=to).map(|n| format!("AZ{n:04}")))
+        .filter(|code| !RETIRED.contains(&code.as_str()))
+        .collect();
+    let known: Vec<&str> = EXPLANATIONS.iter().map(|(code, _)| *code).collect();
+
+    assert_eq!(known, expected);
+    assert!(explain("az0209").is_some());
+    assert!(explain("AZ0002").is_none());
+    assert!(explain("AZ9999").is_none());
+}
+
+#[test]
+fn test_every_code_has_a_message_in_every_language() {
+    for (code, _) in EXPLANATIONS {
+        for lang in Lang::ALL {
+            assert!(
+                lang.catalog().contains(code),
+                "{code} {} dilində tərcümə olunmayıb",
+                lang.name()
+            );
+        }
+    }
+}
+
+/// Heç bir xətanın artıq vermədiyi kodlar. Onların nömrələri başqa xətaya verilmir.
+const RETIRED: [&str; 32] = [
+    "AZ0002", "AZ0004", "AZ0005", "AZ0006", "AZ0106", "AZ0107", "AZ0113", "AZ0114", "AZ0115",
+    "AZ0116", "AZ0118", "AZ0119", "AZ0120", "AZ0121", "AZ0122", "AZ0123", "AZ0124", "AZ0206",
+    "AZ0210", "AZ0214", "AZ0215", "AZ0216", "AZ0217", "AZ0218", "AZ0219", "AZ0220", "AZ0221",
+    "AZ0223", "AZ0224", "AZ0225", "AZ0228", "AZ0229",
+];
+
+/// Mənbədən yox, xarici alətlərdən və kod yaradılmasından gələn xətalar; onların
+/// səhifəsində nümunə olmur.
+const WITHOUT_EXAMPLES: [&str; 4] = ["AZ0301", "AZ0302", "AZ0303", "AZ0304"];
+
+/// Yalnız `azcli fmt --to az` verən kodlar; nümunələri çevrilərək yoxlanılır.
+const FROM_FMT: [&str; 1] = ["AZ0022"];
+
+#[test]
+fn test_examples_produce_their_code() {
+    let mut failures = Vec::new();
+    for (code, text) in EXPLANATIONS {
+        let reserved = WITHOUT_EXAMPLES.contains(code);
+        let Some(wrong) = example(text, "Səhv nümunə") else {
+            if !reserved {
+                failures.push(format!("{code}: səhifədə səhv nümunə yoxdur"));
+            }
+            continue;
+        };
+        if reserved {
+            failures.push(format!("{code}: nümunəsi var, amma nümunəsiz kodlardadır"));
+            continue;
+        }
+        let right = example(text, "Düzgün nümunə").expect(code);
+
+        let check = if FROM_FMT.contains(code) {
+            fmt_codes
+        } else {
+            codes_of
+        };
+        let (errors, warnings) = check("wrong.az", wrong);
+        if !errors.contains(code) && !warnings.contains(code) {
+            failures.push(format!("{code}: səhv nümunə {errors:?} {warnings:?} verdi"));
+        }
+        let (errors, warnings) = check("right.az", right);
+        if !errors.is_empty() || warnings.contains(code) {
+            failures.push(format!(
+                "{code}: düzgün nümunə {errors:?} {warnings:?} verdi"
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
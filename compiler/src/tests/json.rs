@@ -0,0 +1,66 @@
+use parser::parse_file;
+use validator::Validator;
+
+use crate::{
+    errors::{BackendError, CompilerError, render_warnings, render_warnings_json},
+    json::string,
+};
+
+#[test]
+fn test_json_string_escapes_quotes_and_control_characters() {
+    assert_eq!(string("a\"b\\c"), r#""a\"b\\c""#);
+    assert_eq!(string("x\ny\t\u{1}"), r#""x\ny\t\u0001""#);
+    assert_eq!(string("Dəyər"), "\"Dəyər\"");
+}
+
+#[test]
+fn test_warning_json_has_spans_and_suggestion() {
+    let source = "var int a = 1\nexit(a)\n";
+    let ast = parse_file("json.az", source).expect("parse edilmədi");
+    let (validator, _) = Validator::default()
+        .validate(ast)
+        .expect("xəbərdarlıq tərtibi dayandırmamalıdır");
+
+    let json = render_warnings_json(&validator.warnings);
+    let expected = concat!(
+        r#"{"version":1,"code":"AZ0226","severity":"warning","#,
+        r#""message":"'a' Dəyəri dəyişən olaraq qeyd edilib amma dəyişdirilməyib.","#,
+        r#""spans":[{"file":"json.az","byte_start":0,"byte_end":13,"line_start":1,"#,
+        r#""column_start":1,"line_end":1,"column_end":14,"primary":true,"label":null}],"#,
+        r#""notes":[],"help":[],"#,
+        r#""suggestions":[{"file":"json.az","byte_start":0,"byte_end":3,"line_start":1,"#,
+        r#""column_start":1,"line_end":1,"column_end":4,"replacement":"const","#,
+        r#""message":"'var' əvəzinə 'const' istifadə edin"}],"#,
+        r#""rendered":"xəbərdarlıq[AZ0226]: 'a' Dəyəri dəyişən olaraq qeyd edilib amma dəyişdirilməyib.\n"#,
+        r#" --> json.az:1:1\n  |\n1 | var int a = 1\n  | ^^^^^^^^^^^^^\n  |\n"#,
+        r#"  = kömək: 'var' əvəzinə 'const' istifadə edin\n"}"#,
+        "\n"
+    );
+    assert_eq!(json, expected);
+    assert!(render_warnings(&validator.warnings, false).contains("= kömək: 'var' əvəzinə"));
+}
+
+#[test]
+fn test_error_json_has_one_line_per_diagnostic_without_summary() {
+    let source = "exit(b)\nexit(c)\n";
+    let ast = parse_file("lines.az", source).expect("parse edilmədi");
+    let error = Validator::default()
+        .validate(ast)
+        .expect_err("təyin edilməmiş dəyərlər qəbul edilməməlidir");
+
+    let json = CompilerError::Validator(error).render_json();
+    let lines: Vec<&str> = json.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].contains(r#""code":"AZ0209""#));
+    assert!(lines[1].contains(r#""label":"bu ad elan edilməyib""#));
+    assert!(!json.contains("azcli explain"));
+}
+
+#[test]
+fn test_error_json_without_location_has_empty_spans() {
+    let json = CompilerError::Backend(BackendError::Qbe).render_json();
+
+    assert!(json.starts_with(r#"{"version":1,"code":"AZ0301","severity":"error","#));
+    assert!(json.contains(r#""spans":[],"#));
+    assert!(json.ends_with("}\n"));
+}
@@ -1 +1,4 @@
 mod compiler_test;
+mod diagnostics;
+mod error_codes;
+mod json;
@@ -1,8 +1,19 @@
 .intel_syntax noprefix
 .global _start
+.global az_argv_base
+.global az_envp
+.bss
+.balign 8
+az_argv_base:
+  .skip 8
+az_envp:
+  .skip 8
 .text
 _start:
+  mov qword ptr [rip + az_argv_base], rsp
+  mov rax, qword ptr [rsp]
+  lea rdx, [rsp + rax*8 + 16]
+  mov qword ptr [rip + az_envp], rdx
   call main
-
-
-
+  mov edi, eax
+  call exit
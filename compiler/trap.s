@@ -0,0 +1,13 @@
+.intel_syntax noprefix
+.global az_trap
+.text
+
+az_trap:
+    mov rdx, rsi
+    mov rsi, rdi
+    mov rax, 1
+    mov rdi, 2
+    syscall
+    mov rax, 60
+    mov rdi, 101
+    syscall
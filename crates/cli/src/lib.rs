@@ -1,4 +1,4 @@
-use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
 #[derive(Parser)]
 #[command(
     name = "azcli",
@@ -9,6 +9,15 @@ use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+    /// When to color diagnostics; `auto` also respects `NO_COLOR`
+    #[arg(long, value_enum, global = true, default_value_t = Color::Auto)]
+    pub color: Color,
+    /// Language of diagnostics; defaults to `AZLANG_LANG`, then `az`
+    #[arg(long, value_enum, global = true)]
+    pub lang: Option<Language>,
+    /// Keywords of files without a `# keywords: ...` line
+    #[arg(long, value_enum, global = true, default_value_t = Keywords::En)]
+    pub keywords: Keywords,
 }
 
 #[derive(Subcommand)]
@@ -16,10 +25,101 @@ pub enum Commands {
     Build {
         /// E.g: output/output
         binary: String,
+        /// What `+ - *` do when a fixed-width integer overflows
+        #[arg(long, value_enum, default_value_t = Overflow::Trap)]
+        overflow: Overflow,
+        /// Report LINT as a warning (`warnings` means every lint)
+        #[arg(short = 'W', long = "warn", value_name = "LINT")]
+        warn: Vec<String>,
+        /// Report LINT as an error
+        #[arg(short = 'D', long = "deny", value_name = "LINT")]
+        deny: Vec<String>,
+        /// Silence LINT
+        #[arg(short = 'A', long = "allow", value_name = "LINT")]
+        allow: Vec<String>,
+        /// How to print diagnostics; `json` prints one object per line
+        #[arg(long, value_enum, default_value_t = MessageFormat::Human)]
+        message_format: MessageFormat,
+        /// What to produce; `asm` writes `.s` with AzLang names in comments
+        #[arg(long, value_enum, default_value_t = Emit::Exe)]
+        emit: Emit,
+        /// `-W`, `-D` and `-A` in command-line order, so later flags win
+        #[arg(skip)]
+        lints: Vec<(LintLevel, String)>,
+    },
+    /// Rewrite the keywords of FILE in another keyword set
+    Fmt {
+        file: String,
+        /// Keyword set to convert to
+        #[arg(long, value_enum)]
+        to: Keywords,
+    },
+    /// Turn mangled symbols such as `_AZ4main8salamVerE` back into AzLang names;
+    /// without arguments, filters stdin (e.g. a stack trace)
+    Demangle {
+        symbols: Vec<String>,
+    },
+    /// Print a detailed explanation of an error code, e.g. `AZ0209`
+    Explain {
+        code: String,
     },
     Version {},
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Overflow {
+    /// Stop the program with a source-located error (debug)
+    Trap,
+    /// Wrap around in two's complement (release)
+    Wrap,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum MessageFormat {
+    /// Source snippets for people
+    Human,
+    /// One JSON object per diagnostic, see docs/message-format.md
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Emit {
+    /// An executable
+    Exe,
+    /// Assembly only
+    Asm,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Color {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Language {
+    /// Azərbaycan dili
+    Az,
+    /// English
+    En,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Keywords {
+    /// `if`, `while`, `func`, ...
+    En,
+    /// `əgər`, `nə qədər ki`, `funksiya`, ...
+    Az,
+}
+
 pub fn cli() -> Cli {
     let cmd = Cli::command().help_template(
         "\x1b[36m{before-help}AzCLI — {about}\x1b[0m\n\n\
@@ -30,6 +130,35 @@ pub fn cli() -> Cli {
      {after-help}",
     );
     let matches = cmd.get_matches();
-    let cli = Cli::from_arg_matches(&matches);
-    cli.unwrap()
+    let mut cli = Cli::from_arg_matches(&matches).unwrap();
+    if let (Commands::Build { lints, .. }, Some(build)) =
+        (&mut cli.command, matches.subcommand_matches("build"))
+    {
+        *lints = ordered_lints(build);
+    }
+    cli
+}
+
+fn ordered_lints(matches: &ArgMatches) -> Vec<(LintLevel, String)> {
+    let mut lints = Vec::new();
+    for (id, level) in [
+        ("allow", LintLevel::Allow),
+        ("warn", LintLevel::Warn),
+        ("deny", LintLevel::Deny),
+    ] {
+        if let (Some(indices), Some(values)) =
+            (matches.indices_of(id), matches.get_many::<String>(id))
+        {
+            lints.extend(
+                indices
+                    .zip(values)
+                    .map(|(index, name)| (index, level, name.clone())),
+            );
+        }
+    }
+    lints.sort_by_key(|(index, ..)| *index);
+    lints
+        .into_iter()
+        .map(|(_, level, name)| (level, name))
+        .collect()
 }
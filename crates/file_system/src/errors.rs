@@ -1,5 +1,8 @@
 use core::fmt;
 use std::{fmt::Display, io};
+
+use locale::t;
+
 #[derive(Debug)]
 pub enum FileSystemKind {
     IOError(io::Error),
@@ -13,7 +16,13 @@ pub struct FileSystemError {
 }
 impl Display for FileSystemError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[Kod {}] {}: {}", self.code(), self.kind, self.file)
+        let message = t!(
+            "fs.error",
+            code = self.code(),
+            kind = self.kind,
+            file = self.file
+        );
+        write!(f, "{message}")
     }
 }
 
@@ -39,11 +48,9 @@ impl From<io::Error> for FileSystemError {
 impl Display for FileSystemKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            FileSystemKind::IOError(e) => write!(f, "IO Error: {}", e),
-            FileSystemKind::UnsupportedFile => {
-                write!(f, "Dəstəklənməyən Fayl, yalnız .az faylları dəstəklənir")
-            }
-            FileSystemKind::FileNotFound => write!(f, "Fayl tapılmadı"),
+            FileSystemKind::IOError(e) => write!(f, "{}", t!("fs.io", error = e)),
+            FileSystemKind::UnsupportedFile => write!(f, "{}", t!("fs.unsupported")),
+            FileSystemKind::FileNotFound => write!(f, "{}", t!("fs.not-found")),
         }
     }
 }
@@ -0,0 +1,150 @@
+use std::{cell::Cell, collections::HashMap, fmt::Display, sync::OnceLock};
+
+/// Diaqnostikaların dili. Susmaya görə Azərbaycan dilidir.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    #[default]
+    Az,
+    En,
+}
+
+/// Dili seçən mühit dəyişəni, məsələn `AZLANG_LANG=en`.
+pub const LANG_ENV: &str = "AZLANG_LANG";
+
+impl Lang {
+    pub const ALL: [Lang; 2] = [Lang::Az, Lang::En];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Lang::Az => "az",
+            Lang::En => "en",
+        }
+    }
+
+    /// `az`, `en` və ya `en_US.UTF-8` kimi lokal adını tanıyır.
+    pub fn from_name(name: &str) -> Option<Lang> {
+        let name = name.trim().to_ascii_lowercase();
+        let base = name.split(['_', '-', '.']).next().unwrap_or_default();
+        Lang::ALL.into_iter().find(|lang| lang.name() == base)
+    }
+
+    /// [`LANG_ENV`] dəyişənindəki dil; dəyişən yoxdursa və ya tanınmırsa `None`.
+    pub fn from_env() -> Option<Lang> {
+        Lang::from_name(&std::env::var(LANG_ENV).ok()?)
+    }
+
+    pub fn catalog(self) -> &'static Catalog {
+        static AZ: OnceLock<Catalog> = OnceLock::new();
+        static EN: OnceLock<Catalog> = OnceLock::new();
+        match self {
+            Lang::Az => AZ.get_or_init(|| Catalog::parse(include_str!("../catalogs/az.txt"))),
+            Lang::En => EN.get_or_init(|| Catalog::parse(include_str!("../catalogs/en.txt"))),
+        }
+    }
+
+    /// `key` mesajını bu dildə qaytarır. Tərcümə yoxdursa Azərbaycan dilinə, o da
+    /// yoxdursa açarın özünə qayıdır.
+    pub fn tr(self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let template = self
+            .catalog()
+            .get(key)
+            .or_else(|| Lang::Az.catalog().get(key))
+            .unwrap_or(key);
+        format(template, args)
+    }
+
+    /// Say bildirən mesaj: `count` 1 olduqda varsa `key.one` formasını seçir.
+    pub fn tr_count(self, key: &str, count: usize) -> String {
+        let one = format!("{key}.one");
+        let key = if count == 1 && self.catalog().contains(&one) {
+            &one
+        } else {
+            key
+        };
+        self.tr(key, &[("count", &count)])
+    }
+}
+
+/// Bir dilin `açar = mesaj` cədvəli.
+#[derive(Debug)]
+pub struct Catalog {
+    messages: HashMap<&'static str, &'static str>,
+}
+
+impl Catalog {
+    /// Hər sətir `açar = mesaj` şəklindədir; boş sətirlər və `#` ilə başlayanlar atlanır.
+    pub fn parse(text: &'static str) -> Catalog {
+        let messages = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once(" = "))
+            .map(|(key, message)| (key.trim(), message.trim()))
+            .collect();
+        Catalog { messages }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&'static str> {
+        self.messages.get(key).copied()
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.messages.contains_key(key)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.messages.keys().copied()
+    }
+}
+
+/// Şablondakı `{ad}` yerlərini arqumentlərlə əvəz edir. Tanınmayan `{...}` olduğu kimi qalır.
+pub fn format(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let arg = after.find('}').and_then(|close| {
+            let name = &after[..close];
+            args.iter()
+                .find(|(arg, _)| *arg == name)
+                .map(|(_, value)| (close, value))
+        });
+        match arg {
+            Some((close, value)) => {
+                out.push_str(&value.to_string());
+                rest = &after[close + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+thread_local! {
+    static CURRENT: Cell<Lang> = const { Cell::new(Lang::Az) };
+}
+
+/// Cari axın üçün diaqnostika dilini seçir.
+pub fn set_lang(lang: Lang) {
+    CURRENT.set(lang);
+}
+
+pub fn lang() -> Lang {
+    CURRENT.get()
+}
+
+/// `key` mesajını cari dildə qaytarır: `t!("AZ0209", name = name)`.
+#[macro_export]
+macro_rules! t {
+    ($key:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        $crate::lang().tr($key, &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),*])
+    };
+}
+
+#[cfg(test)]
+mod tests;
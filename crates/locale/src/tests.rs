@@ -0,0 +1,73 @@
+use std::collections::BTreeSet;
+
+use crate::{Lang, format, lang, set_lang};
+
+fn placeholders(message: &str) -> BTreeSet<&str> {
+    message
+        .split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}'))
+        .map(|(name, _)| name)
+        .filter(|name| name.chars().all(|c| c.is_ascii_alphanumeric()))
+        .collect()
+}
+
+#[test]
+fn test_catalogs_have_the_same_keys() {
+    let az: BTreeSet<_> = Lang::Az.catalog().keys().collect();
+    let en: BTreeSet<_> = Lang::En.catalog().keys().collect();
+
+    assert_eq!(az.difference(&en).collect::<Vec<_>>(), Vec::<&&str>::new());
+    assert_eq!(en.difference(&az).collect::<Vec<_>>(), Vec::<&&str>::new());
+}
+
+#[test]
+fn test_translations_use_the_same_placeholders() {
+    for key in Lang::Az.catalog().keys() {
+        let az = Lang::Az.catalog().get(key).unwrap();
+        let en = Lang::En.catalog().get(key).unwrap_or_default();
+        assert!(!az.is_empty() && !en.is_empty(), "{key} boşdur");
+        assert_eq!(placeholders(az), placeholders(en), "{key}");
+    }
+}
+
+#[test]
+fn test_format_replaces_only_known_arguments() {
+    assert_eq!(
+        format("'{name}' və {other} {", &[("name", &"a")]),
+        "'a' və {other} {"
+    );
+    assert_eq!(format("{count}{count}", &[("count", &2)]), "22");
+}
+
+#[test]
+fn test_tr_picks_language_and_plural_form() {
+    assert_eq!(
+        Lang::En.tr("AZ0209", &[("name", &"x")]),
+        "'x' is not declared"
+    );
+    assert_eq!(
+        Lang::En.tr_count("summary.warnings", 1),
+        "1 warning emitted"
+    );
+    assert_eq!(
+        Lang::En.tr_count("summary.warnings", 3),
+        "3 warnings emitted"
+    );
+    assert_eq!(Lang::Az.tr("yoxdur.açar", &[]), "yoxdur.açar");
+}
+
+#[test]
+fn test_lang_from_name_and_current_thread() {
+    assert_eq!(Lang::from_name("EN"), Some(Lang::En));
+    assert_eq!(Lang::from_name("az_AZ.UTF-8"), Some(Lang::Az));
+    assert_eq!(Lang::from_name("tr"), None);
+
+    assert_eq!(lang(), Lang::Az);
+    set_lang(Lang::En);
+    assert_eq!(
+        crate::t!("AZ0227", name = "f"),
+        "function 'f' is already defined"
+    );
+    set_lang(Lang::Az);
+}
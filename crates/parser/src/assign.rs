@@ -1,5 +1,5 @@
 use crate::{
-    ast::{Atom, Statement},
+    ast::{Atom, Expr, ExprKind, Operation, Statement, StatementKind},
     binary_op::parse_expression,
     errors::ParserError,
     helpers::expect_token,
@@ -7,11 +7,62 @@ use crate::{
 use tokenizer::{iterator::Tokens, tokens::Token};
 
 pub fn parse_assign(tokens: &mut Tokens, s: String) -> Result<Statement, ParserError> {
+    let start = tokens.peek_span();
     tokens.next();
     expect_token(tokens, Token::Assign)?;
     let value = parse_expression(tokens)?;
-    Ok(Statement::Assignment {
-        name: Atom::from(s),
-        value: Box::new(value),
-    })
+    Ok(Statement::new(
+        StatementKind::Assignment {
+            name: Atom::from(s),
+            value: Box::new(value),
+        },
+        tokens.span_from(&start),
+    ))
+}
+
+pub fn compound_operation(token: &Token) -> Option<Operation> {
+    match token {
+        Token::AddAssign => Some(Operation::Add),
+        Token::SubtractAssign => Some(Operation::Subtract),
+        Token::MultiplyAssign => Some(Operation::Multiply),
+        Token::DivideAssign => Some(Operation::Divide),
+        Token::ModuloAssign => Some(Operation::Modulo),
+        _ => None,
+    }
+}
+
+/// `x += 1` ifadəsi `x = x + 1` kimi parse olunur.
+pub fn parse_compound_assign(tokens: &mut Tokens, s: String) -> Result<Statement, ParserError> {
+    let start = tokens.peek_span();
+    tokens.next();
+    let operator = tokens.next().ok_or(ParserError::UnexpectedEOF)?;
+    let op = compound_operation(&operator.token).ok_or(ParserError::UnexpectedToken(
+        operator.span.clone(),
+        operator.token,
+        None,
+    ))?;
+    let value = parse_expression(tokens)?;
+    let name = Atom::from(s);
+    let span = tokens.span_from(&start);
+    let target = Expr::new(
+        ExprKind::VariableRef {
+            name: name.clone(),
+            symbol: None,
+        },
+        start,
+    );
+    Ok(Statement::new(
+        StatementKind::Assignment {
+            name,
+            value: Box::new(Expr::new(
+                ExprKind::BinaryOp {
+                    left: Box::new(target),
+                    right: Box::new(value),
+                    op,
+                },
+                span.clone(),
+            )),
+        },
+        span,
+    ))
 }
@@ -1,6 +1,7 @@
 use crate::shared_ast::Type;
 use std::{fmt::Display, rc::Rc};
 pub use string_cache::DefaultAtom as Atom;
+pub use tokenizer::iterator::SourceSpan;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct MethodType {
@@ -65,8 +66,31 @@ pub struct FunctionDef {
     pub body: Vec<Statement>,
     pub return_type: Option<Type>,
 }
+/// Mənbədəki yeri ilə birlikdə ifadə (statement).
 #[derive(Debug, Clone, PartialEq)]
-pub enum Statement {
+pub struct Statement {
+    pub kind: StatementKind,
+    pub span: SourceSpan,
+    /// `@allow(...)` ilə bu ifadə üçün söndürülmüş xəbərdarlıqların adları.
+    pub allow: Vec<Atom>,
+}
+impl Statement {
+    pub fn new(kind: StatementKind, span: SourceSpan) -> Self {
+        Self {
+            kind,
+            span,
+            allow: Vec::new(),
+        }
+    }
+}
+/// Yeri bilinməyən (məsələn, kompilyatorun özünün yaratdığı) ifadələr üçün.
+impl From<StatementKind> for Statement {
+    fn from(kind: StatementKind) -> Self {
+        Self::new(kind, SourceSpan::default())
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatementKind {
     Exit(Expr),
     EnumDecl {
         name: Atom,
@@ -75,25 +99,34 @@ pub enum Statement {
     Decl {
         name: Atom,
         typ: Rc<Type>,
+        /// Tip yazılmayıbsa boşdur.
+        type_span: SourceSpan,
         is_mutable: bool,
         value: Box<Expr>,
+        /// Öncəki `///` şərhlərinin mətni.
+        doc: Option<String>,
     },
     FunctionDef {
         name: Atom,
         return_typ: Type,
+        return_type_span: SourceSpan,
         params: Vec<Parameter>,
         body: Vec<Statement>,
+        doc: Option<String>,
     },
     ExternalFunctionDef {
         name: Atom,
         return_typ: Type,
+        return_type_span: SourceSpan,
         params: Vec<Parameter>,
         library: Atom,
+        doc: Option<String>,
     },
     StructDef {
         name: Atom,
         fields: Vec<(Atom, Type, Option<Expr>)>,
         methods: Vec<MethodType>,
+        doc: Option<String>,
     },
 
     UnionType {
@@ -127,7 +160,23 @@ pub enum Statement {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub enum Expr {
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: SourceSpan,
+}
+impl Expr {
+    pub fn new(kind: ExprKind, span: SourceSpan) -> Self {
+        Self { kind, span }
+    }
+}
+/// Yeri bilinməyən (məsələn, kompilyatorun özünün yaratdığı) ifadələr üçün.
+impl From<ExprKind> for Expr {
+    fn from(kind: ExprKind) -> Self {
+        Self::new(kind, SourceSpan::default())
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprKind {
     DynamicString(Rc<String>),
     Void,
     Return(Box<Expr>),
@@ -135,6 +184,11 @@ pub enum Expr {
     String(Atom),
     Bool(bool),
     Number(i64),
+    /// Sonluqla tipi bəlli olan ədəd: `7tinyint`.
+    TypedNumber {
+        value: i64,
+        typ: Type,
+    },
     Char(char),
     Comment(String),
     List(Vec<Expr>),
@@ -147,6 +201,11 @@ pub enum Expr {
         index: Box<Expr>,
         target_type: Type,
     },
+    /// Çağırışsız üzv müraciəti: `istifadəçi.ad`.
+    Member {
+        target: Box<Expr>,
+        name: Atom,
+    },
 
     Float(f64),
     VariableRef {
@@ -175,12 +234,18 @@ pub enum Expr {
     Continue,
 }
 impl Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.kind)
+    }
+}
+impl Display for ExprKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Expr::Number(n) => write!(f, "{n}"),
-            Expr::String(s) => write!(f, "\"{s}\""),
-            Expr::Bool(b) => write!(f, "{b}"),
-            Expr::Char(c) => write!(f, "'{c}'"),
+            ExprKind::Number(n) => write!(f, "{n}"),
+            ExprKind::TypedNumber { value, .. } => write!(f, "{value}"),
+            ExprKind::String(s) => write!(f, "\"{s}\""),
+            ExprKind::Bool(b) => write!(f, "{b}"),
+            ExprKind::Char(c) => write!(f, "'{c}'"),
             other => write!(f, "{other:?}"),
         }
     }
@@ -192,12 +257,47 @@ pub struct Symbol {
     pub is_mutable: bool,
     pub is_used: bool,
     pub is_changed: bool,
+    /// Elan olunduğu yer.
+    pub span: SourceSpan,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TemplateChunk {
     Literal(String),
-    Expr(Box<Expr>),
+    /// `${...}` içindəki ifadə və `:`-dan sonra gələn format, əgər varsa.
+    Expr(Box<Expr>, Option<FormatSpec>),
+}
+
+/// `${x:>5}` formatı: `[[doldurma]düzləndirmə][0][en][.dəqiqlik][növ]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatSpec {
+    pub fill: char,
+    pub align: Option<Align>,
+    /// `0`: ədəd işarədən sonra sıfırlarla doldurulur.
+    pub zero: bool,
+    pub width: u32,
+    pub precision: Option<u32>,
+    pub kind: FormatKind,
+    /// `:`-dan sonrakı mətn olduğu kimi, xəta mesajları üçün.
+    pub text: String,
+    pub span: SourceSpan,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+/// Tam ədədin say sistemi; `Display` hər tip üçün adi göstərilişdir.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatKind {
+    Display,
+    Hex,
+    UpperHex,
+    Octal,
+    Binary,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -205,4 +305,6 @@ pub struct Parameter {
     pub name: Atom,
     pub typ: Type,
     pub is_pointer: bool,
+    pub span: SourceSpan,
+    pub type_span: SourceSpan,
 }
@@ -0,0 +1,57 @@
+use crate::{
+    ast::{Atom, Statement},
+    binary_op::parse_statement,
+    errors::ParserError,
+    helpers::{expect_token, skip_newlines},
+};
+use tokenizer::{
+    iterator::{SpannedToken, Tokens},
+    tokens::Token,
+};
+
+/// `@allow(unused, ...)` atributu. `@allow` artıq götürülüb; adlar növbəti ifadəyə
+/// bağlanır və onun daxilində həmin xəbərdarlıqlar söndürülür.
+pub fn parse_allow(tokens: &mut Tokens) -> Result<Statement, ParserError> {
+    expect_token(tokens, Token::LParen)?;
+    let mut names = Vec::new();
+    loop {
+        match tokens.next() {
+            Some(SpannedToken {
+                token: Token::Identifier(name),
+                ..
+            }) => names.push(Atom::from(name)),
+            Some(other) => {
+                return Err(ParserError::ExpectedToken(
+                    other.span,
+                    Token::Identifier("unused".into()),
+                    other.token,
+                ));
+            }
+            None => return Err(ParserError::UnexpectedEOF),
+        }
+        match tokens.next() {
+            Some(SpannedToken {
+                token: Token::Comma,
+                ..
+            }) => continue,
+            Some(SpannedToken {
+                token: Token::RParen,
+                ..
+            }) => break,
+            Some(other) => {
+                return Err(ParserError::ExpectedToken(
+                    other.span,
+                    Token::RParen,
+                    other.token,
+                ));
+            }
+            None => return Err(ParserError::UnexpectedEOF),
+        }
+    }
+    expect_token(tokens, Token::Newline)?;
+    skip_newlines(tokens)?;
+
+    let mut stmt = parse_statement(tokens)?;
+    stmt.allow.extend(names);
+    Ok(stmt)
+}
@@ -1,19 +1,26 @@
 use core::panic;
 
-use crate::assign::parse_assign;
-use crate::ast::{Expr, Operation, Statement};
+use crate::assign::{compound_operation, parse_assign, parse_compound_assign};
+use crate::ast::{Expr, ExprKind, Operation, Statement, StatementKind};
+use crate::attribute::parse_allow;
 use crate::condition::parse_if_expr;
 use crate::decl::parse_decl;
+use crate::doc::parse_documented;
 use crate::errors::ParserError;
+use crate::exit::parse_exit;
 use crate::expressions::parse_single_expr;
 use crate::function::{parse_external_function_def, parse_function_def, parse_link_directive};
 use crate::helpers::expect_token;
 use crate::r#loop::parse_loop;
+use crate::suggest::misspelled_keyword;
 use crate::r#while_loop::parse_while_loop;
 use tokenizer::iterator::{SpannedToken, Tokens};
 use tokenizer::tokens::Token;
 
 pub fn parse_statement(tokens: &mut Tokens) -> Result<Statement, ParserError> {
+    if let Some(error) = misspelled_keyword(tokens) {
+        return Err(error);
+    }
     match tokens.peek() {
         Some(SpannedToken {
             token: Token::Conditional,
@@ -25,18 +32,38 @@ pub fn parse_statement(tokens: &mut Tokens) -> Result<Statement, ParserError> {
         }) if tokens.peek_nth(1).is_some_and(|t| t.token == Token::Assign) => {
             parse_assign(tokens, s.to_string())
         }
+        Some(SpannedToken {
+            token: Token::Identifier(s),
+            ..
+        }) if tokens
+            .peek_nth(1)
+            .is_some_and(|t| compound_operation(&t.token).is_some()) =>
+        {
+            parse_compound_assign(tokens, s.to_string())
+        }
         Some(SpannedToken {
             token: Token::At, ..
         }) => {
-            if let Some(SpannedToken {
-                token: Token::Identifier(s),
-                ..
-            }) = tokens.nth(1)
-                && s == "link".to_string()
-            {
-                return parse_external_function_def(tokens);
+            let start = tokens.peek_span();
+            // Arxasında ad olmayan `@` özü gözlənilməz tokendir.
+            let Some(SpannedToken {
+                token: Token::Identifier(name),
+                span,
+            }) = tokens.peek_nth(1).cloned()
+            else {
+                tokens.next();
+                return Err(ParserError::UnexpectedToken(start, Token::At, None));
+            };
+            tokens.nth(1);
+            match name.as_ref() {
+                "link" => parse_external_function_def(tokens, start),
+                "allow" => parse_allow(tokens),
+                _ => Err(ParserError::UnexpectedToken(
+                    start.to(&span),
+                    Token::Identifier(name),
+                    None,
+                )),
             }
-            Err(ParserError::UnexpectedEOF)
         }
         // }) => ,
         Some(SpannedToken {
@@ -47,6 +74,13 @@ pub fn parse_statement(tokens: &mut Tokens) -> Result<Statement, ParserError> {
             token: Token::FunctionDef,
             ..
         }) => parse_function_def(tokens),
+        Some(SpannedToken {
+            token: Token::Exit, ..
+        }) => parse_exit(tokens),
+        Some(SpannedToken {
+            token: Token::Comment(_),
+            ..
+        }) => parse_documented(tokens),
 
         Some(SpannedToken {
             token: Token::Loop, ..
@@ -59,138 +93,81 @@ pub fn parse_statement(tokens: &mut Tokens) -> Result<Statement, ParserError> {
             token: Token::MutableDecl,
             ..
         }) => parse_decl(tokens, true),
-        _ => Ok(Statement::Expr(parse_expression(tokens)?)),
+        _ => {
+            let expr = parse_expression(tokens)?;
+            let span = expr.span.clone();
+            Ok(Statement::new(StatementKind::Expr(expr), span))
+        }
     }
 }
 
+/// Pratt parser. Operandlar [`parse_single_expr`] ilə (prefiks və postfiks zənciri
+/// ilə birlikdə) oxunur, binar operatorlar isə öz önəmlərinə görə birləşdirilir.
 pub fn parse_expression(tokens: &mut Tokens) -> Result<Expr, ParserError> {
-    let expr = parse_single_expr(tokens)?;
-
-    parse_binary_op_with_precedence(expr, tokens, 0)
+    parse_binary_op_with_precedence(tokens, 0)
 }
 
+/// Önəmi `min_precedence`-dən aşağı olan operatora çatdıqda dayanır. Eyni önəmli
+/// operatorlar soldan birləşir: `a - b - c` = `(a - b) - c`.
 fn parse_binary_op_with_precedence(
-    mut left: Expr,
     tokens: &mut Tokens,
     min_precedence: u8,
 ) -> Result<Expr, ParserError> {
-    if let Some(SpannedToken {
-        token: Token::LParen,
-        ..
-    }) = tokens.peek()
+    let mut left = parse_single_expr(tokens)?;
+    while let Some(op) = tokens
+        .peek()
+        .and_then(|token| binary_operation(&token.token))
     {
-        tokens.next();
-        let mut args = Vec::new();
-        loop {
-            match tokens.peek() {
-                Some(SpannedToken {
-                    token: Token::RParen,
-                    ..
-                }) => {
-                    tokens.next();
-                    break;
-                }
-                Some(SpannedToken {
-                    token: Token::Comma,
-                    ..
-                }) => {
-                    tokens.next();
-                }
-                None => {
-                    return Err(ParserError::RParenNotFound(Token::Eof));
-                }
-                _ => {
-                    args.push(parse_expression(tokens)?);
-                }
-            }
-        }
-        return Ok(Expr::Call {
-            target: None,
-            name: Box::new(left),
-            args,
-        });
-    }
-    loop {
-        let op = match tokens.peek() {
-            Some(SpannedToken {
-                token: Token::Add, ..
-            }) => Operation::Add,
-            Some(SpannedToken {
-                token: Token::Subtract,
-                ..
-            }) => Operation::Subtract,
-            Some(SpannedToken {
-                token: Token::Multiply,
-                ..
-            }) => Operation::Multiply,
-            Some(SpannedToken {
-                token: Token::Divide,
-                ..
-            }) => Operation::Divide,
-            Some(SpannedToken {
-                token: Token::Modulo,
-                ..
-            }) => Operation::Modulo,
-            Some(SpannedToken {
-                token: Token::Greater,
-                ..
-            }) => Operation::Greater,
-            Some(SpannedToken {
-                token: Token::Less, ..
-            }) => Operation::Less,
-            Some(SpannedToken {
-                token: Token::Equal,
-                ..
-            }) => Operation::Equal,
-            Some(SpannedToken {
-                token: Token::NotEqual,
-                ..
-            }) => Operation::NotEqual,
-            Some(SpannedToken {
-                token: Token::And, ..
-            }) => Operation::And,
-            Some(SpannedToken {
-                token: Token::Or, ..
-            }) => Operation::Or,
-            Some(SpannedToken {
-                token: Token::GreaterEqual,
-                ..
-            }) => Operation::GreaterEqual,
-            Some(SpannedToken {
-                token: Token::LessEqual,
-                ..
-            }) => Operation::LessEqual,
-            _ => break,
-        };
-
         let precedence = operator_precedence(&op);
         if precedence < min_precedence {
             break;
         }
-
         tokens.next();
-        let rhs = parse_single_expr(tokens)?;
 
-        let right = parse_binary_op_with_precedence(rhs, tokens, precedence + 1)?;
-        left = Expr::BinaryOp {
-            left: Box::new(left),
-            right: Box::new(right),
-            op,
-        };
+        let right = parse_binary_op_with_precedence(tokens, precedence + 1)?;
+        let span = left.span.to(&right.span);
+        left = Expr::new(
+            ExprKind::BinaryOp {
+                left: Box::new(left),
+                right: Box::new(right),
+                op,
+            },
+            span,
+        );
     }
 
     Ok(left)
 }
+fn binary_operation(token: &Token) -> Option<Operation> {
+    let op = match token {
+        Token::Add => Operation::Add,
+        Token::Subtract => Operation::Subtract,
+        Token::Multiply => Operation::Multiply,
+        Token::Divide => Operation::Divide,
+        Token::Modulo => Operation::Modulo,
+        Token::Greater => Operation::Greater,
+        Token::Less => Operation::Less,
+        Token::Equal => Operation::Equal,
+        Token::NotEqual => Operation::NotEqual,
+        Token::And | Token::DoubleAnd => Operation::And,
+        Token::Or | Token::DoubleOr => Operation::Or,
+        Token::GreaterEqual => Operation::GreaterEqual,
+        Token::LessEqual => Operation::LessEqual,
+        _ => return None,
+    };
+    Some(op)
+}
 fn operator_precedence(op: &Operation) -> u8 {
     match op {
-        Operation::Multiply | Operation::Divide | Operation::Modulo => 3,
-        Operation::Add | Operation::Subtract => 2,
+        Operation::Multiply | Operation::Divide | Operation::Modulo => 5,
+        Operation::Add | Operation::Subtract => 4,
         Operation::Equal
         | Operation::NotEqual
         | Operation::Less
         | Operation::Greater
         | Operation::LessEqual
-        | Operation::GreaterEqual => 1,
-        _ => 0,
+        | Operation::GreaterEqual => 3,
+        Operation::And => 2,
+        _ => 1,
     }
 }
@@ -4,14 +4,15 @@ use tokenizer::{
 };
 
 use crate::{
-    ast::{Else, IF, Statement},
+    ast::{Else, IF, Statement, StatementKind},
     binary_op::{parse_expression, parse_statement},
     errors::ParserError,
-    helpers::expect_token,
+    helpers::{expect_token, recover},
 };
 
 pub fn parse_block(tokens: &mut Tokens) -> Result<Vec<Statement>, ParserError> {
     let mut block = Vec::new();
+    let mut errors = Vec::new();
     let mut indent = 0;
 
     while let Some(tok) = tokens.peek() {
@@ -33,17 +34,26 @@ pub fn parse_block(tokens: &mut Tokens) -> Result<Vec<Statement>, ParserError> {
                 tokens.next();
             }
             Token::Eof => break,
-            _ => block.push(parse_statement(tokens)?),
+            _ => match parse_statement(tokens) {
+                Ok(statement) => block.push(statement),
+                Err(error) => recover(tokens, &mut errors, error),
+            },
         }
     }
+    if !errors.is_empty() {
+        return Err(ParserError::Multiple(errors));
+    }
     Ok(block)
 }
 
 pub fn parse_if_expr(tokens: &mut Tokens) -> Result<Statement, ParserError> {
+    let start = tokens.peek_span();
     tokens.next();
     let condition = parse_expression(tokens)?;
     expect_token(tokens, Token::Newline)?;
-    let then_branch = parse_block(tokens)?;
+    // Budaqlardakı xətalar toplanır ki, sonrakı `else` hissələri də oxunsun.
+    let mut errors = Vec::new();
+    let then_branch = parse_branch(tokens, &mut errors);
 
     let mut else_if_branch: Vec<IF> = Vec::new();
     let mut else_branch = None;
@@ -55,9 +65,16 @@ pub fn parse_if_expr(tokens: &mut Tokens) -> Result<Statement, ParserError> {
                 ..
             }) => {
                 tokens.next();
-                let cond = parse_expression(tokens)?;
-                expect_token(tokens, Token::Newline)?;
-                let then_b = parse_block(tokens)?;
+                let header = parse_expression(tokens)
+                    .and_then(|cond| expect_token(tokens, Token::Newline).map(|_| cond));
+                let cond = match header {
+                    Ok(cond) => cond,
+                    Err(error) => {
+                        recover(tokens, &mut errors, error);
+                        break;
+                    }
+                };
+                let then_b = parse_branch(tokens, &mut errors);
                 else_if_branch.push(IF {
                     condition: Box::new(cond),
                     body: then_b,
@@ -67,20 +84,37 @@ pub fn parse_if_expr(tokens: &mut Tokens) -> Result<Statement, ParserError> {
                 token: Token::Else, ..
             }) => {
                 tokens.next();
-                expect_token(tokens, Token::Newline)?;
-                let then_b = parse_block(tokens)?;
+                if let Err(error) = expect_token(tokens, Token::Newline) {
+                    recover(tokens, &mut errors, error);
+                    break;
+                }
+                let then_b = parse_branch(tokens, &mut errors);
                 else_branch = Some(Else { body: then_b });
             }
             _ => break,
         }
     }
+    if !errors.is_empty() {
+        return Err(ParserError::Multiple(errors));
+    }
 
-    Ok(Statement::Condition {
-        main: IF {
-            condition: Box::new(condition),
-            body: then_branch,
+    Ok(Statement::new(
+        StatementKind::Condition {
+            main: IF {
+                condition: Box::new(condition),
+                body: then_branch,
+            },
+            elif: else_if_branch,
+            other: else_branch,
         },
-        elif: else_if_branch,
-        other: else_branch,
+        tokens.span_from(&start),
+    ))
+}
+
+/// Budağın xətalarını `errors`-a əlavə edib boş gövdə ilə davam edir.
+fn parse_branch(tokens: &mut Tokens, errors: &mut Vec<ParserError>) -> Vec<Statement> {
+    parse_block(tokens).unwrap_or_else(|error| {
+        errors.extend(error.into_errors());
+        Vec::new()
     })
 }
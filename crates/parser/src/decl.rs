@@ -1,7 +1,7 @@
 use std::rc::Rc;
 
 use crate::{
-    ast::{Atom, Expr, Statement},
+    ast::{Atom, Expr, ExprKind, Statement, StatementKind},
     binary_op::parse_expression,
     errors::ParserError,
     helpers::expect_token,
@@ -14,8 +14,11 @@ use tokenizer::{
 };
 
 pub fn parse_decl(tokens: &mut Tokens, is_mutable: bool) -> Result<Statement, ParserError> {
+    let start = tokens.peek_span();
     tokens.next();
+    let type_start = tokens.peek_span();
     let mut data_typ = parse_type(tokens)?;
+    let type_span = tokens.span_from(&type_start);
 
     let name = match tokens.next() {
         Some(SpannedToken {
@@ -23,37 +26,48 @@ pub fn parse_decl(tokens: &mut Tokens, is_mutable: bool) -> Result<Statement, Pa
             span: _,
             ..
         }) => name,
-        Some(other) => return Err(ParserError::DeclNameNotFound(other.token)),
-        None => return Err(ParserError::DeclNameNotFound(Token::Eof)),
+        Some(other) => return Err(ParserError::DeclNameNotFound(other.span, other.token)),
+        None => {
+            return Err(ParserError::DeclNameNotFound(
+                tokens.peek_span(),
+                Token::Eof,
+            ));
+        }
     };
     expect_token(tokens, Token::Assign)?;
     let value = parse_expression(tokens)?;
-    match (&value, &data_typ) {
-        (Expr::String(_s), Type::String(_d)) if is_mutable => {
+    match (&value.kind, &data_typ) {
+        (ExprKind::String(_s), Type::String(_d)) if is_mutable => {
             data_typ = Type::String(StringEnum::LiteralString)
         }
-        (Expr::String(_s), Type::String(_d)) => {
+        (ExprKind::String(_s), Type::String(_d)) => {
             data_typ = Type::String(StringEnum::LiteralConstString)
         }
         _ => {}
     }
 
-    Ok(Statement::Decl {
-        name: Atom::from(name),
-        typ: Rc::new(data_typ),
-        value: Box::new(value),
-        is_mutable,
-    })
+    Ok(Statement::new(
+        StatementKind::Decl {
+            name: Atom::from(name),
+            typ: Rc::new(data_typ),
+            type_span,
+            value: Box::new(value),
+            is_mutable,
+            doc: None,
+        },
+        tokens.span_from(&start),
+    ))
 }
 
 pub fn is_primite_value_to_type(expr: &Expr, is_mutable: bool) -> Type {
-    match expr {
-        Expr::Number(_) => Type::Integer,
-        Expr::Float(_) => Type::Float,
-        Expr::Bool(_) => Type::Bool,
-        Expr::Char(_) => Type::Char,
-        Expr::String(_) if is_mutable => Type::String(StringEnum::LiteralString),
-        Expr::String(_) => Type::String(StringEnum::LiteralConstString),
+    match &expr.kind {
+        ExprKind::Number(_) => Type::Integer,
+        ExprKind::TypedNumber { typ, .. } => typ.clone(),
+        ExprKind::Float(_) => Type::Float,
+        ExprKind::Bool(_) => Type::Bool,
+        ExprKind::Char(_) => Type::Char,
+        ExprKind::String(_) if is_mutable => Type::String(StringEnum::LiteralString),
+        ExprKind::String(_) => Type::String(StringEnum::LiteralConstString),
         _ => Type::Any,
     }
 }
@@ -0,0 +1,54 @@
+use crate::{
+    ast::{Expr, ExprKind, Statement, StatementKind},
+    binary_op::parse_statement,
+    errors::ParserError,
+    helpers::skip_newlines,
+};
+use tokenizer::{
+    iterator::{SpannedToken, Tokens},
+    tokens::Token,
+};
+
+/// Ardıcıl `///` şərhlərini toplayır və onları növbəti funksiya, dəyər və ya
+/// obyekt tərifinə bağlayır. Sənədləşdirilə bilməyən ifadədən əvvəl gələn şərh
+/// ayrıca [`ExprKind::Comment`] kimi qalır.
+pub fn parse_documented(tokens: &mut Tokens) -> Result<Statement, ParserError> {
+    let start = tokens.peek_span();
+    let mut lines = Vec::new();
+    while let Some(SpannedToken {
+        token: Token::Comment(line),
+        ..
+    }) = tokens.peek()
+    {
+        lines.push(line.clone());
+        tokens.next();
+        skip_newlines(tokens)?;
+    }
+    let text = lines.join("\n");
+
+    let is_documentable = matches!(
+        tokens.peek().map(|t| &t.token),
+        Some(
+            Token::FunctionDef
+                | Token::ConstantDecl
+                | Token::MutableDecl
+                | Token::Object
+                | Token::At
+        )
+    );
+    if !is_documentable {
+        let span = tokens.span_from(&start);
+        let comment = Expr::new(ExprKind::Comment(text), span.clone());
+        return Ok(Statement::new(StatementKind::Expr(comment), span));
+    }
+
+    let mut stmt = parse_statement(tokens)?;
+    match &mut stmt.kind {
+        StatementKind::Decl { doc, .. }
+        | StatementKind::FunctionDef { doc, .. }
+        | StatementKind::ExternalFunctionDef { doc, .. }
+        | StatementKind::StructDef { doc, .. } => *doc = Some(text),
+        _ => {}
+    }
+    Ok(stmt)
+}
@@ -1,133 +1,130 @@
 use core::fmt;
-use std::{fmt::Display, rc::Rc};
+use std::fmt::Display;
 
+use locale::t;
 use tokenizer::{errors::LexerError, iterator::SourceSpan, tokens::Token};
 
 #[derive(Debug)]
 pub enum ParserError {
     LexerError(LexerError),
-    UnexpectedToken(SourceSpan, Token),
+    /// Üçüncü sahə tokenə bənzəyən açar söz, əgər varsa.
+    UnexpectedToken(SourceSpan, Token, Option<String>),
     UnexpectedEOF,
     NotUserDirectValue,
-    MethodNameNotFound(Token),
-    ExpectedToken(Token, Token),
-    LoopVarNameNotFound(Token),
-    StructNameNotFound(Token),
-    ArrayExpected(char, Token),
-    FunctionNameNotFound(Token),
-    ParameterNameNotFound(Token),
-    ParameterNotExpected(Token),
-    RParenNotFound(Token),
-    StructNotExpected(Token),
-    BinaryOpLeftNotExpected(String),
-    StructInitArgNotExpected(Token),
-    StructInitArgSeparatorNotFound,
-    DeclNameNotFound(Token),
-    DeclAssignNotFound(Token),
-    ObjectTypeNotExpected(Rc<Token>),
-    ArrayNotClosed(Token),
-    EnumDeclNameNotFound(Token),
-    EnumNewLineNotFound(Token),
-    UnionDeclNameNotFound(Token),
-    FunctionAlreadyAsigned(String),
+    MethodNameNotFound(SourceSpan, Token),
+    /// Gözlənilən və tapılan token; span tapılanındır.
+    ExpectedToken(SourceSpan, Token, Token),
+    ArrayExpected(SourceSpan, char, Token),
+    FunctionNameNotFound(SourceSpan, Token),
+    ParameterNameNotFound(SourceSpan, Token),
+    ParameterNotExpected(SourceSpan, Token),
+    RParenNotFound(SourceSpan, Token),
+    DeclNameNotFound(SourceSpan, Token),
+    /// `${x:...}` içində `:`-dan sonrakı mətn formata uyğun deyil.
+    InvalidFormatSpec(SourceSpan, String),
+    /// Bərpadan sonra toplanmış bir neçə xəta, mənbədəki sıra ilə.
+    Multiple(Vec<ParserError>),
+}
+
+impl ParserError {
+    /// Xətanın mənbədəki yeri, əgər məlumdursa.
+    pub fn span(&self) -> Option<&SourceSpan> {
+        match self {
+            ParserError::LexerError(error) => error.span(),
+            ParserError::UnexpectedToken(span, ..)
+            | ParserError::InvalidFormatSpec(span, _)
+            | ParserError::MethodNameNotFound(span, _)
+            | ParserError::ExpectedToken(span, ..)
+            | ParserError::ArrayExpected(span, ..)
+            | ParserError::FunctionNameNotFound(span, _)
+            | ParserError::ParameterNameNotFound(span, _)
+            | ParserError::ParameterNotExpected(span, _)
+            | ParserError::RParenNotFound(span, _)
+            | ParserError::DeclNameNotFound(span, _) => Some(span),
+            ParserError::Multiple(errors) => errors.first().and_then(ParserError::span),
+            _ => None,
+        }
+    }
+
+    /// Tək xətanı olduğu kimi, bir neçəsini isə [`ParserError::Multiple`] kimi qaytarır.
+    pub fn collect(mut errors: Vec<ParserError>) -> ParserError {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            ParserError::Multiple(errors)
+        }
+    }
+
+    /// Toplanmış xətaları tək-tək siyahı kimi qaytarır.
+    pub fn into_errors(self) -> Vec<ParserError> {
+        match self {
+            ParserError::Multiple(errors) => errors,
+            error => vec![error],
+        }
+    }
+
+    /// Dəyişməz xəta kodu. Toplanmış xətalar üçün `None`.
+    pub fn code(&self) -> Option<&'static str> {
+        match self {
+            ParserError::LexerError(error) => Some(error.code()),
+            ParserError::UnexpectedToken(..) => Some("AZ0101"),
+            ParserError::UnexpectedEOF => Some("AZ0102"),
+            ParserError::NotUserDirectValue => Some("AZ0103"),
+            ParserError::MethodNameNotFound(..) => Some("AZ0104"),
+            ParserError::ExpectedToken(..) => Some("AZ0105"),
+            ParserError::ArrayExpected(..) => Some("AZ0108"),
+            ParserError::FunctionNameNotFound(..) => Some("AZ0109"),
+            ParserError::ParameterNameNotFound(..) => Some("AZ0110"),
+            ParserError::ParameterNotExpected(..) => Some("AZ0111"),
+            ParserError::RParenNotFound(..) => Some("AZ0112"),
+            ParserError::DeclNameNotFound(..) => Some("AZ0117"),
+            ParserError::InvalidFormatSpec(..) => Some("AZ0125"),
+            ParserError::Multiple(_) => None,
+        }
+    }
+
+    /// Yer göstərilmədən xəta mətni, cari dildə.
+    pub fn message(&self) -> String {
+        let code = self.code().unwrap_or_default();
+        match self {
+            ParserError::LexerError(error) => error.message(),
+            ParserError::UnexpectedToken(_, token, _)
+            | ParserError::MethodNameNotFound(_, token)
+            | ParserError::FunctionNameNotFound(_, token)
+            | ParserError::ParameterNameNotFound(_, token)
+            | ParserError::ParameterNotExpected(_, token)
+            | ParserError::RParenNotFound(_, token)
+            | ParserError::DeclNameNotFound(_, token) => t!(code, token = token),
+            ParserError::ExpectedToken(_, expected, found) => {
+                t!(code, expected = expected, found = found)
+            }
+            ParserError::ArrayExpected(_, expected, token) => {
+                t!(code, expected = expected, token = token)
+            }
+            ParserError::InvalidFormatSpec(_, spec) => t!(code, spec = spec),
+            ParserError::UnexpectedEOF | ParserError::NotUserDirectValue => t!(code),
+            ParserError::Multiple(_) => self.to_string(),
+        }
+    }
 }
 
 impl Display for ParserError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ParserError::LexerError(error) => write!(f, "{}", error),
-            ParserError::UnexpectedToken(span, token) => {
-                write!(f, "{span} Gözlənilməyən token '{token}'")
-            }
-            ParserError::UnexpectedEOF => write!(f, "Gözlənilməyən EOF"),
-            ParserError::NotUserDirectValue => write!(
-                f,
-                "Bir başa mətn, rəqəm və ya kəsr ədəd istifadə edə bilməzsiniz"
-            ),
-            ParserError::MethodNameNotFound(token) => {
-                write!(
-                    f,
-                    "Metod və ya sahə adı gözlənilirdi amma bu tapıldı: '{token}'"
-                )
-            }
-            ParserError::ExpectedToken(expected, found) => {
-                write!(f, "Gözlənilirdi '{expected}', tapıldı '{found}'")
-            }
-            ParserError::LoopVarNameNotFound(token) => {
-                write!(
-                    f,
-                    "Dövr yaradılarkən dəyişən adı gözlənilirdi, tapıldı: '{token}'"
-                )
-            }
-            ParserError::StructNameNotFound(token) => {
-                write!(f, "Struktur adı gözlənilirdi, tapıldı: '{token}'")
-            }
-            ParserError::ArrayExpected(char, token) => {
-                write!(f, "Siyahı üçün '{char}', gözlənilirdi tapıldı '{token}'")
-            }
-            ParserError::FunctionNameNotFound(token) => {
-                write!(f, "Funksiya adı gözlənilirdi, tapıldı: '{token}'")
-            }
-            ParserError::ParameterNameNotFound(token) => {
-                write!(f, "Parametr adı gözlənilirdi, tapıldı: '{token}'")
-            }
-            ParserError::ParameterNotExpected(token) => {
-                write!(
-                    f,
-                    "Parametrdən sonra ',' və ya ')' gözlənilirdi, tapıldı: '{token}'"
-                )
-            }
-            ParserError::RParenNotFound(token) => {
-                write!(f, "')' gözlənilirdi, tapıldı: '{token}'")
-            }
-            ParserError::StructNotExpected(token) => {
-                write!(f, "Struct daxilində gözlənilməz token: '{token}'")
-            }
-            ParserError::BinaryOpLeftNotExpected(string) => {
-                write!(f, "Sol tərəf gözlənilirdi, tapıldı: '{string}'")
-            }
-            ParserError::StructInitArgSeparatorNotFound => {
-                write!(
-                    f,
-                    "Struct init argümentləri arasında ',' və ya '}}' gözlənilirdi"
-                )
-            }
-            ParserError::StructInitArgNotExpected(token) => {
-                write!(
-                    f,
-                    "Struct init argümentləri arasında ':' gözlənilirdi, tapıldı: '{token}'"
-                )
-            }
-            ParserError::DeclNameNotFound(token) => {
-                write!(f, "Dəyişən adı gözlənilirdi, tapıldı: '{token}'")
-            }
-            ParserError::DeclAssignNotFound(token) => {
-                write!(f, "'=' operatoru gözlənilirdi, tapıldı: '{token}'")
-            }
-            ParserError::ObjectTypeNotExpected(token) => {
-                write!(f, "Obyekt tipi gözlənilirdi, tapıldı: '{token}'")
-            }
-            ParserError::ArrayNotClosed(token) => {
-                write!(f, "Siyahı sonuna ']' gözlənilirdi, tapıldı: '{token}'")
-            }
-            ParserError::EnumDeclNameNotFound(token) => {
-                write!(
-                    f,
-                    "tip`-dən sonra identifikator gözlənilirdi, tapıldı: '{token}'"
-                )
-            }
-            ParserError::EnumNewLineNotFound(token) => {
-                write!(
-                    f,
-                    "Enum tərifindən sonra `newline` gözlənilirdi, tapıldı: '{token}'"
-                )
-            }
-            ParserError::UnionDeclNameNotFound(token) => {
-                write!(f, "Birləşik tip adı gözlənilirdi, tapıldı: '{token}'")
-            }
-            ParserError::FunctionAlreadyAsigned(s) => {
-                write!(f, "{s} adlı funksiya onunsuzda var")
-            }
+            ParserError::Multiple(errors) => {
+                for (index, error) in errors.iter().enumerate() {
+                    if index > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{error}")?;
+                }
+                Ok(())
+            }
+            error => match error.span() {
+                Some(span) => write!(f, "{span} {}", error.message()),
+                None => write!(f, "{}", error.message()),
+            },
         }
     }
 }
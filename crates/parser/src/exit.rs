@@ -0,0 +1,19 @@
+use crate::{
+    ast::{Statement, StatementKind},
+    binary_op::parse_expression,
+    errors::ParserError,
+    helpers::expect_token,
+};
+use tokenizer::{iterator::Tokens, tokens::Token};
+
+pub fn parse_exit(tokens: &mut Tokens) -> Result<Statement, ParserError> {
+    let start = tokens.peek_span();
+    tokens.next();
+    expect_token(tokens, Token::LParen)?;
+    let code = parse_expression(tokens)?;
+    expect_token(tokens, Token::RParen)?;
+    Ok(Statement::new(
+        StatementKind::Exit(code),
+        tokens.span_from(&start),
+    ))
+}
@@ -1,18 +1,21 @@
 use crate::{
-    ast::{Expr, Operation, Statement},
+    ast::{Expr, ExprKind, Operation, Statement},
     binary_op::{parse_expression, parse_statement},
     errors::ParserError,
+    helpers::{expect_token, recover},
     identifier::parse_identifier,
-    literal_parse::literals_parse,
+    literal_parse::{literals_parse, number_literal},
+    postfix::parse_postfix,
     template::parse_template_string_expr,
 };
 use tokenizer::{
     iterator::{SpannedToken, Tokens},
-    tokens::Token,
+    tokens::{NumberSuffix, Token},
 };
 
 pub fn parse_expression_block(tokens: &mut Tokens) -> Result<Vec<Statement>, ParserError> {
     let mut ast: Vec<Statement> = vec![];
+    let mut errors = Vec::new();
 
     while let Some(token) = tokens.peek() {
         match token {
@@ -32,110 +35,150 @@ pub fn parse_expression_block(tokens: &mut Tokens) -> Result<Vec<Statement>, Par
                 token: Token::Number(_),
                 ..
             }
+            | SpannedToken {
+                token: Token::TypedNumber(..),
+                ..
+            }
             | SpannedToken {
                 token: Token::Float(_),
                 ..
+            }
+            | SpannedToken {
+                token: Token::CharLiteral(_),
+                ..
             } => {
-                return Err(ParserError::NotUserDirectValue);
+                recover(tokens, &mut errors, ParserError::NotUserDirectValue);
             }
             SpannedToken {
                 token: Token::Eof, ..
             } => {
                 break;
             }
-            _ => {
-                let expr = parse_statement(tokens)?;
-                ast.push(expr);
-            }
+            _ => match parse_statement(tokens) {
+                Ok(expr) => ast.push(expr),
+                Err(error) => recover(tokens, &mut errors, error),
+            },
         }
     }
+    if !errors.is_empty() {
+        return Err(ParserError::collect(errors));
+    }
     Ok(ast)
 }
 
+/// Tək operand: prefiks `-` və `!`, sonra əsas ifadə və onun postfiks zənciri
+/// (`f(1)("a")`, `a[0]`, `a.b()`). Prefiks postfiksdən zəifdir: `-f(x)` = `-(f(x))`.
 pub fn parse_single_expr(tokens: &mut Tokens) -> Result<Expr, ParserError> {
+    let start = tokens.peek_span();
+    let op = match tokens.peek().map(|token| &token.token) {
+        Some(Token::Subtract) => Operation::Subtract,
+        Some(Token::Not) => Operation::Not,
+        _ => {
+            let primary = parse_primary(tokens)?;
+            return parse_postfix(tokens, primary);
+        }
+    };
+    tokens.next();
+
+    // Ədəd literalının mənfisi elə literalın özüdür: `-128tinyint` tək literal kimi oxunur.
+    let signed_literal = match tokens.peek().map(|token| &token.token) {
+        Some(Token::Number(_)) => true,
+        Some(Token::TypedNumber(_, suffix)) => *suffix != NumberSuffix::Natural,
+        _ => false,
+    };
+    if op == Operation::Subtract && signed_literal {
+        let token = tokens.next().ok_or(ParserError::UnexpectedEOF)?;
+        let literal = number_literal(token, true)?;
+        let literal = Expr::new(literal.kind, tokens.span_from(&start));
+        return parse_postfix(tokens, literal);
+    }
+
+    let Expr { kind, span } = parse_single_expr(tokens)?;
+    let kind = match (op, kind) {
+        (Operation::Subtract, ExprKind::Float(f)) => ExprKind::Float(-f),
+        (op, kind) => ExprKind::UnaryOp {
+            op,
+            expr: Box::new(Expr::new(kind, span)),
+        },
+    };
+    Ok(Expr::new(kind, tokens.span_from(&start)))
+}
+
+fn parse_primary(tokens: &mut Tokens) -> Result<Expr, ParserError> {
     let token = tokens.next().ok_or(ParserError::UnexpectedEOF)?;
-    println!("{token:?}");
-    match token {
+    let span = token.span.clone();
+    let kind = match token {
         SpannedToken {
             token: Token::StringLiteral(_),
             ..
-        } => literals_parse(token, tokens),
+        } => return literals_parse(token, tokens),
+        SpannedToken {
+            token: Token::CharLiteral(_),
+            ..
+        } => return literals_parse(token, tokens),
         SpannedToken {
             token: Token::Float(_num),
             ..
-        } => literals_parse(token, tokens),
+        } => return literals_parse(token, tokens),
         SpannedToken {
             token: Token::Number(_num),
             ..
-        } => literals_parse(token, tokens),
+        } => return literals_parse(token, tokens),
+        SpannedToken {
+            token: Token::TypedNumber(..),
+            ..
+        } => return literals_parse(token, tokens),
         SpannedToken {
             token: Token::True, ..
-        } => Ok(Expr::Bool(true)),
+        } => ExprKind::Bool(true),
         SpannedToken {
             token: Token::False,
             ..
-        } => Ok(Expr::Bool(false)),
+        } => ExprKind::Bool(false),
 
         SpannedToken {
             token: Token::Break,
             ..
-        } => Ok(Expr::Break),
+        } => ExprKind::Break,
         SpannedToken {
             token: Token::Comment(s),
             ..
-        } => Ok(Expr::Comment(s.clone())),
+        } => ExprKind::Comment(s.clone()),
         SpannedToken {
             token: Token::Return,
             ..
         } => {
             let returned_value = parse_expression(tokens)?;
-            Ok(Expr::Return(Box::new(returned_value)))
+            ExprKind::Return(Box::new(returned_value))
         }
         SpannedToken {
             token: Token::Continue,
             ..
-        } => Ok(Expr::Continue),
+        } => ExprKind::Continue,
 
         SpannedToken {
             token: Token::Backtick,
             ..
-        } => parse_template_string_expr(tokens),
+        } => return parse_template_string_expr(tokens, span),
         SpannedToken {
             token: Token::Identifier(s),
             ..
-        } => parse_identifier(tokens, s.to_string()),
-
-        SpannedToken {
-            token: Token::ListStart,
-            ..
-        } => literals_parse(token, tokens),
-
+        } => return Ok(parse_identifier(s, span)),
         SpannedToken {
-            token: Token::Subtract,
+            token: Token::LParen,
             ..
         } => {
-            let expr = parse_single_expr(tokens)?;
-            match expr {
-                Expr::Number(i) => Ok(Expr::Number(-i)),
-                Expr::Float(f) => Ok(Expr::Float(-f)),
-                Expr::VariableRef { name, symbol } => Ok(Expr::BinaryOp {
-                    left: Box::new(Expr::Number(-1)),
-                    right: Box::new(Expr::VariableRef { name, symbol }),
-                    op: crate::ast::Operation::Multiply,
-                }),
-                _ => Err(ParserError::UnexpectedEOF),
-            }
+            let inner = parse_expression(tokens)?;
+            expect_token(tokens, Token::RParen)?;
+            inner.kind
         }
+
         SpannedToken {
-            token: Token::Not, ..
-        } => {
-            let expr = parse_single_expr(tokens)?;
-            Ok(Expr::UnaryOp {
-                op: Operation::Not,
-                expr: Box::new(expr),
-            })
-        }
+            token: Token::ListStart,
+            ..
+        } => return literals_parse(token, tokens),
 
-        other => Err(ParserError::UnexpectedToken(other.span, other.token)),
-    }
+        other => return Err(ParserError::UnexpectedToken(other.span, other.token, None)),
+    };
+    Ok(Expr::new(kind, tokens.span_from(&span)))
 }
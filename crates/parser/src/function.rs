@@ -1,13 +1,13 @@
 use crate::{
-    ast::{Atom, Parameter, Statement},
+    ast::{Atom, Parameter, Statement, StatementKind},
     binary_op::parse_statement,
     errors::ParserError,
-    helpers::expect_token,
+    helpers::{expect_token, recover},
     shared_ast::Type,
     types::parse_type,
 };
 use tokenizer::{
-    iterator::{SpannedToken, Tokens},
+    iterator::{SourceSpan, SpannedToken, Tokens},
     tokens::Token,
 };
 
@@ -17,8 +17,9 @@ pub fn parse_link_directive(tokens: &mut Tokens) -> Result<Atom, ParserError> {
             token: Token::Identifier(ref s),
             ..
         }) if s == "link" => {}
-        Some(SpannedToken { token: other, .. }) => {
+        Some(SpannedToken { token: other, span }) => {
             return Err(ParserError::ExpectedToken(
+                span,
                 Token::Identifier("link".into()),
                 other,
             ));
@@ -31,9 +32,10 @@ pub fn parse_link_directive(tokens: &mut Tokens) -> Result<Atom, ParserError> {
             token: Token::StringLiteral(s),
             ..
         }) => s,
-        Some(SpannedToken { token: other, .. }) => {
+        Some(SpannedToken { token: other, span }) => {
             return Err(ParserError::ExpectedToken(
-                Token::StringLiteral(String::new()),
+                span,
+                Token::StringLiteral(Atom::default()),
                 other,
             ));
         }
@@ -44,16 +46,21 @@ pub fn parse_link_directive(tokens: &mut Tokens) -> Result<Atom, ParserError> {
     Ok(Atom::from(lib_name))
 }
 
-pub fn parse_external_function_def(tokens: &mut Tokens) -> Result<Statement, ParserError> {
+/// `start` artıq götürülmüş `@link` direktivinin yeridir.
+pub fn parse_external_function_def(
+    tokens: &mut Tokens,
+    start: SourceSpan,
+) -> Result<Statement, ParserError> {
     expect_token(tokens, Token::LParen)?;
     let library = match tokens.next() {
         Some(SpannedToken {
             token: Token::StringLiteral(s),
             ..
         }) => s,
-        Some(SpannedToken { token: other, .. }) => {
+        Some(SpannedToken { token: other, span }) => {
             return Err(ParserError::ExpectedToken(
-                Token::StringLiteral(String::new()),
+                span,
+                Token::StringLiteral(Atom::default()),
                 other,
             ));
         }
@@ -70,8 +77,8 @@ pub fn parse_external_function_def(tokens: &mut Tokens) -> Result<Statement, Par
             ..
         }) => n,
         None => return Err(ParserError::UnexpectedEOF),
-        Some(SpannedToken { token: other, .. }) => {
-            return Err(ParserError::FunctionNameNotFound(other));
+        Some(SpannedToken { token: other, span }) => {
+            return Err(ParserError::FunctionNameNotFound(span, other));
         }
     };
     expect_token(tokens, Token::LParen)?;
@@ -80,24 +87,30 @@ pub fn parse_external_function_def(tokens: &mut Tokens) -> Result<Statement, Par
     while let Some(tok) = tokens.peek() {
         match &tok.token {
             Token::ConstantDecl | Token::MutableDecl | Token::Identifier(_) => {
+                let param_start = tok.span.clone();
                 let is_mutable = matches!(tok.token, Token::MutableDecl);
                 if matches!(tok.token, Token::MutableDecl | Token::ConstantDecl) {
                     tokens.next();
                 }
+                let type_start = tokens.peek_span();
                 let param_type = param_typ(tokens, is_mutable)?;
+                let type_span = tokens.span_from(&type_start);
                 let param_name = match tokens.next() {
                     Some(SpannedToken {
                         token: Token::Identifier(s),
                         ..
                     }) => s,
-                    other => {
-                        return Err(ParserError::ParameterNameNotFound(other.unwrap().token));
+                    Some(other) => {
+                        return Err(ParserError::ParameterNameNotFound(other.span, other.token));
                     }
+                    None => return Err(ParserError::UnexpectedEOF),
                 };
                 params.push(Parameter {
                     name: Atom::from(param_name),
                     typ: param_type,
                     is_pointer: is_mutable,
+                    span: tokens.span_from(&param_start),
+                    type_span,
                 });
                 match tokens.peek() {
                     Some(SpannedToken {
@@ -111,8 +124,11 @@ pub fn parse_external_function_def(tokens: &mut Tokens) -> Result<Statement, Par
                         ..
                     }) => break,
                     None => return Err(ParserError::UnexpectedEOF),
-                    Some(SpannedToken { token: other, .. }) => {
-                        return Err(ParserError::ParameterNotExpected(other.clone()));
+                    Some(SpannedToken { token: other, span }) => {
+                        return Err(ParserError::ParameterNotExpected(
+                            span.clone(),
+                            other.clone(),
+                        ));
                     }
                 }
             }
@@ -123,23 +139,32 @@ pub fn parse_external_function_def(tokens: &mut Tokens) -> Result<Statement, Par
 
             Token::RParen => break,
 
-            other => return Err(ParserError::RParenNotFound(other.clone())),
+            other => return Err(ParserError::RParenNotFound(tok.span.clone(), other.clone())),
         }
     }
     expect_token(tokens, Token::RParen)?;
     expect_token(tokens, Token::Colon)?;
+    let type_start = tokens.peek_span();
     let return_type = parse_type(tokens)?;
+    let return_type_span = tokens.span_from(&type_start);
+    let span = tokens.span_from(&start);
     expect_token(tokens, Token::Newline)?;
 
-    Ok(Statement::ExternalFunctionDef {
-        name: Atom::from(name),
-        return_typ: return_type,
-        params,
-        library: Atom::from(library),
-    })
+    Ok(Statement::new(
+        StatementKind::ExternalFunctionDef {
+            name: Atom::from(name),
+            return_typ: return_type,
+            return_type_span,
+            params,
+            library: Atom::from(library),
+            doc: None,
+        },
+        span,
+    ))
 }
 
 pub fn parse_function_def(tokens: &mut Tokens) -> Result<Statement, ParserError> {
+    let start = tokens.peek_span();
     tokens.next();
     let name = match tokens.next() {
         Some(SpannedToken {
@@ -147,8 +172,8 @@ pub fn parse_function_def(tokens: &mut Tokens) -> Result<Statement, ParserError>
             ..
         }) => n,
         None => return Err(ParserError::UnexpectedEOF),
-        Some(SpannedToken { token: other, .. }) => {
-            return Err(ParserError::FunctionNameNotFound(other.clone()));
+        Some(SpannedToken { token: other, span }) => {
+            return Err(ParserError::FunctionNameNotFound(span, other));
         }
     };
     expect_token(tokens, Token::LParen)?;
@@ -158,25 +183,31 @@ pub fn parse_function_def(tokens: &mut Tokens) -> Result<Statement, ParserError>
     while let Some(tok) = tokens.peek() {
         match &tok.token {
             Token::ConstantDecl | Token::MutableDecl | Token::Identifier(_) => {
+                let param_start = tok.span.clone();
                 let is_mutable = matches!(tok.token, Token::MutableDecl);
                 if matches!(tok.token, Token::MutableDecl | Token::ConstantDecl) {
                     tokens.next();
                 }
+                let type_start = tokens.peek_span();
                 let param_typ = param_typ(tokens, is_mutable)?;
+                let type_span = tokens.span_from(&type_start);
 
                 let param_name = match tokens.next() {
                     Some(SpannedToken {
                         token: Token::Identifier(s),
                         ..
                     }) => s,
-                    other => {
-                        return Err(ParserError::ParameterNameNotFound(other.unwrap().token));
+                    Some(other) => {
+                        return Err(ParserError::ParameterNameNotFound(other.span, other.token));
                     }
+                    None => return Err(ParserError::UnexpectedEOF),
                 };
                 params.push(Parameter {
                     name: Atom::from(param_name),
                     typ: param_typ,
                     is_pointer: is_mutable,
+                    span: tokens.span_from(&param_start),
+                    type_span,
                 });
                 match tokens.peek() {
                     Some(SpannedToken {
@@ -190,8 +221,11 @@ pub fn parse_function_def(tokens: &mut Tokens) -> Result<Statement, ParserError>
                         ..
                     }) => break,
                     None => return Err(ParserError::UnexpectedEOF),
-                    Some(SpannedToken { token: other, .. }) => {
-                        return Err(ParserError::ParameterNotExpected(other.clone()));
+                    Some(SpannedToken { token: other, span }) => {
+                        return Err(ParserError::ParameterNotExpected(
+                            span.clone(),
+                            other.clone(),
+                        ));
                     }
                 }
             }
@@ -202,18 +236,21 @@ pub fn parse_function_def(tokens: &mut Tokens) -> Result<Statement, ParserError>
 
             Token::RParen => break,
 
-            other => return Err(ParserError::RParenNotFound(other.clone())),
+            other => return Err(ParserError::RParenNotFound(tok.span.clone(), other.clone())),
         }
     }
     expect_token(tokens, Token::RParen)?;
     expect_token(tokens, Token::Colon)?;
 
+    let type_start = tokens.peek_span();
     let return_type = parse_type(tokens)?;
+    let return_type_span = tokens.span_from(&type_start);
 
     expect_token(tokens, Token::Newline)?;
     expect_token(tokens, Token::Indent)?;
 
     let mut body = Vec::new();
+    let mut errors = Vec::new();
 
     while let Some(tok) = tokens.peek() {
         match tok.token {
@@ -225,17 +262,26 @@ pub fn parse_function_def(tokens: &mut Tokens) -> Result<Statement, ParserError>
                 tokens.next();
             }
             Token::Eof => break,
-            _ => {
-                body.push(parse_statement(tokens)?);
-            }
+            _ => match parse_statement(tokens) {
+                Ok(statement) => body.push(statement),
+                Err(error) => recover(tokens, &mut errors, error),
+            },
         }
     }
-    Ok(Statement::FunctionDef {
-        name: Atom::from(name),
-        return_typ: return_type,
-        body,
-        params,
-    })
+    if !errors.is_empty() {
+        return Err(ParserError::Multiple(errors));
+    }
+    Ok(Statement::new(
+        StatementKind::FunctionDef {
+            name: Atom::from(name),
+            return_typ: return_type,
+            return_type_span,
+            body,
+            params,
+            doc: None,
+        },
+        tokens.span_from(&start),
+    ))
 }
 fn param_typ(tokens: &mut Tokens, is_mutable: bool) -> Result<Type, ParserError> {
     let mut param_type = parse_type(tokens)?;
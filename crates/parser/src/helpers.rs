@@ -21,6 +21,56 @@ pub fn expect_token(tokens: &mut Tokens, expected: Token) -> Result<(), ParserEr
     match tokens.next() {
         Some(SpannedToken { token: t, .. }) if t == expected => Ok(()),
         None => Err(ParserError::UnexpectedEOF),
-        Some(other) => Err(ParserError::ExpectedToken(expected, other.token)),
+        Some(other) => Err(ParserError::ExpectedToken(
+            other.span,
+            expected,
+            other.token,
+        )),
+    }
+}
+
+/// Xətalı ifadənin qalan tokenlərini atır: cari sətrin, sətrə aid daxili blok
+/// varsa onun da sonuna qədər. Cari blokun `Dedent`-i yerində saxlanılır.
+pub fn synchronize(tokens: &mut Tokens) {
+    let mut depth = 0usize;
+    while let Some(token) = tokens.peek() {
+        match token.token {
+            Token::Eof => return,
+            Token::Dedent if depth == 0 => return,
+            Token::Dedent => {
+                depth -= 1;
+                tokens.next();
+                // `else` və `else if` budaqları eyni ifadəyə aiddir.
+                let continues = tokens
+                    .peek()
+                    .is_some_and(|t| matches!(t.token, Token::Else | Token::ElseIf));
+                if depth == 0 && !continues {
+                    return;
+                }
+                continue;
+            }
+            Token::Indent => depth += 1,
+            Token::Newline if depth == 0 => {
+                tokens.next();
+                if !tokens.peek().is_some_and(|t| t.token == Token::Indent) {
+                    return;
+                }
+                continue;
+            }
+            _ => {}
+        }
+        tokens.next();
+    }
+}
+
+/// Xətanı siyahıya əlavə edib növbəti ifadəyə keçir. Daxili blokda artıq
+/// toplanmış xətalar ([`ParserError::Multiple`]) üçün keçid lazım deyil.
+pub fn recover(tokens: &mut Tokens, errors: &mut Vec<ParserError>, error: ParserError) {
+    match error {
+        ParserError::Multiple(inner) => errors.extend(inner),
+        error => {
+            errors.push(error);
+            synchronize(tokens);
+        }
     }
 }
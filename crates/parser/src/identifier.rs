@@ -1,36 +1,8 @@
-use crate::{
-    ast::{Atom, Expr},
-    binary_op::parse_expression,
-    errors::ParserError,
-    helpers::expect_token,
-    shared_ast::Type,
-};
-use tokenizer::{
-    iterator::{SpannedToken, Tokens},
-    tokens::Token,
-};
+use crate::ast::{Atom, Expr, ExprKind};
+use tokenizer::iterator::SourceSpan;
 
-pub fn parse_identifier(tokens: &mut Tokens, s: String) -> Result<Expr, ParserError> {
-    match tokens.peek() {
-        Some(SpannedToken {
-            token: Token::ListStart,
-            ..
-        }) => {
-            tokens.next();
-            let index = parse_expression(tokens)?;
-            expect_token(tokens, Token::ListEnd)?;
-            Ok(Expr::Index {
-                target: Box::new(Expr::VariableRef {
-                    name: Atom::from(s.clone()),
-                    symbol: None,
-                }),
-                index: Box::new(index),
-                target_type: Type::Any,
-            })
-        }
-        _ => Ok(Expr::VariableRef {
-            name: Atom::from(s),
-            symbol: None,
-        }),
-    }
+/// `start` identifikatorun özünün yeridir. Ondan sonra gələn `[`, `(` və `.`
+/// [`crate::postfix::parse_postfix`]-də oxunur.
+pub fn parse_identifier(name: Atom, start: SourceSpan) -> Expr {
+    Expr::new(ExprKind::VariableRef { name, symbol: None }, start)
 }
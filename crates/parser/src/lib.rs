@@ -1,9 +1,12 @@
 pub mod assign;
 pub mod ast;
+pub mod attribute;
 pub mod binary_op;
 pub mod condition;
 pub mod decl;
+pub mod doc;
 pub mod errors;
+pub mod exit;
 mod expressions;
 pub mod function;
 pub mod helpers;
@@ -11,7 +14,9 @@ pub mod identifier;
 pub mod list;
 pub mod literal_parse;
 pub mod r#loop;
+pub mod postfix;
 pub mod shared_ast;
+pub mod suggest;
 pub mod template;
 pub mod types;
 pub mod r#while_loop;
@@ -19,12 +24,56 @@ pub mod r#while_loop;
 #[cfg(test)]
 mod tests;
 
+use tokenizer::words::KeywordSet;
+
 use crate::{ast::Statement, errors::ParserError, expressions::parse_expression_block};
 
 pub fn parser(sdk: String) -> Result<Vec<Statement>, ParserError> {
-    let mut lexer = tokenizer::Lexer::new(&sdk);
+    parse_tokens(tokenizer::Lexer::new(&sdk))
+}
+
+/// Faylı adı ilə mənbə xəritəsinə əlavə edib parse edir, span-lar həmin fayla işarə edir.
+pub fn parse_file(path: &str, source: &str) -> Result<Vec<Statement>, ParserError> {
+    parse_file_with_keywords(path, source, KeywordSet::default())
+}
+
+/// [`parse_file`] kimi; `keywords` faylda `# keywords: ...` direktivi olmadıqda işlənir.
+pub fn parse_file_with_keywords(
+    path: &str,
+    source: &str,
+    keywords: KeywordSet,
+) -> Result<Vec<Statement>, ParserError> {
+    let file = tokenizer::source::add_file(path, source);
+    parse_tokens(tokenizer::Lexer::with_keywords(source, file, keywords))
+}
 
-    let mut tokens = lexer.tokenize()?;
-    let ast = parse_expression_block(&mut tokens)?;
-    Ok(ast)
+/// Tokenlər parser istədikcə oxunur. Leksik və sintaksis xətaları birlikdə, mənbədəki
+/// sıra ilə qaytarılır. Leksik xəta olan sətirdəki sintaksis xətaları atılır, çünki
+/// onları çox vaxt həmin sətirdə əskik qalan tokenlər yaradır.
+fn parse_tokens(lexer: tokenizer::Lexer) -> Result<Vec<Statement>, ParserError> {
+    let mut tokens = lexer.into_tokens();
+    let ast = parse_expression_block(&mut tokens);
+    let lexer_errors = tokens.finish();
+    if lexer_errors.is_empty() {
+        return ast;
+    }
+    let lines: Vec<u32> = lexer_errors
+        .iter()
+        .filter_map(|error| error.span()?.location())
+        .map(|location| location.line)
+        .collect();
+    let mut errors: Vec<ParserError> = lexer_errors
+        .into_iter()
+        .map(ParserError::LexerError)
+        .collect();
+    if let Err(error) = ast {
+        errors.extend(error.into_errors().into_iter().filter(|error| {
+            !error
+                .span()
+                .and_then(|span| span.location())
+                .is_some_and(|location| lines.contains(&location.line))
+        }));
+    }
+    errors.sort_by_key(|error| error.span().map_or(u32::MAX, |span| span.start));
+    Err(ParserError::collect(errors))
 }
@@ -1,11 +1,16 @@
 use tokenizer::{
-    iterator::{SpannedToken, Tokens},
+    iterator::{SourceSpan, SpannedToken, Tokens},
     tokens::Token,
 };
 
-use crate::{ast::Expr, binary_op::parse_expression, errors::ParserError};
+use crate::{
+    ast::{Expr, ExprKind},
+    binary_op::parse_expression,
+    errors::ParserError,
+};
 
-pub fn parse_list(tokens: &mut Tokens) -> Result<Expr, ParserError> {
+/// `start` artıq götürülmüş `[` tokeninin yeridir.
+pub fn parse_list(tokens: &mut Tokens, start: SourceSpan) -> Result<Expr, ParserError> {
     let mut elements = Vec::new();
     loop {
         match tokens.peek() {
@@ -32,5 +37,8 @@ pub fn parse_list(tokens: &mut Tokens) -> Result<Expr, ParserError> {
             }
         }
     }
-    Ok(Expr::List(elements))
+    Ok(Expr::new(
+        ExprKind::List(elements),
+        tokens.span_from(&start),
+    ))
 }
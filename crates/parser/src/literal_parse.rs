@@ -1,17 +1,66 @@
-use string_cache::Atom;
 use tokenizer::{
+    errors::LexerError,
     iterator::{SpannedToken, Tokens},
-    tokens::Token,
+    tokens::{NumberSuffix, Token},
 };
 
-use crate::{ast::Expr, errors::ParserError, list::parse_list};
+use crate::{
+    ast::{Expr, ExprKind},
+    errors::ParserError,
+    list::parse_list,
+    shared_ast::Type,
+};
 
 pub fn literals_parse(token: SpannedToken, tokens: &mut Tokens) -> Result<Expr, ParserError> {
-    match token.token {
-        Token::StringLiteral(s) => Ok(Expr::String(Atom::from(s))),
-        Token::Number(num) => Ok(Expr::Number(num)),
-        Token::Float(num) => Ok(Expr::Float(num)),
-        Token::ListStart => parse_list(tokens),
-        _ => Err(ParserError::UnexpectedToken(token.span, token.token)),
+    let kind = match token.token {
+        Token::StringLiteral(s) => ExprKind::String(s),
+        Token::CharLiteral(c) => ExprKind::Char(c),
+        Token::Number(_) | Token::TypedNumber(..) => return number_literal(token, false),
+        Token::Float(num) => ExprKind::Float(num),
+        Token::ListStart => return parse_list(tokens, token.span),
+        _ => return Err(ParserError::UnexpectedToken(token.span, token.token, None)),
+    };
+    Ok(Expr::new(kind, token.span))
+}
+
+/// Tam ədəd literalı; `negative` onun qarşısında unar `-` olduğunu bildirir. Lexer işarəli
+/// tiplərdə mənfi sərhədin mütləq dəyərini (`128tinyint`) də buraxır, aralıq burada yoxlanılır.
+pub fn number_literal(token: SpannedToken, negative: bool) -> Result<Expr, ParserError> {
+    let (value, suffix) = match token.token {
+        Token::Number(value) => (value, None),
+        Token::TypedNumber(value, suffix) => (value, Some(suffix)),
+        other => return Err(ParserError::UnexpectedToken(token.span, other, None)),
+    };
+    let target = suffix.unwrap_or(NumberSuffix::BigInt);
+    // `i64::MIN` burada `2^63` deməkdir.
+    let magnitude = value as u64;
+    if magnitude > target.max() && !negative {
+        let text = format!("{magnitude}{}", suffix.map_or("", NumberSuffix::as_str));
+        return Err(ParserError::LexerError(LexerError::NumberOutOfRange(
+            token.span,
+            text,
+            target.to_string(),
+        )));
+    }
+    let value = if negative {
+        value.wrapping_neg()
+    } else {
+        value
+    };
+    let kind = match suffix {
+        Some(suffix) => ExprKind::TypedNumber {
+            value,
+            typ: suffix_type(suffix),
+        },
+        None => ExprKind::Number(value),
+    };
+    Ok(Expr::new(kind, token.span))
+}
+
+fn suffix_type(suffix: NumberSuffix) -> Type {
+    match suffix {
+        NumberSuffix::TinyInt => Type::LowInteger,
+        NumberSuffix::BigInt => Type::BigInteger,
+        NumberSuffix::Natural => Type::Natural,
     }
 }
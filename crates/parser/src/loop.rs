@@ -1,8 +1,8 @@
 use crate::{
-    ast::{Atom, Statement},
+    ast::{Atom, Statement, StatementKind},
     errors::ParserError,
     expressions::parse_single_expr,
-    helpers::expect_token,
+    helpers::{expect_token, recover},
 };
 use tokenizer::{
     iterator::{SpannedToken, Tokens},
@@ -10,6 +10,7 @@ use tokenizer::{
 };
 
 pub fn parse_loop(tokens: &mut Tokens) -> Result<Statement, ParserError> {
+    let start = tokens.peek_span();
     let iterable = parse_single_expr(tokens)?;
 
     expect_token(tokens, Token::In)?;
@@ -19,14 +20,15 @@ pub fn parse_loop(tokens: &mut Tokens) -> Result<Statement, ParserError> {
             token: Token::Identifier(name),
             ..
         }) => name,
-        Some(other) => return Err(ParserError::LoopVarNameNotFound(other.token)),
-        None => return Err(ParserError::LoopVarNameNotFound(Token::Eof)),
+        Some(other) => return Err(ParserError::UnexpectedToken(other.span, other.token, None)),
+        None => return Err(ParserError::UnexpectedEOF),
     };
 
     expect_token(tokens, Token::Newline)?;
     expect_token(tokens, Token::Indent)?;
 
     let mut body = Vec::new();
+    let mut errors = Vec::new();
 
     while let Some(token) = tokens.peek() {
         match token.token {
@@ -38,16 +40,22 @@ pub fn parse_loop(tokens: &mut Tokens) -> Result<Statement, ParserError> {
                 tokens.next();
             }
             Token::Eof => break,
-            _ => {
-                let expr = parse_single_expr(tokens)?;
-                body.push(expr);
-            }
+            _ => match parse_single_expr(tokens) {
+                Ok(expr) => body.push(expr),
+                Err(error) => recover(tokens, &mut errors, error),
+            },
         }
     }
+    if !errors.is_empty() {
+        return Err(ParserError::Multiple(errors));
+    }
 
-    Ok(Statement::Loop {
-        var_name: Atom::from(var_name),
-        iterable: Box::new(iterable),
-        body,
-    })
+    Ok(Statement::new(
+        StatementKind::Loop {
+            var_name: Atom::from(var_name),
+            iterable: Box::new(iterable),
+            body,
+        },
+        tokens.span_from(&start),
+    ))
 }
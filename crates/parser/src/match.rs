@@ -22,6 +22,7 @@ where
         match tok {
             Token::StringLiteral(_)
             | Token::Number(_)
+            | Token::TypedNumber(..)
             | Token::Underscore
             | Token::Identifier(_) => {
                 let pattern = parse_single_expr(tokens)?;
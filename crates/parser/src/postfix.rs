@@ -0,0 +1,99 @@
+use tokenizer::{
+    iterator::{SpannedToken, Tokens},
+    tokens::Token,
+};
+
+use crate::{
+    ast::{Expr, ExprKind},
+    binary_op::parse_expression,
+    errors::ParserError,
+    helpers::expect_token,
+    shared_ast::Type,
+};
+
+/// `expr`-dən sonra gələn çağırış, indeks və üzv zənciri soldan sağa oxunur:
+/// `generator()("Əli")`, `a[0][1]`, `istifadəçi.ad`, `siyahı.əlavə(1)`.
+pub fn parse_postfix(tokens: &mut Tokens, mut expr: Expr) -> Result<Expr, ParserError> {
+    loop {
+        let start = expr.span.clone();
+        let kind = match tokens.peek().map(|token| &token.token) {
+            Some(Token::LParen) => {
+                tokens.next();
+                ExprKind::Call {
+                    target: None,
+                    name: Box::new(expr),
+                    args: parse_arguments(tokens)?,
+                }
+            }
+            Some(Token::ListStart) => {
+                tokens.next();
+                let index = parse_expression(tokens)?;
+                expect_token(tokens, Token::ListEnd)?;
+                ExprKind::Index {
+                    target: Box::new(expr),
+                    index: Box::new(index),
+                    target_type: Type::Any,
+                }
+            }
+            Some(Token::Dot) => {
+                tokens.next();
+                let (name, span) = match tokens.next() {
+                    Some(SpannedToken {
+                        token: Token::Identifier(name),
+                        span,
+                    }) => (name, span),
+                    Some(other) => {
+                        return Err(ParserError::MethodNameNotFound(other.span, other.token));
+                    }
+                    None => return Err(ParserError::UnexpectedEOF),
+                };
+                if tokens
+                    .peek()
+                    .is_some_and(|token| token.token == Token::LParen)
+                {
+                    tokens.next();
+                    let method = ExprKind::VariableRef { name, symbol: None };
+                    ExprKind::Call {
+                        target: Some(Box::new(expr)),
+                        name: Box::new(Expr::new(method, span)),
+                        args: parse_arguments(tokens)?,
+                    }
+                } else {
+                    ExprKind::Member {
+                        target: Box::new(expr),
+                        name,
+                    }
+                }
+            }
+            _ => return Ok(expr),
+        };
+        expr = Expr::new(kind, tokens.span_from(&start));
+    }
+}
+
+/// Artıq götürülmüş `(`-dan sonra vergüllə ayrılmış arqumentlər və `)`.
+fn parse_arguments(tokens: &mut Tokens) -> Result<Vec<Expr>, ParserError> {
+    let mut args = Vec::new();
+    if tokens
+        .peek()
+        .is_some_and(|token| token.token == Token::RParen)
+    {
+        tokens.next();
+        return Ok(args);
+    }
+    loop {
+        args.push(parse_expression(tokens)?);
+        match tokens.next() {
+            Some(SpannedToken {
+                token: Token::Comma,
+                ..
+            }) => {}
+            Some(SpannedToken {
+                token: Token::RParen,
+                ..
+            }) => return Ok(args),
+            Some(other) => return Err(ParserError::RParenNotFound(other.span, other.token)),
+            None => return Err(ParserError::RParenNotFound(tokens.peek_span(), Token::Eof)),
+        }
+    }
+}
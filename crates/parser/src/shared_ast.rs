@@ -1,4 +1,5 @@
 use crate::ast::Atom;
+use locale::t;
 use std::fmt::Display;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -22,6 +23,8 @@ pub enum Type {
     ZigFloat,
     ZigInteger,
     Function,
+    /// Yoxlaması uğursuz olmuş ifadənin tipi. Ardıcıl xətaların qarşısını almaq üçün hər tiplə uyğun sayılır.
+    Error,
 }
 #[derive(Debug, Clone, PartialEq)]
 pub enum StringEnum {
@@ -29,38 +32,66 @@ pub enum StringEnum {
     LiteralString,
     LiteralConstString,
 }
+impl StringEnum {
+    /// Tip adının mesaj kataloqundakı açarı.
+    pub fn name_key(&self) -> &'static str {
+        match self {
+            StringEnum::DynamicString => "type.dynamic-string",
+            StringEnum::LiteralString => "type.string",
+            StringEnum::LiteralConstString => "type.const-string",
+        }
+    }
+}
 impl Display for StringEnum {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", t!(self.name_key()))
+    }
+}
+
+impl Type {
+    /// Tip adının mesaj kataloqundakı açarı.
+    pub fn name_key(&self) -> &'static str {
         match self {
-            StringEnum::DynamicString => write!(f, "Dinamik Yazı"),
-            StringEnum::LiteralString => write!(f, "Yazı"),
-            StringEnum::LiteralConstString => write!(f, "Sabit yazı"),
+            Type::String(typ) => typ.name_key(),
+            Type::Array(_) => "type.array",
+            Type::User(_) => "type.user",
+            Type::Integer => "type.integer",
+            Type::Natural => "type.natural",
+            Type::BigInteger => "type.big-integer",
+            Type::LowInteger => "type.low-integer",
+            Type::Bool => "type.bool",
+            Type::Char => "type.char",
+            Type::Allocator => "type.allocator",
+            Type::Void => "type.void",
+            Type::Any => "type.any",
+            Type::Float => "type.float",
+            Type::ZigArray => "type.zig-array",
+            Type::ZigConstArray => "type.zig-const-array",
+            Type::ZigNatural => "type.zig-natural",
+            Type::ZigFloat => "type.zig-float",
+            Type::ZigInteger => "type.zig-integer",
+            Type::Function => "type.function",
+            Type::Error => "type.error",
         }
     }
-}
 
+    /// Tam ədəd tipinin aralığı; digər tiplər üçün `None`. Validator literalları,
+    /// transpiler isə daşmanı bu aralıqla yoxlayır.
+    pub fn integer_range(&self) -> Option<(i64, i64)> {
+        match self {
+            Type::LowInteger => Some((i8::MIN as i64, i8::MAX as i64)),
+            Type::Integer => Some((i32::MIN as i64, i32::MAX as i64)),
+            Type::Natural => Some((0, u32::MAX as i64)),
+            Type::BigInteger => Some((i64::MIN, i64::MAX)),
+            _ => None,
+        }
+    }
+}
 impl Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Type::String(typ) => write!(f, "{}", typ),
-            Type::Array(_) => write!(f, "Siyahı"),
-            Type::User(name) => write!(f, "İstifadəçi({name})"),
-            Type::Integer => write!(f, "Tam Ədəd"),
-            Type::Natural => write!(f, "Natural"),
-            Type::BigInteger => write!(f, "Böyük tam ədəd"),
-            Type::LowInteger => write!(f, "Kiçik tam ədəd"),
-            Type::Bool => write!(f, "Şərt"),
-            Type::Char => write!(f, "Simvol"),
-            Type::Allocator => write!(f, "Allocator"),
-            Type::Void => write!(f, "Boşluq"),
-            Type::Any => write!(f, "Hərşey"),
-            Type::Float => write!(f, "Onluq Ədəd"),
-            Type::ZigArray => write!(f, "ZigArray"),
-            Type::ZigConstArray => write!(f, "ZigConstArray"),
-            Type::ZigNatural => write!(f, "ZigNatural"),
-            Type::ZigFloat => write!(f, "ZigFloat"),
-            Type::ZigInteger => write!(f, "ZigInteger"),
-            Type::Function => write!(f, "Funksiya"),
+            Type::User(name) => write!(f, "{}", t!(self.name_key(), name = name)),
+            typ => write!(f, "{}", t!(typ.name_key())),
         }
     }
 }
@@ -0,0 +1,92 @@
+use tokenizer::{
+    iterator::{SpannedToken, Tokens},
+    tokens::Token,
+    words::is_keyword,
+};
+
+use crate::errors::ParserError;
+
+/// İki söz arasındakı redaktə məsafəsi. Hərfin əlavəsi, silinməsi, dəyişdirilməsi və
+/// qonşu iki hərfin yerdəyişməsi bir addım sayılır.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    rows[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// `name`-ə ən çox bənzəyən namizəd. Məsafə adın uzunluğunun üçdə birindən (ən azı 1)
+/// çox və adın uzunluğundan az olmalıdır, yəni `b` üçün `a` təklif edilmir. Yalnız
+/// hərflərin böyüklüyü ilə fərqlənən namizəd həmişə seçilir.
+pub fn similar_name<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let length = name.chars().count();
+    let limit = (length / 3).max(1).min(length.saturating_sub(1));
+    let mut best: Option<(usize, &'a str)> = None;
+    for candidate in candidates {
+        if candidate == name {
+            continue;
+        }
+        if candidate.to_lowercase() == name.to_lowercase() {
+            return Some(candidate);
+        }
+        let distance = edit_distance(name, candidate);
+        if distance <= limit && best.is_none_or(|best| (distance, candidate) < best) {
+            best = Some((distance, candidate));
+        }
+    }
+    best.map(|(_, candidate)| candidate)
+}
+
+/// İfadə faylın dəstindəki açar sözə bənzəyən adla başlayır və ondan sonra ad, dəyər, açar söz və ya
+/// girintili blok gəlirsə, səhv yazılmış açar söz haqqında xəta.
+pub fn misspelled_keyword(tokens: &Tokens) -> Option<ParserError> {
+    let SpannedToken {
+        token: Token::Identifier(word),
+        span,
+    } = tokens.peek()?
+    else {
+        return None;
+    };
+    let follows_keyword = match &tokens.peek_nth(1)?.token {
+        Token::Identifier(_)
+        | Token::Number(_)
+        | Token::TypedNumber(..)
+        | Token::Float(_)
+        | Token::StringLiteral(_)
+        | Token::CharLiteral(_) => true,
+        Token::Newline => tokens
+            .peek_nth(2)
+            .is_some_and(|next| next.token == Token::Indent),
+        Token::And | Token::Or | Token::In => false,
+        token => is_keyword(token),
+    };
+    if !follows_keyword {
+        return None;
+    }
+    // Digər dəstin açar sözləri bu faylda adi addır, onları təklif etmək olmaz.
+    let keyword = similar_name(word, tokens.keywords().words().iter().copied())?;
+    Some(ParserError::UnexpectedToken(
+        span.clone(),
+        Token::Identifier(word.clone()),
+        Some(keyword.to_string()),
+    ))
+}
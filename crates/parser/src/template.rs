@@ -1,12 +1,18 @@
-use crate::{binary_op::parse_expression, errors::ParserError};
+use std::iter::Peekable;
+
+use crate::{binary_op::parse_expression, errors::ParserError, helpers::expect_token};
 use tokenizer::{
-    iterator::{SpannedToken, Tokens},
+    iterator::{SourceSpan, SpannedToken, Tokens},
     tokens::Token,
 };
 
-use crate::ast::{Expr, TemplateChunk};
+use crate::ast::{Align, Expr, ExprKind, FormatKind, FormatSpec, TemplateChunk};
 
-pub fn parse_template_string_expr(tokens: &mut Tokens) -> Result<Expr, ParserError> {
+/// `start` artıq götürülmüş açılış `` ` `` tokeninin yeridir.
+pub fn parse_template_string_expr(
+    tokens: &mut Tokens,
+    start: SourceSpan,
+) -> Result<Expr, ParserError> {
     let mut chunks = Vec::new();
     while let Some(token) = tokens.next() {
         match token {
@@ -14,37 +20,116 @@ pub fn parse_template_string_expr(tokens: &mut Tokens) -> Result<Expr, ParserErr
                 token: Token::StringLiteral(s),
                 ..
             } => {
-                chunks.push(TemplateChunk::Literal(s));
+                chunks.push(TemplateChunk::Literal(s.to_string()));
             }
 
             SpannedToken {
                 token: Token::InterpolationStart,
                 ..
-            } => loop {
-                match tokens.peek() {
+            } => {
+                // İfadənin özü də `` ` `` ilə başlayan şablon ola bilər.
+                let expr = parse_expression(tokens)?;
+                let spec = match tokens.peek().cloned() {
                     Some(SpannedToken {
-                        token: Token::InterpolationEnd,
-                        ..
+                        token: Token::FormatSpec(text),
+                        span,
                     }) => {
                         tokens.next();
-                        break;
-                    }
-                    Some(_) => {
-                        let expr = parse_expression(tokens)?;
-                        chunks.push(TemplateChunk::Expr(Box::new(expr)));
+                        Some(parse_format_spec(&text, span)?)
                     }
-                    None => return Err(ParserError::UnexpectedEOF),
-                }
-            },
+                    _ => None,
+                };
+                expect_token(tokens, Token::InterpolationEnd)?;
+                chunks.push(TemplateChunk::Expr(Box::new(expr), spec));
+            }
 
             SpannedToken {
                 token: Token::Backtick,
                 ..
             } => break,
 
-            other => return Err(ParserError::UnexpectedToken(other.span, other.token)),
+            other => return Err(ParserError::UnexpectedToken(other.span, other.token, None)),
         }
     }
 
-    Ok(Expr::TemplateString(chunks))
+    Ok(Expr::new(
+        ExprKind::TemplateString(chunks),
+        tokens.span_from(&start),
+    ))
+}
+
+/// `.2`, `>5`, `*^10`, `08.3` və ya `x` kimi formatı təhlil edir. Formatın tiplə
+/// uyğunluğunu validator yoxlayır.
+pub fn parse_format_spec(text: &str, span: SourceSpan) -> Result<FormatSpec, ParserError> {
+    let invalid = || ParserError::InvalidFormatSpec(span.clone(), text.to_string());
+    let mut spec = FormatSpec {
+        fill: ' ',
+        align: None,
+        zero: false,
+        width: 0,
+        precision: None,
+        kind: FormatKind::Display,
+        text: text.to_string(),
+        span: span.clone(),
+    };
+    let mut chars = text.chars().peekable();
+
+    let mut ahead = text.chars();
+    match (ahead.next(), ahead.next().and_then(align)) {
+        (Some(fill), Some(align)) => {
+            spec.fill = fill;
+            spec.align = Some(align);
+            chars.nth(1);
+        }
+        (Some(first), None) if align(first).is_some() => {
+            spec.align = align(first);
+            chars.next();
+        }
+        _ => {}
+    }
+    spec.zero = chars.next_if_eq(&'0').is_some();
+    spec.width = number(&mut chars).map_err(|()| invalid())?.unwrap_or(0);
+    if chars.next_if_eq(&'.').is_some() {
+        spec.precision = Some(
+            number(&mut chars)
+                .map_err(|()| invalid())?
+                .ok_or_else(invalid)?,
+        );
+    }
+    spec.kind = match chars.next() {
+        None => return Ok(spec),
+        Some('x') => FormatKind::Hex,
+        Some('X') => FormatKind::UpperHex,
+        Some('o') => FormatKind::Octal,
+        Some('b') => FormatKind::Binary,
+        Some(_) => return Err(invalid()),
+    };
+    match chars.next() {
+        None => Ok(spec),
+        Some(_) => Err(invalid()),
+    }
+}
+
+fn align(ch: char) -> Option<Align> {
+    match ch {
+        '<' => Some(Align::Left),
+        '>' => Some(Align::Right),
+        '^' => Some(Align::Center),
+        _ => None,
+    }
+}
+
+/// Rəqəmlər yoxdursa `Ok(None)`, ədəd `u16`-ya sığmırsa xəta.
+fn number(chars: &mut Peekable<std::str::Chars>) -> Result<Option<u32>, ()> {
+    let mut digits = String::new();
+    while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+        digits.push(digit);
+    }
+    if digits.is_empty() {
+        return Ok(None);
+    }
+    digits
+        .parse::<u16>()
+        .map(|n| Some(u32::from(n)))
+        .map_err(|_| ())
 }
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::assign::parse_assign;
-    use crate::ast::{Atom, Expr, Operation, Statement};
+    use crate::ast::{Atom, Expr, ExprKind, Operation, SourceSpan, Statement, StatementKind};
     use crate::binary_op::parse_statement;
     use crate::decl::parse_decl;
     use crate::shared_ast::{StringEnum, Type};
@@ -12,7 +12,7 @@ mod tests {
     #[test]
     fn test_parse_assign_simple() -> TestResult {
         let mut tokens = create_tokens(vec![
-            Token::Identifier("x".to_string()),
+            Token::Identifier("x".into()),
             Token::Assign,
             Token::Number(42),
         ]);
@@ -20,10 +20,10 @@ mod tests {
 
         assert_eq!(
             result,
-            Statement::Assignment {
+            Statement::from(StatementKind::Assignment {
                 name: Atom::from("x"),
-                value: Box::new(Expr::Number(42))
-            }
+                value: Box::new(Expr::from(ExprKind::Number(42)))
+            })
         );
         Ok(())
     }
@@ -33,7 +33,7 @@ mod tests {
         let mut tokens = create_tokens(vec![
             Token::ConstantDecl,
             Token::StringType,
-            Token::Identifier("a".to_string()),
+            Token::Identifier("a".into()),
             Token::Assign,
             Token::Float(2.1),
         ]);
@@ -41,29 +41,31 @@ mod tests {
 
         assert_eq!(
             result,
-            Statement::Decl {
+            Statement::from(StatementKind::Decl {
                 name: Atom::from("a"),
                 typ: Rc::new(Type::String(StringEnum::DynamicString)),
+                type_span: SourceSpan::default(),
                 is_mutable: false,
-                value: Box::new(Expr::Float(2.1)),
-            }
+                value: Box::new(Expr::from(ExprKind::Float(2.1))),
+                doc: None,
+            })
         );
         Ok(())
     }
     #[test]
     fn test_parse_assign_float() -> TestResult {
         let mut tokens = create_tokens(vec![
-            Token::Identifier("c".to_string()),
+            Token::Identifier("c".into()),
             Token::Assign,
             Token::Float(2.1),
         ]);
         let result = parse_statement(&mut tokens)?;
         assert_eq!(
             result,
-            Statement::Assignment {
+            Statement::from(StatementKind::Assignment {
                 name: Atom::from("c"),
-                value: Box::new(Expr::Float(2.1))
-            }
+                value: Box::new(Expr::from(ExprKind::Float(2.1)))
+            })
         );
         Ok(())
     }
@@ -77,7 +79,7 @@ mod tests {
     #[test]
     fn test_parse_assign_complex_expr() -> TestResult {
         let mut tokens = create_tokens(vec![
-            Token::Identifier("sum".to_string()),
+            Token::Identifier("sum".into()),
             Token::Assign,
             Token::Number(10),
             Token::Add,
@@ -86,15 +88,50 @@ mod tests {
         let result = parse_assign(&mut tokens, "sum".to_string())?;
         assert_eq!(
             result,
-            Statement::Assignment {
+            Statement::from(StatementKind::Assignment {
                 name: Atom::from("sum"),
-                value: Box::new(Expr::BinaryOp {
-                    left: Box::new(Expr::Number(10)),
-                    right: Box::new(Expr::Number(20)),
+                value: Box::new(Expr::from(ExprKind::BinaryOp {
+                    left: Box::new(Expr::from(ExprKind::Number(10))),
+                    right: Box::new(Expr::from(ExprKind::Number(20))),
                     op: Operation::Add,
-                })
-            }
+                }))
+            })
         );
         Ok(())
     }
+
+    #[test]
+    fn test_parse_compound_assign() -> TestResult {
+        let operators = [
+            (Token::AddAssign, Operation::Add),
+            (Token::SubtractAssign, Operation::Subtract),
+            (Token::MultiplyAssign, Operation::Multiply),
+            (Token::DivideAssign, Operation::Divide),
+            (Token::ModuloAssign, Operation::Modulo),
+        ];
+        for (token, op) in operators {
+            let mut tokens = create_tokens(vec![
+                Token::Identifier("i".into()),
+                token,
+                Token::Number(1),
+                Token::Newline,
+            ]);
+            let result = parse_statement(&mut tokens)?;
+            assert_eq!(
+                result,
+                Statement::from(StatementKind::Assignment {
+                    name: Atom::from("i"),
+                    value: Box::new(Expr::from(ExprKind::BinaryOp {
+                        left: Box::new(Expr::from(ExprKind::VariableRef {
+                            name: Atom::from("i"),
+                            symbol: None,
+                        })),
+                        right: Box::new(Expr::from(ExprKind::Number(1))),
+                        op,
+                    }))
+                })
+            );
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,62 @@
+use crate::{ast::StatementKind, errors::ParserError, parse_file, parser};
+use tokenizer::tokens::Token;
+
+#[test]
+fn test_allow_attaches_to_next_statement() {
+    let ast = parser("@allow(unused, unused_mut)\n\nvar int a = 1\nexit(0)\n".to_string())
+        .expect("parse edilmədi");
+
+    assert_eq!(ast.len(), 2);
+    assert!(matches!(ast[0].kind, StatementKind::Decl { .. }));
+    let names: Vec<&str> = ast[0].allow.iter().map(|name| name.as_ref()).collect();
+    assert_eq!(names, vec!["unused", "unused_mut"]);
+    assert!(ast[1].allow.is_empty());
+}
+
+#[test]
+fn test_doc_comment_before_allow() {
+    let ast = parser("/// sayğac\n@allow(unused)\nconst int a = 1\n".to_string())
+        .expect("parse edilmədi");
+
+    let StatementKind::Decl { doc, .. } = &ast[0].kind else {
+        panic!("Decl gözlənilirdi, {:?} alındı", ast[0]);
+    };
+    assert_eq!(doc.as_deref(), Some("sayğac"));
+    assert_eq!(ast[0].allow.len(), 1);
+}
+
+#[test]
+fn test_allow_requires_closing_paren() {
+    assert!(parser("@allow(unused\nconst int a = 1\n".to_string()).is_err());
+}
+
+#[test]
+fn test_unknown_attribute_is_an_unexpected_token() {
+    let source = "@foo\nconst int a = 1\n";
+    let error = parse_file("attr.az", source).expect_err("naməlum atribut qəbul edilməməlidir");
+    let ParserError::UnexpectedToken(span, Token::Identifier(name), None) = error else {
+        panic!("UnexpectedToken gözlənilirdi, {error:?} alındı");
+    };
+    assert_eq!(name.as_ref(), "foo");
+    assert_eq!(&source[span.start as usize..span.end as usize], "@foo");
+}
+
+#[test]
+fn test_stray_at_is_an_unexpected_token() {
+    let error =
+        parse_file("attr.az", "@\nconst int a = 1\n").expect_err("tək @ qəbul edilməməlidir");
+    assert!(matches!(
+        error,
+        ParserError::UnexpectedToken(ref span, Token::At, None) if span.start == 0
+    ));
+}
+
+#[test]
+fn test_allow_errors_point_at_the_token() {
+    let source = "@allow(5)\nconst int a = 1\n";
+    let error = parse_file("attr.az", source).expect_err("ad gözlənilirdi");
+    let ParserError::ExpectedToken(span, _, Token::Number(5)) = error else {
+        panic!("ExpectedToken gözlənilirdi, {error:?} alındı");
+    };
+    assert_eq!(&source[span.start as usize..span.end as usize], "5");
+}
@@ -1,9 +1,14 @@
 use crate::{
-    ast::{Expr, Operation},
+    ast::{Expr, ExprKind, Operation},
     binary_op::parse_expression,
+    errors::ParserError,
+    shared_ast::Type,
     tests::{TestResult, create_tokens},
 };
-use tokenizer::tokens::Token;
+use tokenizer::{
+    errors::LexerError,
+    tokens::{NumberSuffix, Token},
+};
 
 #[test]
 fn test_binary_op_print() -> TestResult {
@@ -11,11 +16,11 @@ fn test_binary_op_print() -> TestResult {
     let result = parse_expression(&mut tokens)?;
     assert_eq!(
         result,
-        Expr::BinaryOp {
-            left: Box::new(Expr::Number(2)),
-            right: Box::new(Expr::Number(2)),
+        Expr::from(ExprKind::BinaryOp {
+            left: Box::new(Expr::from(ExprKind::Number(2))),
+            right: Box::new(Expr::from(ExprKind::Number(2))),
             op: Operation::Add,
-        }
+        })
     );
     Ok(())
 }
@@ -32,15 +37,15 @@ fn test_multi_add_binary_op() -> TestResult {
     let result = parse_expression(&mut tokens)?;
     assert_eq!(
         result,
-        Expr::BinaryOp {
-            left: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Number(2)),
-                right: Box::new(Expr::Number(2)),
+        Expr::from(ExprKind::BinaryOp {
+            left: Box::new(Expr::from(ExprKind::BinaryOp {
+                left: Box::new(Expr::from(ExprKind::Number(2))),
+                right: Box::new(Expr::from(ExprKind::Number(2))),
                 op: Operation::Add,
-            }),
-            right: Box::new(Expr::Number(4)),
+            })),
+            right: Box::new(Expr::from(ExprKind::Number(4))),
             op: Operation::Add,
-        }
+        })
     );
     Ok(())
 }
@@ -65,27 +70,27 @@ fn test_multiply_add_binary_op() -> TestResult {
     let result2 = parse_expression(&mut tokens2)?;
     assert_eq!(
         result,
-        Expr::BinaryOp {
-            left: Box::new(Expr::Number(2)),
-            right: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Number(2)),
-                right: Box::new(Expr::Number(4)),
+        Expr::from(ExprKind::BinaryOp {
+            left: Box::new(Expr::from(ExprKind::Number(2))),
+            right: Box::new(Expr::from(ExprKind::BinaryOp {
+                left: Box::new(Expr::from(ExprKind::Number(2))),
+                right: Box::new(Expr::from(ExprKind::Number(4))),
                 op: Operation::Multiply,
-            }),
+            })),
             op: Operation::Add,
-        }
+        })
     );
     assert_eq!(
         result2,
-        Expr::BinaryOp {
-            left: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Number(2)),
-                right: Box::new(Expr::Number(2)),
+        Expr::from(ExprKind::BinaryOp {
+            left: Box::new(Expr::from(ExprKind::BinaryOp {
+                left: Box::new(Expr::from(ExprKind::Number(2))),
+                right: Box::new(Expr::from(ExprKind::Number(2))),
                 op: Operation::Multiply,
-            }),
-            right: Box::new(Expr::Number(4)),
+            })),
+            right: Box::new(Expr::from(ExprKind::Number(4))),
             op: Operation::Add,
-        }
+        })
     );
     Ok(())
 }
@@ -98,19 +103,222 @@ fn test_equal_binary_op() -> TestResult {
     let result2 = parse_expression(&mut tokens2)?;
     assert_eq!(
         result,
-        Expr::BinaryOp {
-            left: Box::new(Expr::Number(2)),
-            right: Box::new(Expr::Number(2)),
+        Expr::from(ExprKind::BinaryOp {
+            left: Box::new(Expr::from(ExprKind::Number(2))),
+            right: Box::new(Expr::from(ExprKind::Number(2))),
             op: Operation::Equal,
-        }
+        })
     );
     assert_eq!(
         result2,
-        Expr::BinaryOp {
-            left: Box::new(Expr::Number(2)),
-            right: Box::new(Expr::Number(2)),
+        Expr::from(ExprKind::BinaryOp {
+            left: Box::new(Expr::from(ExprKind::Number(2))),
+            right: Box::new(Expr::from(ExprKind::Number(2))),
             op: Operation::NotEqual,
-        }
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_symbolic_logical_and_comparison_ops() -> TestResult {
+    let operators = [
+        (Token::DoubleAnd, Operation::And),
+        (Token::DoubleOr, Operation::Or),
+        (Token::GreaterEqual, Operation::GreaterEqual),
+        (Token::LessEqual, Operation::LessEqual),
+    ];
+    for (token, op) in operators {
+        let mut tokens = create_tokens(vec![Token::Number(1), token, Token::Number(2)]);
+        let result = parse_expression(&mut tokens)?;
+        assert_eq!(
+            result,
+            Expr::from(ExprKind::BinaryOp {
+                left: Box::new(Expr::from(ExprKind::Number(1))),
+                right: Box::new(Expr::from(ExprKind::Number(2))),
+                op,
+            })
+        );
+    }
+    Ok(())
+}
+
+fn var(name: &str) -> Box<Expr> {
+    Box::new(Expr::from(ExprKind::VariableRef {
+        name: name.into(),
+        symbol: None,
+    }))
+}
+
+#[test]
+fn test_parenthesized_expression_binds_first() -> TestResult {
+    let mut tokens = create_tokens(vec![
+        Token::LParen,
+        Token::Identifier("a".into()),
+        Token::Add,
+        Token::Identifier("b".into()),
+        Token::RParen,
+        Token::Multiply,
+        Token::Identifier("c".into()),
+    ]);
+    let result = parse_expression(&mut tokens)?;
+    assert_eq!(
+        result,
+        Expr::from(ExprKind::BinaryOp {
+            left: Box::new(Expr::from(ExprKind::BinaryOp {
+                left: var("a"),
+                right: var("b"),
+                op: Operation::Add,
+            })),
+            right: var("c"),
+            op: Operation::Multiply,
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_unary_minus_is_not_multiplication() -> TestResult {
+    let mut tokens = create_tokens(vec![
+        Token::Subtract,
+        Token::Identifier("x".into()),
+        Token::Multiply,
+        Token::Identifier("y".into()),
+    ]);
+    let result = parse_expression(&mut tokens)?;
+    assert_eq!(
+        result,
+        Expr::from(ExprKind::BinaryOp {
+            left: Box::new(Expr::from(ExprKind::UnaryOp {
+                op: Operation::Subtract,
+                expr: var("x"),
+            })),
+            right: var("y"),
+            op: Operation::Multiply,
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_unary_minus_on_parenthesized_expression() -> TestResult {
+    let mut tokens = create_tokens(vec![
+        Token::Subtract,
+        Token::LParen,
+        Token::Identifier("a".into()),
+        Token::Add,
+        Token::Identifier("b".into()),
+        Token::RParen,
+    ]);
+    let result = parse_expression(&mut tokens)?;
+    assert_eq!(
+        result,
+        Expr::from(ExprKind::UnaryOp {
+            op: Operation::Subtract,
+            expr: Box::new(Expr::from(ExprKind::BinaryOp {
+                left: var("a"),
+                right: var("b"),
+                op: Operation::Add,
+            })),
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_negative_literals_are_folded() -> TestResult {
+    let mut tokens = create_tokens(vec![
+        Token::Number(1),
+        Token::Subtract,
+        Token::Subtract,
+        Token::Number(2),
+    ]);
+    let result = parse_expression(&mut tokens)?;
+    assert_eq!(
+        result,
+        Expr::from(ExprKind::BinaryOp {
+            left: Box::new(Expr::from(ExprKind::Number(1))),
+            right: Box::new(Expr::from(ExprKind::Number(-2))),
+            op: Operation::Subtract,
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_and_binds_tighter_than_or() -> TestResult {
+    let and = |left, right| {
+        Box::new(Expr::from(ExprKind::BinaryOp {
+            left,
+            right,
+            op: Operation::And,
+        }))
+    };
+    let or = |left, right| {
+        Expr::from(ExprKind::BinaryOp {
+            left,
+            right,
+            op: Operation::Or,
+        })
+    };
+
+    let mut tokens = create_tokens(vec![
+        Token::Identifier("a".into()),
+        Token::Or,
+        Token::Identifier("b".into()),
+        Token::And,
+        Token::Identifier("c".into()),
+    ]);
+    let result = parse_expression(&mut tokens)?;
+    assert_eq!(result, or(var("a"), and(var("b"), var("c"))));
+
+    let mut tokens = create_tokens(vec![
+        Token::Identifier("a".into()),
+        Token::And,
+        Token::Identifier("b".into()),
+        Token::Or,
+        Token::Identifier("c".into()),
+    ]);
+    let result = parse_expression(&mut tokens)?;
+    assert_eq!(result, or(and(var("a"), var("b")), var("c")));
+    Ok(())
+}
+
+#[test]
+fn test_negative_bound_of_signed_literals() -> TestResult {
+    let mut tokens = create_tokens(vec![
+        Token::Subtract,
+        Token::TypedNumber(128, NumberSuffix::TinyInt),
+        Token::Add,
+        Token::Subtract,
+        Token::Number(i64::MIN),
+    ]);
+    let result = parse_expression(&mut tokens)?;
+    assert_eq!(
+        result,
+        Expr::from(ExprKind::BinaryOp {
+            left: Box::new(Expr::from(ExprKind::TypedNumber {
+                value: -128,
+                typ: Type::LowInteger,
+            })),
+            right: Box::new(Expr::from(ExprKind::Number(i64::MIN))),
+            op: Operation::Add,
+        })
     );
+
+    // Mənfi işarəsi olmadan həmin mütləq dəyər tipə sığmır.
+    let mut tokens = create_tokens(vec![Token::TypedNumber(128, NumberSuffix::TinyInt)]);
+    let Err(ParserError::LexerError(LexerError::NumberOutOfRange(_, text, typ))) =
+        parse_expression(&mut tokens)
+    else {
+        panic!("NumberOutOfRange gözlənilirdi");
+    };
+    assert_eq!((text.as_str(), typ.as_str()), ("128tinyint", "tinyint"));
+
+    let mut tokens = create_tokens(vec![Token::Number(i64::MIN)]);
+    assert!(matches!(
+        parse_expression(&mut tokens),
+        Err(ParserError::LexerError(LexerError::NumberOutOfRange(..)))
+    ));
     Ok(())
 }
@@ -1,12 +1,12 @@
 #[cfg(test)]
 mod tests {
-    use crate::ast::{Expr, Statement};
+    use crate::ast::{Expr, ExprKind, Statement, StatementKind};
     use crate::condition::parse_if_expr;
     use crate::tests::create_tokens;
     use tokenizer::tokens::Token;
 
     fn expr_stmt(expr: Expr) -> Statement {
-        Statement::Expr(expr)
+        Statement::from(StatementKind::Expr(expr))
     }
 
     #[test]
@@ -22,9 +22,9 @@ mod tests {
         ]);
         let result = parse_if_expr(&mut tokens).expect("if parse edilmədi");
 
-        if let Statement::Condition { main, elif, other } = result {
-            assert_eq!(*main.condition, Expr::Bool(true));
-            assert_eq!(main.body, vec![expr_stmt(Expr::Number(1))]);
+        if let StatementKind::Condition { main, elif, other } = result.kind {
+            assert_eq!(*main.condition, Expr::from(ExprKind::Bool(true)));
+            assert_eq!(main.body, vec![expr_stmt(Expr::from(ExprKind::Number(1)))]);
             assert!(elif.is_empty());
             assert!(other.is_none());
         } else {
@@ -1,9 +1,12 @@
 use std::rc::Rc;
 
-use tokenizer::tokens::Token;
+use tokenizer::{
+    tokens::{NumberSuffix, Token},
+    words::KeywordSet,
+};
 
 use crate::{
-    ast::{Atom, Expr, Statement},
+    ast::{Atom, Expr, ExprKind, SourceSpan, Statement, StatementKind},
     binary_op::parse_statement,
     shared_ast::{StringEnum, Type},
     tests::create_tokens,
@@ -14,15 +17,15 @@ fn test_parse_statement_decl() {
     let mut tokens = create_tokens(vec![
         Token::ConstantDecl,
         Token::IntegerType,
-        Token::Identifier("x".to_string()),
+        Token::Identifier("x".into()),
         Token::Assign,
         Token::Number(42),
         Token::Newline,
     ]);
     let result = parse_statement(&mut tokens).expect("decl parse edilmədi");
     assert!(matches!(
-        result,
-        Statement::Decl {
+        result.kind,
+        StatementKind::Decl {
             is_mutable: false,
             ..
         }
@@ -33,37 +36,41 @@ fn test_parse_string_decl() {
     let mut tokens = create_tokens(vec![
         Token::ConstantDecl,
         Token::StringType,
-        Token::Identifier('y'.to_string()),
+        Token::Identifier("y".into()),
         Token::Assign,
-        Token::StringLiteral("Salam".to_string()),
+        Token::StringLiteral("Salam".into()),
     ]);
     let mut tokens2 = create_tokens(vec![
         Token::MutableDecl,
         Token::StringType,
-        Token::Identifier('y'.to_string()),
+        Token::Identifier("y".into()),
         Token::Assign,
-        Token::StringLiteral("Salam".to_string()),
+        Token::StringLiteral("Salam".into()),
     ]);
     let result = parse_statement(&mut tokens).expect("String testdə problem oldu");
     let result2 = parse_statement(&mut tokens2).expect("String testdə problem oldu");
     assert_eq!(
         result2,
-        Statement::Decl {
+        Statement::from(StatementKind::Decl {
             name: Atom::from("y"),
             typ: Rc::new(Type::String(StringEnum::LiteralString)),
+            type_span: SourceSpan::default(),
             is_mutable: true,
-            value: Box::new(Expr::String(Atom::from("Salam")))
-        }
+            value: Box::new(Expr::from(ExprKind::String(Atom::from("Salam")))),
+            doc: None
+        })
     );
 
     assert_eq!(
         result,
-        Statement::Decl {
+        Statement::from(StatementKind::Decl {
             name: Atom::from("y"),
             typ: Rc::new(Type::String(StringEnum::LiteralConstString)),
+            type_span: SourceSpan::default(),
             is_mutable: false,
-            value: Box::new(Expr::String(Atom::from("Salam")))
-        }
+            value: Box::new(Expr::from(ExprKind::String(Atom::from("Salam")))),
+            doc: None
+        })
     )
 }
 
@@ -72,17 +79,94 @@ fn test_parse_statement_mutable_decl() {
     let mut tokens = create_tokens(vec![
         Token::MutableDecl,
         Token::IntegerType,
-        Token::Identifier("y".to_string()),
+        Token::Identifier("y".into()),
         Token::Assign,
         Token::Number(10),
         Token::Newline,
     ]);
     let result = parse_statement(&mut tokens).expect("mutable decl parse edilmədi");
     assert!(matches!(
-        result,
-        Statement::Decl {
+        result.kind,
+        StatementKind::Decl {
             is_mutable: true,
             ..
         }
     ));
 }
+
+#[test]
+fn test_parse_char_decl() {
+    let mut tokens = create_tokens(vec![
+        Token::ConstantDecl,
+        Token::CharType,
+        Token::Identifier("c".into()),
+        Token::Assign,
+        Token::CharLiteral('ə'),
+        Token::Newline,
+    ]);
+    let result = parse_statement(&mut tokens).expect("char decl parse edilmədi");
+    assert_eq!(
+        result,
+        Statement::from(StatementKind::Decl {
+            name: Atom::from("c"),
+            typ: Rc::new(Type::Char),
+            type_span: SourceSpan::default(),
+            is_mutable: false,
+            value: Box::new(Expr::from(ExprKind::Char('ə'))),
+            doc: None
+        })
+    );
+}
+
+#[test]
+fn test_parse_typed_number_decl() {
+    let mut tokens = create_tokens(vec![
+        Token::ConstantDecl,
+        Token::NaturalType,
+        Token::Identifier("n".into()),
+        Token::Assign,
+        Token::TypedNumber(255, NumberSuffix::Natural),
+        Token::Newline,
+    ]);
+    let result = parse_statement(&mut tokens).expect("decl parse edilmədi");
+    let StatementKind::Decl { value, .. } = result.kind else {
+        panic!("Decl gözlənilirdi");
+    };
+    assert_eq!(
+        *value,
+        Expr::from(ExprKind::TypedNumber {
+            value: 255,
+            typ: Type::Natural
+        })
+    );
+}
+
+#[test]
+fn test_parse_decl_with_azerbaijani_keywords() {
+    let source = "dəyişən tam say = 1\nsabit mətn ad = \"Salam\"\n";
+    let ast = crate::parse_file_with_keywords("az.az", source, KeywordSet::Az)
+        .expect("Azərbaycan açar sözləri parse edilmədi");
+    let kinds: Vec<_> = ast
+        .iter()
+        .map(|stmt| match &stmt.kind {
+            StatementKind::Decl {
+                name,
+                typ,
+                is_mutable,
+                ..
+            } => (name.to_string(), (**typ).clone(), *is_mutable),
+            other => panic!("Decl gözlənilirdi, {other:?} alındı"),
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ("say".to_string(), Type::Integer, true),
+            (
+                "ad".to_string(),
+                Type::String(StringEnum::LiteralConstString),
+                false
+            ),
+        ]
+    );
+}
@@ -0,0 +1,71 @@
+use std::rc::Rc;
+
+use tokenizer::tokens::Token;
+
+use crate::{
+    ast::{Atom, Expr, ExprKind, SourceSpan, Statement, StatementKind},
+    binary_op::parse_statement,
+    parser,
+    shared_ast::Type,
+    tests::create_tokens,
+};
+
+#[test]
+fn test_doc_comment_attached_to_decl() {
+    let mut tokens = create_tokens(vec![
+        Token::Comment("Sayğac".to_string()),
+        Token::Newline,
+        Token::ConstantDecl,
+        Token::IntegerType,
+        Token::Identifier("x".into()),
+        Token::Assign,
+        Token::Number(1),
+        Token::Newline,
+    ]);
+    let result = parse_statement(&mut tokens).expect("doc decl parse edilmədi");
+    assert_eq!(
+        result,
+        Statement::from(StatementKind::Decl {
+            name: Atom::from("x"),
+            typ: Rc::new(Type::Integer),
+            type_span: SourceSpan::default(),
+            is_mutable: false,
+            value: Box::new(Expr::from(ExprKind::Number(1))),
+            doc: Some("Sayğac".to_string())
+        })
+    );
+}
+
+#[test]
+fn test_multiline_doc_comment_attached_to_function() {
+    let ast = parser("/// İki ədədi toplayır.\n///\n/// Nəticə `int` olur.\nfunc add(const int a, const int b): int\n    return a + b\n".to_string())
+        .expect("parse edilmədi");
+    let StatementKind::FunctionDef { name, doc, .. } = &ast[0].kind else {
+        panic!("FunctionDef gözlənilirdi, {:?} alındı", ast[0]);
+    };
+    assert_eq!(name.as_ref(), "add");
+    assert_eq!(
+        doc.as_deref(),
+        Some("İki ədədi toplayır.\n\nNəticə `int` olur.")
+    );
+}
+
+#[test]
+fn test_doc_comment_before_expression_is_kept_as_comment() {
+    let mut tokens = create_tokens(vec![
+        Token::Comment("qeyd".to_string()),
+        Token::Newline,
+        Token::Identifier("x".into()),
+    ]);
+    let result = parse_statement(&mut tokens).expect("comment parse edilmədi");
+    assert_eq!(
+        result,
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Comment(
+            "qeyd".to_string()
+        ))))
+    );
+    assert_eq!(
+        tokens.next().map(|t| t.token),
+        Some(Token::Identifier("x".into()))
+    );
+}
@@ -0,0 +1,38 @@
+use tokenizer::tokens::Token;
+
+use crate::{
+    ast::{Expr, ExprKind, Statement, StatementKind},
+    binary_op::parse_statement,
+    errors::ParserError,
+    tests::create_tokens,
+};
+
+#[test]
+fn test_parse_exit() {
+    let mut tokens = create_tokens(vec![
+        Token::Exit,
+        Token::LParen,
+        Token::Number(3),
+        Token::RParen,
+        Token::Newline,
+    ]);
+    let result = parse_statement(&mut tokens).expect("exit parse edilmədi");
+    assert_eq!(
+        result,
+        Statement::from(StatementKind::Exit(Expr::from(ExprKind::Number(3))))
+    );
+}
+
+#[test]
+fn test_parse_exit_without_parens() {
+    let mut tokens = create_tokens(vec![Token::Exit, Token::Number(3), Token::Newline]);
+    let result = parse_statement(&mut tokens);
+    assert!(matches!(
+        result,
+        Err(ParserError::ExpectedToken(
+            _,
+            Token::LParen,
+            Token::Number(3)
+        ))
+    ));
+}
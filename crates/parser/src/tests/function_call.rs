@@ -1,8 +1,9 @@
 use std::f64::consts::PI;
 
 use crate::{
-    ast::{Atom, Expr, Operation, Statement},
+    ast::{Atom, Expr, ExprKind, Operation, Statement, StatementKind},
     binary_op::{parse_expression, parse_statement},
+    shared_ast::Type,
     tests::create_tokens,
 };
 use tokenizer::tokens::Token;
@@ -10,7 +11,7 @@ use tokenizer::tokens::Token;
 #[test]
 fn test_parse_function_call_no_args() {
     let mut tokens = create_tokens(vec![
-        Token::Identifier("x".to_string()),
+        Token::Identifier("x".into()),
         Token::LParen,
         Token::RParen,
     ]);
@@ -19,21 +20,21 @@ fn test_parse_function_call_no_args() {
 
     assert_eq!(
         result,
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("x"),
                 symbol: None
-            }),
+            })),
             args: vec![]
-        })
+        })))
     )
 }
 
 #[test]
 fn test_parse_function_call_single_arg() {
     let mut tokens = create_tokens(vec![
-        Token::Identifier("x".to_string()),
+        Token::Identifier("x".into()),
         Token::LParen,
         Token::Number(5),
         Token::RParen,
@@ -43,21 +44,21 @@ fn test_parse_function_call_single_arg() {
 
     assert_eq!(
         result,
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("x"),
                 symbol: None
-            }),
-            args: vec![Expr::Number(5)]
-        })
+            })),
+            args: vec![Expr::from(ExprKind::Number(5))]
+        })))
     )
 }
 
 #[test]
 fn test_parse_function_call_multiple_args() {
     let mut tokens = create_tokens(vec![
-        Token::Identifier("x".to_string()),
+        Token::Identifier("x".into()),
         Token::LParen,
         Token::Number(1),
         Token::Comma,
@@ -71,23 +72,27 @@ fn test_parse_function_call_multiple_args() {
 
     assert_eq!(
         result,
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("x"),
                 symbol: None
-            }),
-            args: vec![Expr::Number(1), Expr::Number(2), Expr::Number(3)]
-        })
+            })),
+            args: vec![
+                Expr::from(ExprKind::Number(1)),
+                Expr::from(ExprKind::Number(2)),
+                Expr::from(ExprKind::Number(3))
+            ]
+        })))
     )
 }
 
 #[test]
 fn test_parse_function_call_string_arg() {
     let mut tokens = create_tokens(vec![
-        Token::Identifier("x".to_string()),
+        Token::Identifier("x".into()),
         Token::LParen,
-        Token::StringLiteral("hello".to_string()),
+        Token::StringLiteral("hello".into()),
         Token::RParen,
     ]);
 
@@ -95,21 +100,21 @@ fn test_parse_function_call_string_arg() {
 
     assert_eq!(
         result,
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("x"),
                 symbol: None
-            }),
-            args: vec![Expr::String(Atom::from("hello"))]
-        })
+            })),
+            args: vec![Expr::from(ExprKind::String(Atom::from("hello")))]
+        })))
     )
 }
 
 #[test]
 fn test_parse_function_call_bool_arg() {
     let mut tokens = create_tokens(vec![
-        Token::Identifier("x".to_string()),
+        Token::Identifier("x".into()),
         Token::LParen,
         Token::True,
         Token::RParen,
@@ -119,23 +124,23 @@ fn test_parse_function_call_bool_arg() {
 
     assert_eq!(
         result,
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("x"),
                 symbol: None
-            }),
-            args: vec![Expr::Bool(true)]
-        })
+            })),
+            args: vec![Expr::from(ExprKind::Bool(true))]
+        })))
     )
 }
 
 #[test]
 fn test_parse_function_call_variable_arg() {
     let mut tokens = create_tokens(vec![
-        Token::Identifier("x".to_string()),
+        Token::Identifier("x".into()),
         Token::LParen,
-        Token::Identifier("y".to_string()),
+        Token::Identifier("y".into()),
         Token::RParen,
     ]);
 
@@ -143,24 +148,24 @@ fn test_parse_function_call_variable_arg() {
 
     assert_eq!(
         result,
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("x"),
                 symbol: None
-            }),
-            args: vec![Expr::VariableRef {
+            })),
+            args: vec![Expr::from(ExprKind::VariableRef {
                 name: Atom::from("y"),
                 symbol: None
-            }]
-        })
+            })]
+        })))
     )
 }
 
 #[test]
 fn test_parse_function_call_float_arg() {
     let mut tokens = create_tokens(vec![
-        Token::Identifier("x".to_string()),
+        Token::Identifier("x".into()),
         Token::LParen,
         Token::Float(PI),
         Token::RParen,
@@ -170,29 +175,29 @@ fn test_parse_function_call_float_arg() {
 
     assert_eq!(
         result,
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("x"),
                 symbol: None
-            }),
-            args: vec![Expr::Float(PI)]
-        })
+            })),
+            args: vec![Expr::from(ExprKind::Float(PI))]
+        })))
     )
 }
 
 #[test]
 fn test_parse_function_call_mixed_args() {
     let mut tokens = create_tokens(vec![
-        Token::Identifier("x".to_string()),
+        Token::Identifier("x".into()),
         Token::LParen,
         Token::Number(1),
         Token::Comma,
-        Token::StringLiteral("hello".to_string()),
+        Token::StringLiteral("hello".into()),
         Token::Comma,
         Token::True,
         Token::Comma,
-        Token::Identifier("z".to_string()),
+        Token::Identifier("z".into()),
         Token::RParen,
     ]);
 
@@ -200,29 +205,29 @@ fn test_parse_function_call_mixed_args() {
 
     assert_eq!(
         result,
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("x"),
                 symbol: None
-            }),
+            })),
             args: vec![
-                Expr::Number(1),
-                Expr::String(Atom::from("hello")),
-                Expr::Bool(true),
-                Expr::VariableRef {
+                Expr::from(ExprKind::Number(1)),
+                Expr::from(ExprKind::String(Atom::from("hello"))),
+                Expr::from(ExprKind::Bool(true)),
+                Expr::from(ExprKind::VariableRef {
                     name: Atom::from("z"),
                     symbol: None
-                }
+                })
             ]
-        })
+        })))
     )
 }
 
 #[test]
 fn test_parse_function_call_expression_direct() {
     let mut tokens = create_tokens(vec![
-        Token::Identifier("foo".to_string()),
+        Token::Identifier("foo".into()),
         Token::LParen,
         Token::Number(42),
         Token::RParen,
@@ -232,21 +237,21 @@ fn test_parse_function_call_expression_direct() {
 
     assert_eq!(
         result,
-        Expr::Call {
+        Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("foo"),
                 symbol: None
-            }),
-            args: vec![Expr::Number(42)]
-        }
+            })),
+            args: vec![Expr::from(ExprKind::Number(42))]
+        })
     )
 }
 
 #[test]
 fn test_parse_function_call_neg_arg() {
     let mut tokens = create_tokens(vec![
-        Token::Identifier("x".to_string()),
+        Token::Identifier("x".into()),
         Token::LParen,
         Token::Subtract,
         Token::Number(5),
@@ -257,24 +262,24 @@ fn test_parse_function_call_neg_arg() {
 
     assert_eq!(
         result,
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("x"),
                 symbol: None
-            }),
-            args: vec![Expr::Number(-5)]
-        })
+            })),
+            args: vec![Expr::from(ExprKind::Number(-5))]
+        })))
     )
 }
 
 #[test]
 fn test_parse_function_call_not_arg() {
     let mut tokens = create_tokens(vec![
-        Token::Identifier("x".to_string()),
+        Token::Identifier("x".into()),
         Token::LParen,
         Token::Not,
-        Token::Identifier("flag".to_string()),
+        Token::Identifier("flag".into()),
         Token::RParen,
     ]);
 
@@ -282,27 +287,27 @@ fn test_parse_function_call_not_arg() {
 
     assert_eq!(
         result,
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("x"),
                 symbol: None
-            }),
-            args: vec![Expr::UnaryOp {
+            })),
+            args: vec![Expr::from(ExprKind::UnaryOp {
                 op: Operation::Not,
-                expr: Box::new(Expr::VariableRef {
+                expr: Box::new(Expr::from(ExprKind::VariableRef {
                     name: Atom::from("flag"),
                     symbol: None
-                })
-            }]
-        })
+                }))
+            })]
+        })))
     )
 }
 
 #[test]
 fn test_parse_function_call_two_args_expression() {
     let mut tokens = create_tokens(vec![
-        Token::Identifier("add".to_string()),
+        Token::Identifier("add".into()),
         Token::LParen,
         Token::Number(10),
         Token::Comma,
@@ -314,21 +319,24 @@ fn test_parse_function_call_two_args_expression() {
 
     assert_eq!(
         result,
-        Expr::Call {
+        Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("add"),
                 symbol: None
-            }),
-            args: vec![Expr::Number(10), Expr::Number(20)]
-        }
+            })),
+            args: vec![
+                Expr::from(ExprKind::Number(10)),
+                Expr::from(ExprKind::Number(20))
+            ]
+        })
     )
 }
 
 #[test]
 fn test_parse_function_call_false_arg() {
     let mut tokens = create_tokens(vec![
-        Token::Identifier("f".to_string()),
+        Token::Identifier("f".into()),
         Token::LParen,
         Token::False,
         Token::RParen,
@@ -338,13 +346,161 @@ fn test_parse_function_call_false_arg() {
 
     assert_eq!(
         result,
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("f"),
                 symbol: None
-            }),
-            args: vec![Expr::Bool(false)]
+            })),
+            args: vec![Expr::from(ExprKind::Bool(false))]
+        })))
+    )
+}
+
+fn var(name: &str) -> Box<Expr> {
+    Box::new(Expr::from(ExprKind::VariableRef {
+        name: name.into(),
+        symbol: None,
+    }))
+}
+
+#[test]
+fn test_call_result_is_called_again() {
+    let mut tokens = create_tokens(vec![
+        Token::Identifier("generator".into()),
+        Token::LParen,
+        Token::RParen,
+        Token::LParen,
+        Token::StringLiteral("Əli".into()),
+        Token::RParen,
+    ]);
+
+    let result = parse_expression(&mut tokens).expect("Parse failed");
+
+    assert_eq!(
+        result,
+        Expr::from(ExprKind::Call {
+            target: None,
+            name: Box::new(Expr::from(ExprKind::Call {
+                target: None,
+                name: var("generator"),
+                args: vec![],
+            })),
+            args: vec![Expr::from(ExprKind::String("Əli".into()))]
+        })
+    )
+}
+
+#[test]
+fn test_calls_as_binary_operands() {
+    let mut tokens = create_tokens(vec![
+        Token::Identifier("f".into()),
+        Token::LParen,
+        Token::Number(1),
+        Token::RParen,
+        Token::Add,
+        Token::Identifier("g".into()),
+        Token::LParen,
+        Token::Number(2),
+        Token::RParen,
+    ]);
+
+    let result = parse_expression(&mut tokens).expect("Parse failed");
+
+    assert_eq!(
+        result,
+        Expr::from(ExprKind::BinaryOp {
+            left: Box::new(Expr::from(ExprKind::Call {
+                target: None,
+                name: var("f"),
+                args: vec![Expr::from(ExprKind::Number(1))],
+            })),
+            right: Box::new(Expr::from(ExprKind::Call {
+                target: None,
+                name: var("g"),
+                args: vec![Expr::from(ExprKind::Number(2))],
+            })),
+            op: Operation::Add,
+        })
+    )
+}
+
+#[test]
+fn test_negated_call() {
+    let mut tokens = create_tokens(vec![
+        Token::Subtract,
+        Token::Identifier("f".into()),
+        Token::LParen,
+        Token::Identifier("x".into()),
+        Token::RParen,
+    ]);
+
+    let result = parse_expression(&mut tokens).expect("Parse failed");
+
+    assert_eq!(
+        result,
+        Expr::from(ExprKind::UnaryOp {
+            op: Operation::Subtract,
+            expr: Box::new(Expr::from(ExprKind::Call {
+                target: None,
+                name: var("f"),
+                args: vec![*var("x")],
+            })),
+        })
+    )
+}
+
+#[test]
+fn test_nested_index() {
+    let mut tokens = create_tokens(vec![
+        Token::Identifier("a".into()),
+        Token::ListStart,
+        Token::Number(0),
+        Token::ListEnd,
+        Token::ListStart,
+        Token::Number(1),
+        Token::ListEnd,
+    ]);
+
+    let result = parse_expression(&mut tokens).expect("Parse failed");
+
+    assert_eq!(
+        result,
+        Expr::from(ExprKind::Index {
+            target: Box::new(Expr::from(ExprKind::Index {
+                target: var("a"),
+                index: Box::new(Expr::from(ExprKind::Number(0))),
+                target_type: Type::Any,
+            })),
+            index: Box::new(Expr::from(ExprKind::Number(1))),
+            target_type: Type::Any,
+        })
+    )
+}
+
+#[test]
+fn test_member_chain_ends_in_method_call() {
+    let mut tokens = create_tokens(vec![
+        Token::Identifier("a".into()),
+        Token::Dot,
+        Token::Identifier("b".into()),
+        Token::Dot,
+        Token::Identifier("c".into()),
+        Token::LParen,
+        Token::RParen,
+    ]);
+
+    let result = parse_expression(&mut tokens).expect("Parse failed");
+
+    assert_eq!(
+        result,
+        Expr::from(ExprKind::Call {
+            target: Some(Box::new(Expr::from(ExprKind::Member {
+                target: var("a"),
+                name: "b".into(),
+            }))),
+            name: var("c"),
+            args: vec![],
         })
     )
 }
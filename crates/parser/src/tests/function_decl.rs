@@ -1,7 +1,7 @@
 use std::rc::Rc;
 
 use crate::{
-    ast::{Atom, Expr, Parameter, Statement},
+    ast::{Atom, Expr, ExprKind, Parameter, SourceSpan, Statement, StatementKind},
     binary_op::parse_statement,
     shared_ast::Type,
     tests::create_tokens,
@@ -12,15 +12,15 @@ use tokenizer::tokens::Token;
 fn test_parse_function_def() {
     let mut tokens = create_tokens(vec![
         Token::FunctionDef,
-        Token::Identifier("topla".to_string()),
+        Token::Identifier("topla".into()),
         Token::LParen,
         Token::ConstantDecl,
         Token::IntegerType,
-        Token::Identifier("a".to_string()),
+        Token::Identifier("a".into()),
         Token::Comma,
         Token::MutableDecl,
         Token::IntegerType,
-        Token::Identifier("b".to_string()),
+        Token::Identifier("b".into()),
         Token::RParen,
         Token::Colon,
         Token::IntegerType,
@@ -28,7 +28,7 @@ fn test_parse_function_def() {
         Token::Indent,
         Token::ConstantDecl,
         Token::IntegerType,
-        Token::Identifier("x".to_string()),
+        Token::Identifier("x".into()),
         Token::Assign,
         Token::Number(42),
         Token::Newline,
@@ -37,12 +37,13 @@ fn test_parse_function_def() {
 
     let result = parse_statement(&mut tokens).expect("Function declaration parse edilmədi");
 
-    let Statement::FunctionDef {
+    let StatementKind::FunctionDef {
         name,
         return_typ,
         params,
         body,
-    } = result
+        ..
+    } = result.kind
     else {
         panic!("FunctionDef statement gözlənilirdi");
     };
@@ -56,22 +57,28 @@ fn test_parse_function_def() {
                 name: Atom::from("a"),
                 typ: Type::Integer,
                 is_pointer: false,
+                span: SourceSpan::default(),
+                type_span: SourceSpan::default(),
             },
             Parameter {
                 name: Atom::from("b"),
                 typ: Type::Integer,
                 is_pointer: true,
+                span: SourceSpan::default(),
+                type_span: SourceSpan::default(),
             },
         ]
     );
     assert_eq!(
         body,
-        vec![Statement::Decl {
+        vec![Statement::from(StatementKind::Decl {
             name: Atom::from("x"),
             typ: Rc::new(Type::Integer),
+            type_span: SourceSpan::default(),
             is_mutable: false,
-            value: Box::new(Expr::Number(42)),
-        }]
+            value: Box::new(Expr::from(ExprKind::Number(42))),
+            doc: None,
+        })]
     );
 }
 
@@ -79,23 +86,23 @@ fn test_parse_function_def() {
 fn test_parse_external_func_with_link() {
     let mut tokens = create_tokens(vec![
         Token::At,
-        Token::Identifier("link".to_string()),
+        Token::Identifier("link".into()),
         Token::LParen,
-        Token::StringLiteral("printlib".to_string()),
+        Token::StringLiteral("printlib".into()),
         Token::RParen,
         Token::Newline,
         Token::At,
-        Token::Identifier("external".to_string()),
+        Token::Identifier("external".into()),
         Token::LParen,
-        Token::StringLiteral("../build/printlib.so".to_string()),
+        Token::StringLiteral("../build/printlib.so".into()),
         Token::RParen,
         Token::Newline,
         Token::FunctionDef,
-        Token::Identifier("print".to_string()),
+        Token::Identifier("print".into()),
         Token::LParen,
         Token::ConstantDecl,
         Token::AnyType,
-        Token::Identifier("val".to_string()),
+        Token::Identifier("val".into()),
         Token::RParen,
         Token::Colon,
         Token::Void,
@@ -104,7 +111,7 @@ fn test_parse_external_func_with_link() {
 
     let result = parse_statement(&mut tokens).expect("External function with @link parse edilmədi");
 
-    let Statement::ExternalFunctionDef { name, library, .. } = result else {
+    let StatementKind::ExternalFunctionDef { name, library, .. } = result.kind else {
         panic!("ExternalFunctionDef statement gözlənilirdi");
     };
 
@@ -116,17 +123,17 @@ fn test_parse_external_func_with_link() {
 fn test_parse_external_func_without_link() {
     let mut tokens = create_tokens(vec![
         Token::At,
-        Token::Identifier("external".to_string()),
+        Token::Identifier("external".into()),
         Token::LParen,
-        Token::StringLiteral("../build/printlib.so".to_string()),
+        Token::StringLiteral("../build/printlib.so".into()),
         Token::RParen,
         Token::Newline,
         Token::FunctionDef,
-        Token::Identifier("print".to_string()),
+        Token::Identifier("print".into()),
         Token::LParen,
         Token::ConstantDecl,
         Token::AnyType,
-        Token::Identifier("val".to_string()),
+        Token::Identifier("val".into()),
         Token::RParen,
         Token::Colon,
         Token::Void,
@@ -136,7 +143,7 @@ fn test_parse_external_func_without_link() {
     let result =
         parse_statement(&mut tokens).expect("External function without @link parse edilmədi");
 
-    let Statement::ExternalFunctionDef { name, library, .. } = result else {
+    let StatementKind::ExternalFunctionDef { name, library, .. } = result.kind else {
         panic!("ExternalFunctionDef statement gözlənilirdi");
     };
 
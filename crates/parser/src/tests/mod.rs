@@ -4,24 +4,25 @@ mod object;
 use tokenizer::tokens::Token;
 
 type TestResult = Result<(), Box<dyn std::error::Error>>;
-pub fn create_tokens(tokens_vec: Vec<Token>) -> Tokens {
+pub fn create_tokens(tokens_vec: Vec<Token>) -> Tokens<'static> {
     let mut tokens = Tokens::default();
     for token in tokens_vec {
-        tokens.push(
-            token,
-            SourceSpan {
-                start: 0,
-                end: 0,
-                line: 0,
-            },
-        );
+        tokens.push(token, SourceSpan::default());
     }
     tokens
 }
 mod assignment;
+mod attribute;
 mod binary_op_test;
 mod condition;
 mod decl;
+mod doc;
+mod exit;
 mod function_call;
 mod function_decl;
+mod recovery;
+mod span;
+mod suggest;
+mod template;
+mod types;
 mod while_loop;
@@ -3,11 +3,11 @@ use tokenizer::tokens::Token;
 
 #[test]
 pub fn create_an_object() {
-    let mut tokens = create_tokens(vec![Token::Object, Token::Identifier(String::from("Adam"))]);
+    let mut tokens = create_tokens(vec![Token::Object, Token::Identifier("Adam".into())]);
 
     assert!(expect_token(&mut tokens, Token::Object).is_ok());
     assert_eq!(
         tokens.next().map(|token| token.token),
-        Some(Token::Identifier(String::from("Adam")))
+        Some(Token::Identifier("Adam".into()))
     );
 }
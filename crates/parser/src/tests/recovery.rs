@@ -0,0 +1,83 @@
+use crate::{errors::ParserError, parse_file, parser};
+
+fn errors(source: &str) -> Vec<ParserError> {
+    parse_file("recovery.az", source)
+        .expect_err("xəta gözlənilirdi")
+        .into_errors()
+}
+
+#[test]
+fn test_reports_every_top_level_error() {
+    let errors = errors("const int = 5\nconst int b = 2\nconst int = 3\n");
+    assert_eq!(errors.len(), 2);
+    assert!(
+        errors
+            .iter()
+            .all(|e| matches!(e, ParserError::DeclNameNotFound(..)))
+    );
+}
+
+#[test]
+fn test_single_error_is_not_wrapped() {
+    let error = parser("const int = 5\nconst int b = 2\n".to_string()).unwrap_err();
+    assert!(matches!(error, ParserError::DeclNameNotFound(..)));
+}
+
+#[test]
+fn test_recovers_inside_function_body() {
+    let source = "func f(): int\n    const int = 1\n    return 2\n    const = 3\nconst int b = 4\n";
+    let errors = errors(source);
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(errors[0], ParserError::DeclNameNotFound(..)));
+    let span = errors[1].span().expect("span gözlənilirdi");
+    assert_eq!(span.location().map(|l| l.line), Some(4));
+}
+
+#[test]
+fn test_broken_if_header_skips_its_else_branch() {
+    let errors = errors("if a ==\n    exit(1)\nelse\n    exit(2)\nconst = 1\n");
+    assert_eq!(errors.len(), 2);
+    let lines: Vec<_> = errors
+        .iter()
+        .map(|e| e.span().and_then(|s| s.location()).map(|l| l.line))
+        .collect();
+    assert_eq!(lines, vec![Some(1), Some(5)]);
+}
+
+#[test]
+fn test_errors_in_both_branches_are_reported() {
+    let errors = errors("if a == 1\n    const = 1\nelse\n    const = 2\n");
+    assert_eq!(errors.len(), 2);
+}
+
+#[test]
+fn test_lexer_errors_are_collected_before_parsing() {
+    let errors = errors("a $ b\nc ; d\n");
+    assert_eq!(errors.len(), 2);
+    assert!(
+        errors
+            .iter()
+            .all(|e| matches!(e, ParserError::LexerError(_)))
+    );
+}
+
+#[test]
+fn test_lexer_and_syntax_errors_are_reported_together() {
+    let errors = errors("const int = 1\nconst int = 2\nx ; y\n");
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(errors[0], ParserError::DeclNameNotFound(..)));
+    assert!(matches!(errors[1], ParserError::DeclNameNotFound(..)));
+    assert!(matches!(errors[2], ParserError::LexerError(_)));
+}
+
+#[test]
+fn test_syntax_errors_on_a_lexer_error_line_are_dropped() {
+    let errors = errors("exit(1 $ 2)\nconst int = 5\n");
+    let lines: Vec<_> = errors
+        .iter()
+        .map(|e| e.span().and_then(|s| s.location()).map(|l| l.line))
+        .collect();
+    assert_eq!(lines, vec![Some(1), Some(2)]);
+    assert!(matches!(errors[0], ParserError::LexerError(_)));
+    assert!(matches!(errors[1], ParserError::DeclNameNotFound(..)));
+}
@@ -0,0 +1,40 @@
+use crate::{
+    ast::{ExprKind, StatementKind},
+    parse_file,
+};
+
+fn text<'a>(source: &'a str, span: &tokenizer::iterator::SourceSpan) -> &'a str {
+    &source[span.start as usize..span.end as usize]
+}
+
+#[test]
+fn test_decl_span_covers_whole_statement() {
+    let source = "const int a = 2 + 3\n";
+    let ast = parse_file("span.az", source).expect("parse edilmədi");
+
+    assert_eq!(text(source, &ast[0].span), "const int a = 2 + 3");
+    let StatementKind::Decl {
+        type_span, value, ..
+    } = &ast[0].kind
+    else {
+        panic!("Decl gözlənilirdi, {:?} alındı", ast[0]);
+    };
+    assert_eq!(text(source, type_span), "int");
+    assert_eq!(text(source, &value.span), "2 + 3");
+    let ExprKind::BinaryOp { left, right, .. } = &value.kind else {
+        panic!("BinaryOp gözlənilirdi, {value:?} alındı");
+    };
+    assert_eq!(text(source, &left.span), "2");
+    assert_eq!(text(source, &right.span), "3");
+}
+
+#[test]
+fn test_call_span_on_second_line() {
+    let source = "const int a = 1\nprint(a)\n";
+    let ast = parse_file("span.az", source).expect("parse edilmədi");
+
+    let span = &ast[1].span;
+    assert_eq!(text(source, span), "print(a)");
+    let location = span.location().expect("fayl qeydə alınmalıdır");
+    assert_eq!((location.line, location.column), (2, 1));
+}
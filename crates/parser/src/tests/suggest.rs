@@ -0,0 +1,71 @@
+use tokenizer::tokens::Token;
+
+use crate::{
+    errors::ParserError,
+    parser,
+    suggest::{edit_distance, similar_name},
+};
+
+fn keyword_hint(source: &str) -> Option<String> {
+    match parser(source.to_string()) {
+        Err(ParserError::UnexpectedToken(_, Token::Identifier(_), hint)) => hint,
+        _ => None,
+    }
+}
+
+#[test]
+fn test_edit_distance() {
+    assert_eq!(edit_distance("const", "const"), 0);
+    assert_eq!(edit_distance("cons", "const"), 1);
+    assert_eq!(edit_distance("cosnt", "const"), 1);
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+    assert_eq!(edit_distance("", "abc"), 3);
+    assert_eq!(edit_distance("ağac", "agac"), 1);
+}
+
+#[test]
+fn test_similar_name_respects_threshold() {
+    let names = ["value", "count", "total"];
+    assert_eq!(similar_name("valeu", names), Some("value"));
+    assert_eq!(similar_name("cont", names), Some("count"));
+    assert_eq!(similar_name("xyz", names), None);
+    assert_eq!(similar_name("b", ["a"]), None);
+    assert_eq!(similar_name("value", names), None);
+}
+
+#[test]
+fn test_similar_name_prefers_case_difference_and_is_deterministic() {
+    assert_eq!(similar_name("VALUE", ["valu", "value"]), Some("value"));
+    assert_eq!(similar_name("abc", ["abd", "abb"]), Some("abb"));
+    assert_eq!(similar_name("abc", ["abb", "abd"]), Some("abb"));
+}
+
+#[test]
+fn test_misspelled_keywords_are_reported() {
+    assert_eq!(keyword_hint("cosnt int a = 1\n").as_deref(), Some("const"));
+    assert_eq!(keyword_hint("vra a = 1\n").as_deref(), Some("var"));
+    assert_eq!(
+        keyword_hint("const int a = 1\nwhlie a > 0\n    exit(a)\n").as_deref(),
+        Some("while")
+    );
+    assert_eq!(
+        keyword_hint("const int a = 1\nretrun a\n").as_deref(),
+        Some("return")
+    );
+}
+
+#[test]
+fn test_keyword_hints_come_from_the_file_keyword_set() {
+    assert_eq!(keyword_hint("sabti int a = 1\n"), None);
+    assert_eq!(
+        keyword_hint("# keywords: az\nsabti tam a = 1\n").as_deref(),
+        Some("sabit")
+    );
+    assert_eq!(keyword_hint("# keywords: az\ncosnt tam a = 1\n"), None);
+}
+
+#[test]
+fn test_similar_identifiers_in_expressions_are_not_keywords() {
+    assert!(parser("const bool a = true\nconst bool b = a and a\nexit(b)\n".to_string()).is_ok());
+    assert!(parser("const int fi = 1\nfi = 2\n".to_string()).is_ok());
+}
@@ -0,0 +1,91 @@
+use crate::{
+    ast::{Align, ExprKind, FormatKind, StatementKind, TemplateChunk},
+    errors::ParserError,
+    parser,
+    template::parse_format_spec,
+};
+use tokenizer::iterator::SourceSpan;
+
+fn template(source: &str) -> Vec<TemplateChunk> {
+    let statements = parser(source.to_string()).expect("parse edilmədi");
+    let StatementKind::Decl { value, .. } = &statements[0].kind else {
+        panic!("dəyişən gözlənilirdi");
+    };
+    match &value.kind {
+        ExprKind::TemplateString(chunks) => chunks.clone(),
+        other => panic!("şablon gözlənilirdi: {other:?}"),
+    }
+}
+
+#[test]
+fn test_template_with_format_specs() {
+    let chunks = template("const str s = `${price:.2} ${n:*>5} ${x:X}`\n");
+    let specs: Vec<_> = chunks
+        .iter()
+        .filter_map(|chunk| match chunk {
+            TemplateChunk::Expr(_, spec) => spec.clone(),
+            TemplateChunk::Literal(_) => None,
+        })
+        .collect();
+    assert_eq!(specs.len(), 3);
+    assert_eq!(specs[0].precision, Some(2));
+    assert_eq!(specs[0].align, None);
+    assert_eq!(
+        (specs[1].fill, specs[1].align, specs[1].width),
+        ('*', Some(Align::Right), 5)
+    );
+    assert_eq!(specs[2].kind, FormatKind::UpperHex);
+}
+
+#[test]
+fn test_nested_template() {
+    let chunks = template("const str s = `a ${`b ${c}`} d`\n");
+    let [
+        TemplateChunk::Literal(a),
+        TemplateChunk::Expr(inner, None),
+        TemplateChunk::Literal(d),
+    ] = chunks.as_slice()
+    else {
+        panic!("gözlənilməz hissələr: {chunks:?}");
+    };
+    assert_eq!((a.as_str(), d.as_str()), ("a ", " d"));
+    let ExprKind::TemplateString(inner) = &inner.kind else {
+        panic!("daxili şablon gözlənilirdi");
+    };
+    assert_eq!(inner.len(), 2);
+}
+
+#[test]
+fn test_format_spec_grammar() {
+    let spec = parse_format_spec("08.3", SourceSpan::default()).unwrap();
+    assert!(spec.zero);
+    assert_eq!((spec.width, spec.precision), (8, Some(3)));
+
+    let spec = parse_format_spec("^10", SourceSpan::default()).unwrap();
+    assert_eq!(
+        (spec.fill, spec.align, spec.width),
+        (' ', Some(Align::Center), 10)
+    );
+
+    let spec = parse_format_spec("ə<3b", SourceSpan::default()).unwrap();
+    assert_eq!(
+        (spec.fill, spec.align, spec.kind),
+        ('ə', Some(Align::Left), FormatKind::Binary)
+    );
+
+    for bad in [".", "5q", ">>>", "x5", "99999999"] {
+        assert!(
+            matches!(
+                parse_format_spec(bad, SourceSpan::default()),
+                Err(ParserError::InvalidFormatSpec(_, ref spec)) if spec == bad
+            ),
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn test_invalid_format_spec_in_source() {
+    let error = parser("const str s = `${x:.}`\n".to_string()).unwrap_err();
+    assert_eq!(error.code(), Some("AZ0125"));
+}
@@ -0,0 +1,52 @@
+use locale::{Lang, set_lang};
+
+use crate::shared_ast::{StringEnum, Type};
+
+fn every_type() -> Vec<Type> {
+    vec![
+        Type::String(StringEnum::DynamicString),
+        Type::String(StringEnum::LiteralString),
+        Type::String(StringEnum::LiteralConstString),
+        Type::Array(Box::new(Type::Integer)),
+        Type::User("Nöqtə".into()),
+        Type::Integer,
+        Type::Natural,
+        Type::BigInteger,
+        Type::LowInteger,
+        Type::Bool,
+        Type::Char,
+        Type::Allocator,
+        Type::Void,
+        Type::Any,
+        Type::Float,
+        Type::ZigArray,
+        Type::ZigConstArray,
+        Type::ZigNatural,
+        Type::ZigFloat,
+        Type::ZigInteger,
+        Type::Function,
+        Type::Error,
+    ]
+}
+
+#[test]
+fn test_every_type_name_is_translated() {
+    for typ in every_type() {
+        for lang in Lang::ALL {
+            assert!(
+                lang.catalog().contains(typ.name_key()),
+                "{} dilində {typ:?} adı yoxdur",
+                lang.name()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_type_name_follows_current_language() {
+    assert_eq!(Type::Integer.to_string(), "Tam Ədəd");
+    set_lang(Lang::En);
+    assert_eq!(Type::Integer.to_string(), "Integer");
+    assert_eq!(Type::User("Nöqtə".into()).to_string(), "User(Nöqtə)");
+    set_lang(Lang::Az);
+}
@@ -1,12 +1,12 @@
 #[cfg(test)]
 mod tests {
-    use crate::ast::{Expr, Statement};
+    use crate::ast::{Expr, ExprKind, Statement, StatementKind};
     use crate::tests::create_tokens;
     use crate::r#while_loop::parse_while_loop;
     use tokenizer::tokens::Token;
 
     fn expr_stmt(expr: Expr) -> Statement {
-        Statement::Expr(expr)
+        Statement::from(StatementKind::Expr(expr))
     }
 
     #[test]
@@ -22,9 +22,9 @@ mod tests {
         ]);
         let result = parse_while_loop(&mut tokens).expect("while loop parse edilmədi");
 
-        if let Statement::While { condition, body } = result {
-            assert_eq!(*condition, Expr::Bool(true));
-            assert_eq!(body, vec![expr_stmt(Expr::Number(1))]);
+        if let StatementKind::While { condition, body } = result.kind {
+            assert_eq!(*condition, Expr::from(ExprKind::Bool(true)));
+            assert_eq!(body, vec![expr_stmt(Expr::from(ExprKind::Number(1)))]);
         } else {
             panic!("While statement gözlənilirdi");
         }
@@ -34,14 +34,14 @@ mod tests {
     fn test_parse_while_loop_comparison_condition() {
         let mut tokens = create_tokens(vec![
             Token::While,
-            Token::Identifier("x".to_string()),
+            Token::Identifier("x".into()),
             Token::Less,
             Token::Number(10),
             Token::Newline,
             Token::Indent,
-            Token::Identifier("x".to_string()),
+            Token::Identifier("x".into()),
             Token::Assign,
-            Token::Identifier("x".to_string()),
+            Token::Identifier("x".into()),
             Token::Add,
             Token::Number(1),
             Token::Newline,
@@ -49,17 +49,17 @@ mod tests {
         ]);
         let result = parse_while_loop(&mut tokens).expect("while loop parse edilmədi");
 
-        if let Statement::While { condition, body } = result {
+        if let StatementKind::While { condition, body } = result.kind {
             assert_eq!(
                 *condition,
-                Expr::BinaryOp {
-                    left: Box::new(Expr::VariableRef {
+                Expr::from(ExprKind::BinaryOp {
+                    left: Box::new(Expr::from(ExprKind::VariableRef {
                         name: "x".into(),
                         symbol: None,
-                    }),
-                    right: Box::new(Expr::Number(10)),
+                    })),
+                    right: Box::new(Expr::from(ExprKind::Number(10))),
                     op: crate::ast::Operation::Less,
-                }
+                })
             );
             assert_eq!(body.len(), 1);
         } else {
@@ -82,11 +82,11 @@ mod tests {
         ]);
         let result = parse_while_loop(&mut tokens).expect("while loop parse edilmədi");
 
-        if let Statement::While { condition, body } = result {
-            assert_eq!(*condition, Expr::Bool(true));
+        if let StatementKind::While { condition, body } = result.kind {
+            assert_eq!(*condition, Expr::from(ExprKind::Bool(true)));
             assert_eq!(body.len(), 2);
-            assert_eq!(body[0], expr_stmt(Expr::Number(1)));
-            assert_eq!(body[1], expr_stmt(Expr::Number(2)));
+            assert_eq!(body[0], expr_stmt(Expr::from(ExprKind::Number(1))));
+            assert_eq!(body[1], expr_stmt(Expr::from(ExprKind::Number(2))));
         } else {
             panic!("While statement gözlənilirdi");
         }
@@ -6,8 +6,42 @@ use crate::{
 use tokenizer::{
     iterator::{SpannedToken, Tokens},
     tokens::Token,
+    words::KeywordSet,
 };
 
+/// Hər iki dəstdə tip bildirən açar sözlər, məsələn `int` və `tam`.
+pub fn type_names() -> impl Iterator<Item = &'static str> {
+    KeywordSet::ALL.into_iter().flat_map(|set| {
+        set.words()
+            .iter()
+            .copied()
+            .filter(move |word| is_type_token(&set.tokenize(word)))
+    })
+}
+
+fn is_type_token(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::IntegerType
+            | Token::BigIntegerType
+            | Token::LowIntegerType
+            | Token::ZigConstArray
+            | Token::ZigArray
+            | Token::ZigNatural
+            | Token::ZigFloat
+            | Token::ZigInteger
+            | Token::BoolType
+            | Token::FnType
+            | Token::NaturalType
+            | Token::StringType
+            | Token::CharType
+            | Token::Void
+            | Token::FloatType
+            | Token::AnyType
+            | Token::Array
+    )
+}
+
 pub fn parse_type(tokens: &mut Tokens) -> Result<Type, ParserError> {
     let token = match tokens.next() {
         Some(token) => token,
@@ -38,7 +72,9 @@ pub fn parse_type(tokens: &mut Tokens) -> Result<Type, ParserError> {
                     token: Token::Less, ..
                 }) => {}
                 None => return Err(ParserError::UnexpectedEOF),
-                Some(other) => return Err(ParserError::ArrayExpected('<', other.token)),
+                Some(other) => {
+                    return Err(ParserError::ArrayExpected(other.span, '<', other.token));
+                }
             }
 
             let inner_type = parse_type(tokens)?;
@@ -49,12 +85,14 @@ pub fn parse_type(tokens: &mut Tokens) -> Result<Type, ParserError> {
                     ..
                 }) => {}
                 None => return Err(ParserError::UnexpectedEOF),
-                Some(other) => return Err(ParserError::ArrayExpected('>', other.token)),
+                Some(other) => {
+                    return Err(ParserError::ArrayExpected(other.span, '>', other.token));
+                }
             }
 
             Type::Array(Box::new(inner_type))
         }
-        ref _other => return Err(ParserError::UnexpectedToken(token.span, token.token)),
+        ref _other => return Err(ParserError::UnexpectedToken(token.span, token.token, None)),
     };
 
     Ok(typ)
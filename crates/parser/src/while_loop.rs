@@ -1,16 +1,23 @@
 use crate::{
-    ast::Statement, binary_op::parse_expression, condition::parse_block, errors::ParserError,
+    ast::{Statement, StatementKind},
+    binary_op::parse_expression,
+    condition::parse_block,
+    errors::ParserError,
     helpers::expect_token,
 };
 use tokenizer::{iterator::Tokens, tokens::Token};
 
 pub fn parse_while_loop(tokens: &mut Tokens) -> Result<Statement, ParserError> {
+    let start = tokens.peek_span();
     tokens.next();
     let condition = parse_expression(tokens)?;
     expect_token(tokens, Token::Newline)?;
     let body = parse_block(tokens)?;
-    Ok(Statement::While {
-        condition: Box::new(condition),
-        body,
-    })
+    Ok(Statement::new(
+        StatementKind::While {
+            condition: Box::new(condition),
+            body,
+        },
+        tokens.span_from(&start),
+    ))
 }
@@ -0,0 +1,68 @@
+//! Lexerin sürəti: `cargo bench -p tokenizer`.
+//!
+//! 100 000 sətirlik yaradılmış faylı bir neçə dəfə tokenlərə ayırır və ən yaxşı
+//! nəticəni MB/s və sətir/s ilə çap edir. `lazy` tokenləri parser kimi bir-bir
+//! götürür, bütün faylın tokenləri yaddaşda saxlanmır.
+
+use std::{hint::black_box, time::Instant};
+
+use tokenizer::Lexer;
+
+const LINES: usize = 100_000;
+const RUNS: usize = 5;
+
+/// Hər funksiya 10 sətirdir: şərh, təyinatlar, şərt, dövr, şablon və qaytarma.
+fn generate(lines: usize) -> String {
+    let mut source = String::new();
+    for i in 0..lines / 10 {
+        source.push_str(&format!(
+            "# {i} nömrəli funksiya\n\
+             func hesabla_{i}(const int say, const str ad): int\n    \
+             var int cəm = say * {i} + 0x1F\n    \
+             const float orta = 3.25e2 / 7.5\n    \
+             if cəm >= 100 && ad != \"boş\\n\":\n        \
+             cəm -= 1\n    \
+             for j in 0..say:\n        \
+             cəm += j % 3\n    \
+             print(`Salam, ${{ad:>10}}! Cəm: ${{cəm}}`)\n    \
+             return cəm\n"
+        ));
+    }
+    source
+}
+
+fn measure(name: &str, source: &str, mut run: impl FnMut(&str) -> usize) {
+    let mut best = f64::MAX;
+    let mut tokens = 0;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        tokens = black_box(run(black_box(source)));
+        best = best.min(start.elapsed().as_secs_f64());
+    }
+    let megabytes = source.len() as f64 / 1_000_000.0;
+    println!(
+        "{name:<10} {tokens:>9} token  {:>8.2} ms  {:>8.1} MB/s  {:>12.0} sətir/s",
+        best * 1000.0,
+        megabytes / best,
+        LINES as f64 / best,
+    );
+}
+
+fn main() {
+    let source = generate(LINES);
+    println!(
+        "{} sətir, {:.1} MB",
+        LINES,
+        source.len() as f64 / 1_000_000.0
+    );
+
+    measure("tokenize", &source, |source| {
+        Lexer::new(source).tokenize().unwrap().count()
+    });
+    measure("lazy", &source, |source| {
+        Lexer::new(source).into_tokens().count()
+    });
+    measure("lossless", &source, |source| {
+        Lexer::new(source).tokenize_lossless().0.len()
+    });
+}
@@ -0,0 +1,56 @@
+use crate::{
+    Lexer,
+    errors::LexerError,
+    iterator::SpannedToken,
+    source::FileId,
+    tokens::Token,
+    words::{KeywordSet, PRAGMA, pragma},
+};
+
+/// Mətndəki açar sözləri `to` dəstinə çevirir; qalan mətn olduğu kimi qalır.
+/// `default` faylda direktiv olmadıqda işlənən dəstdir. Direktiv varsa yenilənir,
+/// yoxdursa və `to` `default`-dan fərqlidirsə faylın əvvəlinə əlavə olunur.
+/// `to` dəstində açar söz olan ad varsa fayl çevrilmir, çünki nəticə oxunmaz.
+pub fn convert_keywords(
+    source: &str,
+    file: FileId,
+    default: KeywordSet,
+    to: KeywordSet,
+) -> Result<String, LexerError> {
+    let mut lexer = Lexer::with_keywords(source, file, default);
+    let tokens = lexer.tokenize()?;
+    let from = lexer.keywords();
+
+    let mut out = String::with_capacity(source.len());
+    let mut last = 0;
+    for SpannedToken { token, span } in tokens {
+        if let Token::Identifier(name) = &token
+            && !matches!(to.tokenize(name), Token::Identifier(_))
+        {
+            return Err(LexerError::KeywordCollision(
+                span,
+                name.to_string(),
+                to.name(),
+            ));
+        }
+        let (Some(old), Some(new)) = (from.spelling(&token), to.spelling(&token)) else {
+            continue;
+        };
+        let (start, end) = (span.start as usize, span.end as usize);
+        // `&&` kimi operatorlar da açar söz tokeni verir, onlar dəyişmir.
+        if source.get(start..end) != Some(old) {
+            continue;
+        }
+        out.push_str(&source[last..start]);
+        out.push_str(new);
+        last = end;
+    }
+    out.push_str(&source[last..]);
+
+    match pragma(&out) {
+        Some((_, range)) => out.replace_range(range, to.name()),
+        None if to != default => out.insert_str(0, &format!("# {PRAGMA} {}\n", to.name())),
+        None => {}
+    }
+    Ok(out)
+}
@@ -1,63 +1,133 @@
 use core::fmt;
-use std::{
-    fmt::Display,
-    num::{ParseFloatError, ParseIntError},
-};
+use std::fmt::Display;
+
+use locale::t;
 
 use crate::iterator::SourceSpan;
 
 #[derive(Debug)]
 pub enum LexerError {
     UnClosedString(SourceSpan, String),
-    VariableCannotBeNumber,
     NumberAndAlpha,
-    DoubleDotNumber,
-    FloatUnKnow(ParseFloatError),
-    NumberUnKnow(ParseIntError),
     CannotStartZeroNumber(SourceSpan, String),
+    InvalidNumber(SourceSpan, String),
+    NumberOutOfRange(SourceSpan, String, String),
     InCorrectSpaceSize(SourceSpan),
+    TabIndentation(SourceSpan),
+    MixedIndentation(SourceSpan),
     UnexpectedToken(SourceSpan, char),
     UnknownOperator(SourceSpan, String),
+    InvalidEscape(SourceSpan, String),
+    EmptyCharLiteral(SourceSpan),
+    MultiCharLiteral(SourceSpan, String),
+    /// `# keywords: ...` direktivində tanınmayan dəst adı.
+    UnknownKeywordSet(SourceSpan, String),
+    InvisibleCharacter(SourceSpan, char),
+    /// Latın, yunan və kiril hərflərini qarışdıran ad.
+    MixedScriptIdentifier(SourceSpan, String),
+    /// Tapılan simvol və ona bənzəyən ASCII simvolu.
+    ConfusableCharacter(SourceSpan, char, char),
+    /// Çevrilən faylda hədəf dəstin açar sözü ilə eyni yazılan ad və dəstin adı.
+    KeywordCollision(SourceSpan, String, &'static str),
+    /// Girinti 4-ün misli olsa da, yeni blok üçün gözləniləndən dərindir: gözlənilən və tapılan boşluq sayı.
+    IndentedTooFar(SourceSpan, usize, usize),
 }
 
-impl Display for LexerError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl LexerError {
+    /// Xətanın mənbədəki yeri. Köhnə, yersiz xətalar üçün `None`.
+    pub fn span(&self) -> Option<&SourceSpan> {
         match self {
-            LexerError::UnexpectedToken(span, token) => {
-                write!(f, "{}, Uyğunluq olmayan token \"{}\"", span, token)
-            }
-            LexerError::UnClosedString(span, str) => {
-                write!(f, "{}, String düzgün bağlanmayıb \"{}\"", span, str)
-            }
-            LexerError::VariableCannotBeNumber => {
-                write!(f, "Başlangıc ədədlə adlandırıla bilməz!")
-            }
-            LexerError::NumberAndAlpha => {
-                write!(f, "Ədəddən sonra hərf gələ bilməz!")
-            }
-            LexerError::DoubleDotNumber => {
-                write!(f, "İki dəfə nöqtə qoya bilməzsiniz")
-            }
-            LexerError::NumberUnKnow(s) => {
-                write!(f, "Ədəd tokenizerdə bilinməyən problem oldu problem: {s}")
-            }
-            LexerError::FloatUnKnow(s) => {
-                write!(f, "Kəsr tokenizerdə bilinməyən problem oldu problem: {s}")
-            }
-            LexerError::CannotStartZeroNumber(span, str) => {
-                write!(
-                    f,
-                    "{} Yanlış ədəd formatı \"{}\" . Onluq ədədlərin başlangıcı sıfırla başlaya bilməz.",
-                    span, str
-                )
-            }
-            LexerError::UnknownOperator(span, str) => {
-                write!(f, "{} Uyğunluq olmayan operator \"{}\"", span, str)
-            }
+            LexerError::UnClosedString(span, ..)
+            | LexerError::CannotStartZeroNumber(span, ..)
+            | LexerError::InvalidNumber(span, ..)
+            | LexerError::NumberOutOfRange(span, ..)
+            | LexerError::InCorrectSpaceSize(span)
+            | LexerError::TabIndentation(span)
+            | LexerError::MixedIndentation(span)
+            | LexerError::UnexpectedToken(span, ..)
+            | LexerError::UnknownOperator(span, ..)
+            | LexerError::InvalidEscape(span, ..)
+            | LexerError::EmptyCharLiteral(span)
+            | LexerError::MultiCharLiteral(span, ..)
+            | LexerError::UnknownKeywordSet(span, ..)
+            | LexerError::InvisibleCharacter(span, ..)
+            | LexerError::MixedScriptIdentifier(span, ..)
+            | LexerError::ConfusableCharacter(span, ..)
+            | LexerError::KeywordCollision(span, ..)
+            | LexerError::IndentedTooFar(span, ..) => Some(span),
+            LexerError::NumberAndAlpha => None,
+        }
+    }
 
-            LexerError::InCorrectSpaceSize(span) => {
-                write!(f, "{} Uyğunsuz boşluq var.", span)
+    /// Dəyişməz xəta kodu, məsələn `AZ0001`. `azcli explain` bu kodla izah verir.
+    pub fn code(&self) -> &'static str {
+        match self {
+            LexerError::UnClosedString(..) => "AZ0001",
+            LexerError::NumberAndAlpha => "AZ0003",
+            LexerError::CannotStartZeroNumber(..) => "AZ0007",
+            LexerError::InvalidNumber(..) => "AZ0008",
+            LexerError::NumberOutOfRange(..) => "AZ0009",
+            LexerError::InCorrectSpaceSize(..) => "AZ0010",
+            LexerError::TabIndentation(..) => "AZ0011",
+            LexerError::MixedIndentation(..) => "AZ0012",
+            LexerError::UnexpectedToken(..) => "AZ0013",
+            LexerError::UnknownOperator(..) => "AZ0014",
+            LexerError::InvalidEscape(..) => "AZ0015",
+            LexerError::EmptyCharLiteral(..) => "AZ0016",
+            LexerError::MultiCharLiteral(..) => "AZ0017",
+            LexerError::UnknownKeywordSet(..) => "AZ0018",
+            LexerError::InvisibleCharacter(..) => "AZ0019",
+            LexerError::MixedScriptIdentifier(..) => "AZ0020",
+            LexerError::ConfusableCharacter(..) => "AZ0021",
+            LexerError::KeywordCollision(..) => "AZ0022",
+            LexerError::IndentedTooFar(..) => "AZ0023",
+        }
+    }
+
+    /// Yer göstərilmədən xəta mətni, cari dildə.
+    pub fn message(&self) -> String {
+        let code = self.code();
+        match self {
+            LexerError::UnexpectedToken(_, token) => t!(code, token = token),
+            LexerError::UnClosedString(_, text)
+            | LexerError::CannotStartZeroNumber(_, text)
+            | LexerError::InvalidNumber(_, text)
+            | LexerError::UnknownOperator(_, text)
+            | LexerError::InvalidEscape(_, text)
+            | LexerError::MultiCharLiteral(_, text)
+            | LexerError::MixedScriptIdentifier(_, text) => t!(code, text = text),
+            LexerError::InvisibleCharacter(_, found) => t!(code, point = code_point(*found)),
+            LexerError::ConfusableCharacter(_, found, expected) => t!(
+                code,
+                found = found,
+                point = code_point(*found),
+                expected = expected
+            ),
+            LexerError::UnknownKeywordSet(_, name) => t!(code, name = name),
+            LexerError::KeywordCollision(_, name, set) => t!(code, name = name, set = set),
+            LexerError::IndentedTooFar(_, expected, found) => {
+                t!(code, expected = expected, found = found)
             }
+            LexerError::NumberOutOfRange(_, text, typ) => t!(code, text = text, typ = typ),
+            LexerError::NumberAndAlpha
+            | LexerError::InCorrectSpaceSize(_)
+            | LexerError::TabIndentation(_)
+            | LexerError::MixedIndentation(_)
+            | LexerError::EmptyCharLiteral(_) => t!(code),
+        }
+    }
+}
+
+/// Simvolun `U+200B` şəklində kodu.
+fn code_point(ch: char) -> String {
+    format!("U+{:04X}", ch as u32)
+}
+
+impl Display for LexerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.span() {
+            Some(span) => write!(f, "{span} {}", self.message()),
+            None => write!(f, "{}", self.message()),
         }
     }
 }
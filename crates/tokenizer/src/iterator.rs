@@ -2,17 +2,55 @@ use std::collections::VecDeque;
 use std::fmt;
 use std::fmt::Display;
 
+use crate::Lexer;
+use crate::errors::LexerError;
+use crate::source::{FileId, Location, get_file};
 use crate::tokens::Token;
+use crate::words::KeywordSet;
 
-#[derive(Debug, Clone, PartialEq)]
+/// Fayl daxilində `start..end` bayt aralığı.
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct SourceSpan {
+    pub file: FileId,
     pub start: u32,
     pub end: u32,
-    pub line: u32,
+}
+impl SourceSpan {
+    pub fn new(file: FileId, start: u32, end: u32) -> Self {
+        Self { file, start, end }
+    }
+    pub fn len(&self) -> u32 {
+        self.end.saturating_sub(self.start)
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// İki span-ı əhatə edən span. Fərqli fayllarda olduqda `self` qaytarılır.
+    pub fn to(&self, other: &SourceSpan) -> SourceSpan {
+        if self.file != other.file {
+            return self.clone();
+        }
+        SourceSpan::new(
+            self.file,
+            self.start.min(other.start),
+            self.end.max(other.end),
+        )
+    }
+    /// `other` tamamilə bu span-ın daxilindədirsə `true`.
+    pub fn contains(&self, other: &SourceSpan) -> bool {
+        self.file == other.file && self.start <= other.start && other.end <= self.end
+    }
+    /// Başlanğıcın sətir və sütunu. Fayl mənbə xəritəsində yoxdursa `None`.
+    pub fn location(&self) -> Option<Location> {
+        get_file(self.file).map(|file| file.location(self.start))
+    }
 }
 impl Display for SourceSpan {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Sətir {}, sütun {}", self.line, self.start)
+        match self.location() {
+            Some(location) => write!(f, "{location}"),
+            None => write!(f, "Bayt {}..{}", self.start, self.end),
+        }
     }
 }
 #[derive(Debug, Clone, PartialEq)]
@@ -20,29 +58,91 @@ pub struct SpannedToken {
     pub token: Token,
     pub span: SourceSpan,
 }
-#[derive(Default, Debug, Clone)]
-pub struct Tokens {
+/// Parserin [`Tokens::peek_nth`] ilə irəli baxdığı token sayı. Tənbəl axında
+/// bufferdə həmişə ən azı bu qədər token olur.
+pub const LOOKAHEAD: usize = 3;
+
+/// Parserin token axını. [`Lexer::into_tokens`] ilə yarandıqda tokenlər lazım
+/// olduqca oxunur, [`Tokens::push`] ilə isə əvvəlcədən doldurulur.
+#[derive(Default, Debug)]
+pub struct Tokens<'a> {
     source: VecDeque<SpannedToken>,
+    /// Sonuncu götürülmüş mənalı tokenin yeri. Sətir sonu və girinti tokenləri nəzərə alınmır.
+    last_span: SourceSpan,
+    /// Hələ oxunmamış tokenlər; fayl bitdikdə `None`.
+    lexer: Option<Lexer<'a>>,
+    errors: Vec<LexerError>,
+    /// Faylın açar söz dəsti; lexer bitdikdən sonra da məlum qalır.
+    keywords: KeywordSet,
 }
 
-impl Iterator for Tokens {
+impl Iterator for Tokens<'_> {
     type Item = SpannedToken;
     fn next(&mut self) -> Option<Self::Item> {
-        self.source.pop_front()
+        let token = self.source.pop_front()?;
+        self.fill();
+        if !matches!(token.token, Token::Newline | Token::Indent | Token::Dedent) {
+            self.last_span = token.span.clone();
+        }
+        Some(token)
     }
 }
 
-impl Tokens {
+impl<'a> Tokens<'a> {
+    pub(crate) fn lazy(lexer: Lexer<'a>) -> Self {
+        let mut tokens = Self {
+            keywords: lexer.keywords(),
+            lexer: Some(lexer),
+            ..Self::default()
+        };
+        tokens.fill();
+        tokens
+    }
+    fn fill(&mut self) {
+        while self.source.len() < LOOKAHEAD {
+            let Some(lexer) = &mut self.lexer else {
+                return;
+            };
+            match lexer.next_recovering(&mut self.errors) {
+                Some(token) => self.source.push_back(token),
+                None => self.lexer = None,
+            }
+        }
+    }
+    /// Faylın qalanını oxuyub bütün leksik xətaları qaytarır.
+    pub fn finish(mut self) -> Vec<LexerError> {
+        if let Some(lexer) = &mut self.lexer {
+            while lexer.next_recovering(&mut self.errors).is_some() {}
+        }
+        self.errors
+    }
+    /// Tokenlərin oxunduğu açar söz dəsti.
+    pub fn keywords(&self) -> KeywordSet {
+        self.keywords
+    }
     pub fn push(&mut self, token: Token, span: SourceSpan) {
         self.source.push_back(SpannedToken { token, span });
     }
     pub fn peek(&self) -> Option<&SpannedToken> {
         self.source.front()
     }
+    /// `index` [`LOOKAHEAD`]-dan kiçik olmalıdır, yoxsa tənbəl axında token hələ oxunmamış ola bilər.
     pub fn peek_nth(&self, index: usize) -> Option<&SpannedToken> {
+        debug_assert!(index < LOOKAHEAD || self.lexer.is_none());
         self.source.get(index)
     }
     pub fn push_front(&mut self, token: SpannedToken) {
         self.source.push_front(token);
     }
+    /// Növbəti tokenin yeri; tokenlər bitibsə sonuncunun sonundakı boş span.
+    pub fn peek_span(&self) -> SourceSpan {
+        match self.source.front() {
+            Some(token) => token.span.clone(),
+            None => SourceSpan::new(self.last_span.file, self.last_span.end, self.last_span.end),
+        }
+    }
+    /// `start`-dan sonuncu götürülmüş tokenin sonuna qədər olan span.
+    pub fn span_from(&self, start: &SourceSpan) -> SourceSpan {
+        start.to(&self.last_span)
+    }
 }
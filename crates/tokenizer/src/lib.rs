@@ -1,291 +1,713 @@
-use std::{iter::Peekable, str::Chars};
+use std::borrow::Cow;
 
 use crate::{
     errors::LexerError,
-    iterator::{SourceSpan, Tokens},
+    iterator::{SourceSpan, SpannedToken, Tokens},
+    literal::Literal,
+    number::parse_number,
+    source::FileId,
     tokens::Token,
-    words::tokenize_word,
+    trivia::{LosslessToken, split_trivia},
+    unicode::{
+        confusable, is_bidi_control, is_ident_continue, is_ident_start, is_invisible,
+        is_mixed_script, normalize,
+    },
+    words::{KeywordSet, pragma},
 };
+pub mod convert;
 pub mod errors;
 pub mod iterator;
+mod literal;
+mod number;
+pub mod source;
 pub mod token_display;
 pub mod tokens;
+pub mod trivia;
+mod unicode;
 pub mod words;
 #[derive(Debug)]
 pub enum LexerMode {
-    Normal,
+    /// `${...}` içi: adi tokenlər, `:` isə format spesifikasiyasını başladır.
+    Interpolation,
     Template,
 }
 #[derive(Debug)]
 pub struct Lexer<'a> {
-    chars: Peekable<Chars<'a>>,
+    input: &'a str,
     is_line_start: bool,
-    indent_level: usize,
+    /// Açıq blokların girinti ölçüləri, həmişə `0` ilə başlayır.
+    indent_stack: Vec<usize>,
+    pending_dedents: usize,
     space: usize,
-    line: u32,
+    has_tab: bool,
+    file: FileId,
+    /// İndiyə qədər oxunmuş baytların sayı.
+    offset: u32,
+    /// Cari tokenin başladığı bayt.
     start: u32,
-    end: u32,
+    /// Cari sətrin girintisinin başladığı bayt.
+    line_start: u32,
     mode_stack: Vec<LexerMode>,
+    keywords: KeywordSet,
+    /// Faylın əvvəlindəki direktivin xətası; ilk token istənəndə qaytarılır.
+    pragma_error: Option<LexerError>,
+    /// Cari yazı və ya şərhdə tapılan ilk istiqamət simvolu; yazı bitəndə qaytarılır.
+    hidden: Option<LexerError>,
 }
 impl<'a> Lexer<'a> {
+    /// Adsız mətn mənbə xəritəsinə əlavə edilmir, çünki xəritə proses boyu yaşayır.
+    /// Onun span-ları [`FileId`] `0`-a işarə edir və yeri baytla göstərilir.
     pub fn new(input: &'a str) -> Self {
-        Self {
-            chars: input.chars().peekable(),
+        Self::with_file(input, FileId::default())
+    }
+    pub fn with_file(input: &'a str, file: FileId) -> Self {
+        Self::with_keywords(input, file, KeywordSet::default())
+    }
+    /// `keywords` faylda `# keywords: ...` direktivi olmadıqda işlənən dəstdir.
+    pub fn with_keywords(input: &'a str, file: FileId, keywords: KeywordSet) -> Self {
+        let (keywords, pragma_error) = match pragma(input) {
+            None => (keywords, None),
+            Some((name, range)) => match KeywordSet::from_name(name) {
+                Some(keywords) => (keywords, None),
+                None => {
+                    let span = SourceSpan::new(file, range.start as u32, range.end as u32);
+                    let error = LexerError::UnknownKeywordSet(span, name.to_string());
+                    (keywords, Some(error))
+                }
+            },
+        };
+        let mut lexer = Self {
+            input,
             is_line_start: false,
-            indent_level: 0,
+            indent_stack: vec![0],
+            pending_dedents: 0,
             space: 0,
-            line: 1,
-            start: 1,
-            end: 1,
+            has_tab: false,
+            file,
+            offset: 0,
+            start: 0,
+            line_start: 0,
             mode_stack: Vec::new(),
+            keywords,
+            pragma_error,
+            hidden: None,
+        };
+        // Faylın əvvəlindəki BOM mətnə aid deyil.
+        if lexer.peek() == Some('\u{FEFF}') {
+            lexer.bump();
         }
+        lexer
+    }
+    /// Bu faylda işlənən açar söz dəsti.
+    pub fn keywords(&self) -> KeywordSet {
+        self.keywords
     }
     fn skip_whitespace(&mut self) {
-        while let Some(ch) = self.chars.peek() {
-            if *ch == ' ' && self.is_line_start {
-                self.space += 1;
-                self.start += 1;
-                self.end += 1;
-                self.chars.next();
-            } else if *ch == ' ' && !self.is_line_start {
-                self.start += 1;
-                self.end += 1;
-                self.chars.next();
-            } else {
-                break;
+        while let Some(ch) = self.peek() {
+            match ch {
+                ' ' if self.is_line_start => self.space += 1,
+                '\t' if self.is_line_start => self.has_tab = true,
+                ' ' | '\t' => {}
+                _ => break,
             }
+            self.bump();
         }
     }
-    pub fn tokenize(&mut self) -> Result<Tokens, LexerError> {
+    pub fn tokenize(&mut self) -> Result<Tokens<'a>, LexerError> {
         let mut tokens = Tokens::default();
 
         loop {
             let token = self.next_token()?;
             match token {
                 Token::Eof => break,
-                _ => tokens.push(
-                    token,
-                    SourceSpan {
-                        start: self.start,
-                        end: self.end,
-                        line: self.line,
-                    },
-                ),
+                _ => tokens.push(token, self.span()),
             }
-            self.start = self.end;
         }
 
         Ok(tokens)
     }
-    fn read_string(&mut self) -> Result<Token, LexerError> {
-        self.chars.next();
+    /// İlk xətada dayanmadan bütün mətni tokenlərə ayırır. Tanınmayan simvol atılır,
+    /// girinti xətasında sətir olduğu kimi davam edir, digər xətalarda isə sətrin qalanı ötürülür.
+    pub fn tokenize_recovering(&mut self) -> (Tokens<'a>, Vec<LexerError>) {
+        let mut tokens = Tokens::default();
+        let mut errors = Vec::new();
+        while let Some(token) = self.next_recovering(&mut errors) {
+            tokens.push(token.token, token.span);
+        }
+        (tokens, errors)
+    }
+    /// Tokenləri parser istədikcə oxuyan axın. Xətalar [`Lexer::tokenize_recovering`]
+    /// kimi bərpa olunur və [`Tokens::finish`] ilə götürülür.
+    pub fn into_tokens(self) -> Tokens<'a> {
+        Tokens::lazy(self)
+    }
+    /// Növbəti token; xəta `errors`-a yazılır və oxuma davam edir. Fayl bitdikdə `None`.
+    pub(crate) fn next_recovering(&mut self, errors: &mut Vec<LexerError>) -> Option<SpannedToken> {
+        loop {
+            match self.next_token() {
+                Ok(Token::Eof) => return None,
+                Ok(token) => {
+                    return Some(SpannedToken {
+                        token,
+                        span: self.span(),
+                    });
+                }
+                Err(error) => {
+                    if !matches!(
+                        error,
+                        LexerError::UnexpectedToken(..)
+                            | LexerError::InCorrectSpaceSize(_)
+                            | LexerError::IndentedTooFar(..)
+                            | LexerError::TabIndentation(_)
+                            | LexerError::MixedIndentation(_)
+                            | LexerError::UnknownKeywordSet(..)
+                            | LexerError::InvisibleCharacter(..)
+                            | LexerError::MixedScriptIdentifier(..)
+                            | LexerError::ConfusableCharacter(..)
+                    ) {
+                        self.skip_line();
+                    }
+                    errors.push(error);
+                }
+            }
+        }
+    }
+    /// [`Lexer::tokenize_recovering`] kimidir, lakin boşluqlar, şərhlər və ötürülmüş mətn
+    /// trivia kimi tokenlərə bağlanır. Bütün tokenlərin [`LosslessToken`] mətnlərinin
+    /// birləşməsi girişin özüdür; trivia atıldıqda isə adi token axını qalır.
+    pub fn tokenize_lossless(&mut self) -> (Vec<LosslessToken<'a>>, Vec<LexerError>) {
+        let input = self.input;
+        let (tokens, errors) = self.tokenize_recovering();
 
-        let mut str = String::new();
-        let mut is_closed = false;
+        let mut lossless = Vec::new();
+        let mut last = 0;
+        for token in tokens {
+            let start = (token.span.start as usize).max(last);
+            let end = (token.span.end as usize).max(start);
+            lossless.push(LosslessToken {
+                leading: split_trivia(&input[last..start], self.file, last),
+                token: token.token,
+                text: &input[start..end],
+                span: token.span,
+            });
+            last = end;
+        }
+        lossless.push(LosslessToken {
+            leading: split_trivia(&input[last..], self.file, last),
+            token: Token::Eof,
+            text: "",
+            span: SourceSpan::new(self.file, input.len() as u32, input.len() as u32),
+        });
 
-        for ch in &mut self.chars {
-            match ch {
-                '"' => {
-                    is_closed = true;
-                    break;
+        (lossless, errors)
+    }
+    /// Sətrin sonuna qədər olan simvolları atır, `\n` isə yerində qalır.
+    fn skip_line(&mut self) {
+        // Bağlanmamış şablon yazısı sətrin sonunda bitmiş sayılır.
+        self.mode_stack.clear();
+        while self.peek().is_some_and(|ch| ch != '\n') {
+            self.bump();
+        }
+    }
+    fn span(&self) -> SourceSpan {
+        SourceSpan::new(self.file, self.start, self.offset)
+    }
+    fn span_from(&self, start: u32) -> SourceSpan {
+        SourceSpan::new(self.file, start, self.offset)
+    }
+    /// Cari mövqedən başlayan mətnin `prefix` ilə başlayıb-başlamadığını yoxlayır.
+    fn starts_with(&self, prefix: &str) -> bool {
+        self.rest().starts_with(prefix)
+    }
+    /// Cari mövqedən sonrakı mətn.
+    fn rest(&self) -> &'a str {
+        &self.input[self.offset as usize..]
+    }
+    /// Növbəti simvol. ASCII baytı birbaşa simvoldur, qalanları UTF-8 kimi oxunur.
+    fn peek(&self) -> Option<char> {
+        match *self.input.as_bytes().get(self.offset as usize)? {
+            byte if byte.is_ascii() => Some(byte as char),
+            _ => self.rest().chars().next(),
+        }
+    }
+    fn bump(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.offset += ch.len_utf8() as u32;
+        Some(ch)
+    }
+    /// Yazı və şərh daxilindəki simvolu götürür. İstiqamət simvolu kodu olduğundan
+    /// fərqli göstərə bildiyi üçün qeyd olunur və [`Lexer::check_hidden`] onu qaytarır.
+    fn bump_text(&mut self) -> Option<char> {
+        let start = self.offset;
+        let ch = self.bump()?;
+        if is_bidi_control(ch) && self.hidden.is_none() {
+            self.hidden = Some(LexerError::InvisibleCharacter(self.span_from(start), ch));
+        }
+        Some(ch)
+    }
+    fn check_hidden(&mut self) -> Result<(), LexerError> {
+        match self.hidden.take() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+    fn finish_text(&mut self, token: Token) -> Result<Token, LexerError> {
+        self.check_hidden().map(|()| token)
+    }
+    /// `raw` olduqda escape ardıcıllıqları emal olunmur: `r"C:\yol"`.
+    fn read_string(&mut self, raw: bool) -> Result<Token, LexerError> {
+        if self.starts_with("\"\"\"") {
+            return self.read_text_block(raw);
+        }
+        self.bump();
+
+        let mut text = Literal::new(self.offset);
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    let text = text.into_atom(self.input, self.offset);
+                    self.bump();
+                    return self.finish_text(Token::StringLiteral(text));
                 }
-                '\n' => {
-                    return Err(LexerError::UnClosedString(
-                        SourceSpan {
-                            start: self.start,
-                            end: self.end,
-                            line: self.line,
-                        },
-                        str,
-                    ));
+                None | Some('\n') => {
+                    let text = text.into_string(self.input, self.offset);
+                    return Err(LexerError::UnClosedString(self.span(), text));
                 }
-                other => {
-                    str.push(other);
+                Some('\\') if !raw => self.escape_into(&mut text)?,
+                Some(_) => {
+                    self.bump_text();
                 }
             }
-
-            self.end += 1;
-        }
-        if !is_closed {
-            return Err(LexerError::UnClosedString(
-                SourceSpan {
-                    start: self.start,
-                    end: self.end,
-                    line: self.line,
-                },
-                str,
-            ));
         }
-
-        Ok(Token::StringLiteral(str))
     }
-    fn read_word(&mut self) -> Result<Token, LexerError> {
+    /// `'ə'` kimi tək simvol. Escape ardıcıllıqları yazılardakı kimidir.
+    fn read_char(&mut self) -> Result<Token, LexerError> {
+        self.bump();
+
         let mut str = String::new();
-        while let Some(ch) = self.chars.peek() {
-            if ch.is_alphanumeric() || *ch == '_' {
-                self.end += 1;
-                str.push(*ch);
-                self.chars.next();
-            } else {
-                break;
+        loop {
+            match self.peek() {
+                Some('\'') => {
+                    self.bump();
+                    break;
+                }
+                None | Some('\n') => return Err(LexerError::UnClosedString(self.span(), str)),
+                Some('\\') => str.push(self.read_escape()?),
+                Some(other) => {
+                    self.bump_text();
+                    str.push(other);
+                }
             }
         }
-        self.end += 1;
-        Ok(tokenize_word(str.as_str()))
+
+        let mut chars = str.chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => self.finish_text(Token::CharLiteral(ch)),
+            (None, _) => Err(LexerError::EmptyCharLiteral(self.span())),
+            _ => Err(LexerError::MultiCharLiteral(self.span(), str)),
+        }
     }
+    /// `"""` ilə açılıb bağlanan çoxsətirli yazı. Açılışdan dərhal sonra gələn
+    /// sətir sonu mətnə daxil edilmir.
+    fn read_text_block(&mut self, raw: bool) -> Result<Token, LexerError> {
+        for _ in 0..3 {
+            self.bump();
+        }
+        if self.peek() == Some('\n') {
+            self.bump();
+        }
 
-    fn read_number(&mut self) -> Result<Token, LexerError> {
-        let mut buf = String::new();
-        let mut has_dot = false;
-        while let Some(ch) = self.chars.peek() {
-            match ch {
-                '0'..='9' => {
-                    buf.push(*ch);
-                    self.chars.next();
+        let mut text = Literal::new(self.offset);
+        loop {
+            if self.starts_with("\"\"\"") {
+                let text = text.into_atom(self.input, self.offset);
+                for _ in 0..3 {
+                    self.bump();
                 }
-
-                'A'..='Z' | 'a'..='z' => {
-                    return Err(LexerError::NumberAndAlpha);
+                return self.finish_text(Token::StringLiteral(text));
+            }
+            match self.peek() {
+                None => {
+                    let text = text.into_string(self.input, self.offset);
+                    return Err(LexerError::UnClosedString(self.span(), text));
                 }
-
-                '.' if !has_dot => {
-                    has_dot = true;
-                    buf.push('.');
-                    self.chars.next();
+                Some('\\') if !raw => self.escape_into(&mut text)?,
+                Some(_) => {
+                    self.bump_text();
                 }
+            }
+        }
+    }
+    fn escape_into(&mut self, text: &mut Literal) -> Result<(), LexerError> {
+        let at = self.offset;
+        let ch = self.read_escape()?;
+        text.push(self.input, at, ch, self.offset);
+        Ok(())
+    }
+    fn read_escape(&mut self) -> Result<char, LexerError> {
+        let start = self.offset;
+        self.bump();
+        let invalid =
+            |lexer: &Self, text: String| LexerError::InvalidEscape(lexer.span_from(start), text);
+        let ch = match self.peek() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('r') => '\r',
+            Some('0') => '\0',
+            Some('\\') => '\\',
+            Some('"') => '"',
+            Some('\'') => '\'',
+            Some('u') => return self.read_unicode_escape(start),
+            Some(other) if other != '\n' => {
+                self.bump();
+                return Err(invalid(self, format!("\\{other}")));
+            }
+            _ => return Err(invalid(self, "\\".to_string())),
+        };
+        self.bump();
+        Ok(ch)
+    }
+    /// `\u{XXXX}`: fiqurlu mötərizə içində 1-6 onaltılıq rəqəm.
+    fn read_unicode_escape(&mut self, start: u32) -> Result<char, LexerError> {
+        let mut text = String::from("\\u");
+        self.bump();
 
-                _ => break,
+        let mut digits = String::new();
+        let mut closed = false;
+        if self.peek() == Some('{') {
+            text.push('{');
+            self.bump();
+            while let Some(ch) = self.peek() {
+                if ch == '}' {
+                    text.push('}');
+                    self.bump();
+                    closed = true;
+                    break;
+                }
+                if !ch.is_ascii_hexdigit() {
+                    break;
+                }
+                digits.push(ch);
+                text.push(ch);
+                self.bump();
             }
         }
 
-        self.end = self.start + buf.len() as u32;
+        let ch = (closed && (1..=6).contains(&digits.len()))
+            .then(|| u32::from_str_radix(&digits, 16).ok())
+            .flatten()
+            .and_then(char::from_u32);
+        ch.ok_or(LexerError::InvalidEscape(self.span_from(start), text))
+    }
+    /// Söz mənbədən kopyalanmadan götürülür; yalnız ASCII olmayan söz normallaşdırılır.
+    fn read_word(&mut self) -> Result<Token, LexerError> {
+        let start = self.offset as usize;
+        // ZWJ və ZWNJ `is_ident_continue`-dan keçir, amma adı görünməz şəkildə dəyişir.
+        // Söz sona qədər oxunur ki, qalanı ayrıca token kimi görünməsin.
+        let mut invisible = None;
+        while let Some(ch) = self.peek().filter(|ch| is_ident_continue(*ch)) {
+            let position = self.offset;
+            self.bump();
+            if invisible.is_none() && is_invisible(ch) {
+                invisible = Some(LexerError::InvisibleCharacter(self.span_from(position), ch));
+            }
+        }
+        if let Some(error) = invisible {
+            return Err(error);
+        }
+        let word = &self.input[start..self.offset as usize];
+        if word == "r" && self.peek() == Some('"') {
+            return self.read_string(true);
+        }
+        let mut word = Cow::Borrowed(word);
+        if !word.is_ascii() {
+            let normalized = normalize(word.into_owned());
+            if is_mixed_script(&normalized) {
+                return Err(LexerError::MixedScriptIdentifier(self.span(), normalized));
+            }
+            word = Cow::Owned(normalized);
+        }
+        // `əks halda` kimi çoxsözlü açar sözlər.
+        if self.peek() == Some(' ') {
+            for rest in self.keywords.continuations(&word) {
+                if self.starts_with_word(rest) {
+                    for _ in rest.chars() {
+                        self.bump();
+                    }
+                    word.to_mut().push_str(rest);
+                    break;
+                }
+            }
+        }
+        Ok(self.keywords.tokenize(&word))
+    }
+    /// Cari mövqedən `text` gəlir və ondan sonra söz davam etmir.
+    fn starts_with_word(&self, text: &str) -> bool {
+        self.rest()
+            .strip_prefix(text)
+            .is_some_and(|after| !after.starts_with(is_ident_continue))
+    }
 
-        if has_dot {
-            Ok(Token::Float(
-                buf.parse::<f64>().map_err(LexerError::FloatUnKnow)?,
-            ))
+    /// Heç bir qaydaya uyğun gəlməyən simvolu, məsələn `$` və ya `;`, ötürüb xəta qaytarır.
+    fn unexpected_char(&mut self) -> Result<Token, LexerError> {
+        let ch = self.bump().unwrap_or_default();
+        let span = self.span();
+        Err(if is_invisible(ch) {
+            LexerError::InvisibleCharacter(span, ch)
+        } else if let Some(ascii) = confusable(ch) {
+            LexerError::ConfusableCharacter(span, ch, ascii)
         } else {
-            if buf.starts_with('0') && buf.len() > 1 {
-                return Err(LexerError::CannotStartZeroNumber(
-                    SourceSpan {
-                        start: self.start,
-                        end: self.end,
-                        line: self.line,
-                    },
-                    buf,
-                ));
+            LexerError::UnexpectedToken(span, ch)
+        })
+    }
+
+    /// Ədəd literalını toplayır; təhlili və aralıq yoxlaması `number` modulundadır.
+    fn read_number(&mut self) -> Result<Token, LexerError> {
+        let start = self.offset as usize;
+        let mut has_dot = false;
+        while let Some(ch) = self.peek() {
+            let buf = &self.input[start..self.offset as usize];
+            let decimal = !buf.starts_with("0x") && !buf.starts_with("0X");
+            match ch {
+                'A'..='Z' | 'a'..='z' | '0'..='9' | '_' => {}
+                '.' if !has_dot && decimal && self.digit_after_next() => has_dot = true,
+                '+' | '-' if decimal && buf.ends_with(['e', 'E']) => {}
+                _ => break,
             }
-            Ok(Token::Number(
-                buf.parse::<i64>().map_err(LexerError::NumberUnKnow)?,
-            ))
+            self.bump();
         }
+
+        parse_number(&self.input[start..self.offset as usize], self.span())
+    }
+
+    /// Növbəti simvoldan sonra rəqəm gəlirsə `true` qaytarır (`1.5` və `1.max` fərqi).
+    fn digit_after_next(&self) -> bool {
+        let mut ahead = self.rest().chars();
+        ahead.next();
+        ahead.next().is_some_and(|ch| ch.is_ascii_digit())
     }
     fn consume(&mut self, token: Token) -> Result<Token, LexerError> {
-        self.chars.next();
-        self.end += 1;
+        self.bump();
         Ok(token)
     }
 
     fn handle_indentation(&mut self) -> Result<Option<Token>, LexerError> {
-        if let Some('\n') = self.chars.peek() {
+        if self.pending_dedents > 0 {
+            self.pending_dedents -= 1;
+            return Ok(Some(Token::Dedent));
+        }
+        if let Some('\n') = self.peek() {
             self.is_line_start = true;
             self.space = 0;
-            self.chars.next();
-            self.line += 1;
-            self.start = 0;
-            self.end = 0;
+            self.has_tab = false;
+            self.bump();
+            self.line_start = self.offset;
             return Ok(Some(Token::Newline));
         };
-        if self.space == self.indent_level * 4 {
-            Ok(None)
-        } else if self.space == (self.indent_level + 1) * 4 {
-            self.indent_level += 1;
-            Ok(Some(Token::Indent))
-        } else if self.space.is_multiple_of(4) && self.space < self.indent_level * 4 {
-            self.indent_level -= 1;
-            Ok(Some(Token::Dedent))
-        } else {
-            Err(LexerError::InCorrectSpaceSize(SourceSpan {
-                start: self.start,
-                end: self.end,
-                line: self.line,
-            }))
+        if self.peek().is_none() {
+            // Fayl bitdikdə açıq qalan bütün bloklar bağlanır.
+            if self.indent_stack.len() > 1 {
+                self.indent_stack.pop();
+                return Ok(Some(Token::Dedent));
+            }
+            return Ok(None);
+        }
+        // Boş və yalnız şərhdən ibarət sətirlər girintiyə təsir etmir.
+        if !self.is_line_start || self.is_comment_line() {
+            return Ok(None);
+        }
+        self.is_line_start = false;
+
+        if self.has_tab {
+            let span = self.span_from(self.line_start);
+            return Err(if self.space > 0 {
+                LexerError::MixedIndentation(span)
+            } else {
+                LexerError::TabIndentation(span)
+            });
+        }
+        let current = *self.indent_stack.last().unwrap_or(&0);
+        if self.space == current {
+            return Ok(None);
+        }
+        if self.space == current + 4 {
+            self.indent_stack.push(self.space);
+            return Ok(Some(Token::Indent));
+        }
+        if self.space < current && self.indent_stack.contains(&self.space) {
+            while self
+                .indent_stack
+                .last()
+                .is_some_and(|level| *level > self.space)
+            {
+                self.indent_stack.pop();
+                self.pending_dedents += 1;
+            }
+            self.pending_dedents -= 1;
+            return Ok(Some(Token::Dedent));
+        }
+        let span = self.span_from(self.line_start);
+        if self.space > current && self.space.is_multiple_of(4) {
+            return Err(LexerError::IndentedTooFar(span, current + 4, self.space));
+        }
+        Err(LexerError::InCorrectSpaceSize(span))
+    }
+    fn is_comment_line(&self) -> bool {
+        let mut ahead = self.rest().chars().peekable();
+        loop {
+            match ahead.peek() {
+                Some(' ') | Some('\t') => {
+                    ahead.next();
+                }
+                Some('#') => return true,
+                Some('/') => {
+                    ahead.next();
+                    match ahead.next() {
+                        // `///` sənəd şərhi token yaradır, ona görə girintiyə təsir edir.
+                        Some('/') => {
+                            let is_doc = ahead.next() == Some('/') && ahead.next() != Some('/');
+                            return !is_doc;
+                        }
+                        Some('*') => {}
+                        _ => return false,
+                    }
+                    let mut previous = None;
+                    for ch in ahead.by_ref() {
+                        if previous == Some('*') && ch == '/' {
+                            break;
+                        }
+                        previous = Some(ch);
+                    }
+                }
+                Some('\n') | None => return true,
+                Some(_) => return false,
+            }
         }
     }
     fn read_template_part(&mut self) -> Result<Token, LexerError> {
-        let mut content = String::new();
+        let mut content = Literal::new(self.offset);
 
-        while let Some(&ch) = self.chars.peek() {
+        while let Some(ch) = self.peek() {
             match ch {
+                '`' | '$'
+                    if !content.is_empty(self.offset) && (ch == '`' || self.starts_with("${")) =>
+                {
+                    let content = content.into_atom(self.input, self.offset);
+                    return self.finish_text(Token::StringLiteral(content));
+                }
                 '`' => {
-                    if !content.is_empty() {
-                        return Ok(Token::StringLiteral(content));
-                    }
-                    self.chars.next();
+                    self.bump();
                     self.mode_stack.pop();
                     return Ok(Token::Backtick);
                 }
-                '$' => {
-                    self.chars.next();
-                    if let Some('{') = self.chars.peek() {
-                        self.mode_stack.push(LexerMode::Normal);
-                        if !content.is_empty() {
-                            return Ok(Token::StringLiteral(content));
+                '$' if self.starts_with("${") => {
+                    self.bump();
+                    self.bump();
+                    self.mode_stack.push(LexerMode::Interpolation);
+                    return Ok(Token::InterpolationStart);
+                }
+                '\\' => {
+                    let mut ahead = self.rest().chars();
+                    ahead.next();
+                    match ahead.next() {
+                        Some(escaped @ ('`' | '$')) => {
+                            let at = self.offset;
+                            self.bump();
+                            self.bump();
+                            content.push(self.input, at, escaped, self.offset);
                         }
+                        _ => self.escape_into(&mut content)?,
                     }
-                    content.push('$');
                 }
                 _ => {
-                    content.push(ch);
-                    self.chars.next();
-                    self.end += 1;
+                    self.bump_text();
                 }
             }
         }
-        Err(LexerError::UnClosedString(
-            SourceSpan {
-                line: self.line,
-                end: self.end,
-                start: self.start,
-            },
-            content,
-        ))
+        let content = content.into_string(self.input, self.offset);
+        Err(LexerError::UnClosedString(self.span(), content))
+    }
+    /// `${x:>5}` içində `:`-dan `}`-a qədər olan mətn. Onun təhlili parserdədir.
+    fn read_format_spec(&mut self) -> Result<Token, LexerError> {
+        self.bump();
+        let mut spec = String::new();
+        while let Some(ch) = self.peek() {
+            match ch {
+                '}' => return Ok(Token::FormatSpec(spec)),
+                '\n' | '`' => break,
+                _ => {
+                    spec.push(ch);
+                    self.bump();
+                }
+            }
+        }
+        Err(LexerError::UnClosedString(self.span(), spec))
     }
     fn read_operator(&mut self) -> Result<Token, LexerError> {
-        let ch = self.chars.next().unwrap();
-        match ch {
-            '+' => Ok(Token::Add),
-            '-' => Ok(Token::Subtract),
-            '*' => Ok(Token::Multiply),
-            '/' if self.chars.peek() == Some(&'*') => self.commentline(),
-            '/' => Ok(Token::Divide),
-            '%' => Ok(Token::Modulo),
-            '>' => Ok(Token::Greater),
-            '<' => Ok(Token::Less),
-            '=' if self.chars.next() == Some('=') => Ok(Token::Equal),
-            '=' => Ok(Token::Assign),
-            '!' if self.chars.next() == Some('=') => Ok(Token::NotEqual),
-            '!' => Ok(Token::Not),
-            '&' if self.chars.peek() == Some(&'=') => Ok(Token::DoubleAnd),
-            '&' => Ok(Token::And),
-            '|' => Ok(Token::Or),
-            _ => Err(LexerError::UnexpectedToken(
-                SourceSpan {
-                    line: self.line,
-                    end: self.end,
-                    start: self.start,
-                },
-                ch,
-            )),
+        let ch = self.bump().unwrap();
+        let token = match ch {
+            '+' => self.choose('=', Token::AddAssign, Token::Add),
+            '-' => self.choose('=', Token::SubtractAssign, Token::Subtract),
+            '*' => self.choose('=', Token::MultiplyAssign, Token::Multiply),
+            '/' if self.peek() == Some('*') => return self.commentline(),
+            '/' if self.peek() == Some('/') => {
+                self.bump();
+                return self.line_comment(true);
+            }
+            '/' => self.choose('=', Token::DivideAssign, Token::Divide),
+            '%' => self.choose('=', Token::ModuloAssign, Token::Modulo),
+            '>' => self.choose('=', Token::GreaterEqual, Token::Greater),
+            '<' => self.choose('=', Token::LessEqual, Token::Less),
+            '=' => self.choose('=', Token::Equal, Token::Assign),
+            '!' => self.choose('=', Token::NotEqual, Token::Not),
+            '&' if self.peek() == Some('&') => {
+                self.bump();
+                Token::DoubleAnd
+            }
+            '|' if self.peek() == Some('|') => {
+                self.bump();
+                Token::DoubleOr
+            }
+            // Bit əməliyyatları yoxdur, ona görə tək `&` və `|` operator deyil.
+            '&' | '|' => return Err(LexerError::UnknownOperator(self.span(), ch.to_string())),
+            _ => return Err(LexerError::UnexpectedToken(self.span(), ch)),
+        };
+        Ok(token)
+    }
+    /// Növbəti simvol `next` olduqda onu da operatora daxil edir.
+    fn choose(&mut self, next: char, matched: Token, otherwise: Token) -> Token {
+        if self.peek() == Some(next) {
+            self.bump();
+            matched
+        } else {
+            otherwise
+        }
+    }
+    /// `#` və `//` şərhləri atılır, `///` isə sənəd şərhi kimi [`Token::Comment`] olur.
+    fn line_comment(&mut self, allow_doc: bool) -> Result<Token, LexerError> {
+        let is_doc = allow_doc && self.starts_with("/") && !self.starts_with("//");
+        let start = self.offset as usize;
+        while self.peek().is_some_and(|ch| ch != '\n') {
+            self.bump_text();
+        }
+        if !is_doc {
+            self.check_hidden()?;
+            return self.next_token();
         }
+        let text = &self.input[start..self.offset as usize];
+        let text = text[1..].strip_prefix(' ').unwrap_or(&text[1..]);
+        self.finish_text(Token::Comment(text.trim_end().to_string()))
     }
     fn commentline(&mut self) -> Result<Token, LexerError> {
         loop {
-            match self.chars.next() {
-                Some('*') if self.chars.next().unwrap() == '/' => {
+            match self.bump_text() {
+                Some('*') if self.starts_with("/") => {
+                    self.bump();
+                    self.check_hidden()?;
                     return self.next_token();
                 }
                 None => {
+                    self.check_hidden()?;
                     return self.next_token();
                 }
                 _ => {}
@@ -293,30 +715,34 @@ impl<'a> Lexer<'a> {
         }
     }
     fn next_token(&mut self) -> Result<Token, LexerError> {
+        if let Some(error) = self.pragma_error.take() {
+            return Err(error);
+        }
+        self.hidden = None;
         if let Some(LexerMode::Template) = self.mode_stack.last() {
+            self.start = self.offset;
             return self.read_template_part();
         }
         self.skip_whitespace();
+        self.start = self.offset;
         match self.handle_indentation() {
             Ok(Some(token)) => return Ok(token),
             Ok(None) => {}
             Err(e) => return Err(e),
         }
-        let char = self.chars.peek();
-        let token = match char {
+        let token = match self.peek() {
             Some('`') => {
-                self.chars.next();
+                self.bump();
                 self.mode_stack.push(LexerMode::Template);
                 Ok(Token::Backtick)
             }
-            Some('}') if self.mode_stack.len() > 1 => {
-                self.chars.next();
+            Some('}') if matches!(self.mode_stack.last(), Some(LexerMode::Interpolation)) => {
+                self.bump();
                 self.mode_stack.pop();
                 Ok(Token::InterpolationEnd)
             }
-            Some('{') if self.mode_stack.len() > 1 => {
-                self.chars.next();
-                Ok(Token::InterpolationStart)
+            Some(':') if matches!(self.mode_stack.last(), Some(LexerMode::Interpolation)) => {
+                self.read_format_spec()
             }
             Some('(') => self.consume(Token::LParen),
             Some(')') => self.consume(Token::RParen),
@@ -328,16 +754,25 @@ impl<'a> Lexer<'a> {
             Some('}') => self.consume(Token::RBrace),
             Some('_') => self.consume(Token::Underscore),
             Some('@') => self.consume(Token::At),
+            Some('#') => {
+                self.bump();
+                self.line_comment(false)
+            }
             Some('[') => self.consume(Token::ListStart),
             Some(']') => self.consume(Token::ListEnd),
             Some('=') | Some('/') | Some('*') | Some('%') | Some('^') | Some('>') | Some('<')
-            | Some('+') | Some('-') | Some('!') => self.read_operator(),
+            | Some('+') | Some('-') | Some('!') | Some('&') | Some('|') => self.read_operator(),
             Some('0'..='9') => self.read_number(),
-            Some('\'') | Some('"') => self.read_string(),
-            Some(_) => self.read_word(),
+            Some('\'') => self.read_char(),
+            Some('"') => self.read_string(false),
+            Some(ch) if is_ident_start(ch) => self.read_word(),
+            Some(_) => self.unexpected_char(),
             None => Ok(Token::Eof),
         };
-        self.is_line_start = false;
+        // Şərhdən sonra gələn sətir sonu yeni sətrin girintisini artıq gözləyir.
+        if !matches!(token, Ok(Token::Newline)) {
+            self.is_line_start = false;
+        }
         token
     }
 }
@@ -0,0 +1,42 @@
+use crate::tokens::Atom;
+
+/// Oxunan yazı literalının mətni. Escape olmadıqca mətn mənbədən kopyalanmır:
+/// `owned` yalnız ilk escape-dən sonra dolur, `from` isə hələ köçürülməmiş
+/// hissənin başladığı baytdır.
+pub(crate) struct Literal {
+    owned: String,
+    from: usize,
+}
+
+impl Literal {
+    pub fn new(from: u32) -> Self {
+        Self {
+            owned: String::new(),
+            from: from as usize,
+        }
+    }
+
+    /// `at`-a qədər olan mətndən sonra escape-in nəticəsi `ch` gəlir; mətn `to`-dan davam edir.
+    pub fn push(&mut self, input: &str, at: u32, ch: char, to: u32) {
+        self.owned.push_str(&input[self.from..at as usize]);
+        self.owned.push(ch);
+        self.from = to as usize;
+    }
+
+    /// Escape nəticəsi həmişə bir simvol əlavə etdiyi üçün boş `owned` escape olmadığını göstərir.
+    pub fn is_empty(&self, to: u32) -> bool {
+        self.owned.is_empty() && self.from == to as usize
+    }
+
+    pub fn into_atom(self, input: &str, to: u32) -> Atom {
+        if self.owned.is_empty() {
+            return Atom::from(&input[self.from..to as usize]);
+        }
+        Atom::from(self.into_string(input, to))
+    }
+
+    pub fn into_string(mut self, input: &str, to: u32) -> String {
+        self.owned.push_str(&input[self.from..to as usize]);
+        self.owned
+    }
+}
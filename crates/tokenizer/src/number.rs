@@ -0,0 +1,98 @@
+use crate::{
+    errors::LexerError,
+    iterator::SourceSpan,
+    tokens::{NumberSuffix, Token},
+};
+
+const SUFFIXES: [(&str, NumberSuffix); 3] = [
+    ("tinyint", NumberSuffix::TinyInt),
+    ("bigint", NumberSuffix::BigInt),
+    ("natural", NumberSuffix::Natural),
+];
+
+/// Ədəd literalının mətnini (`0xFF`, `1_000`, `1.5e-3`, `255natural`) tokenə çevirir.
+pub fn parse_number(text: &str, span: SourceSpan) -> Result<Token, LexerError> {
+    let invalid = || LexerError::InvalidNumber(span.clone(), text.to_string());
+
+    let (body, suffix) = split_suffix(text);
+    let (radix, digits) = match body.get(..2) {
+        Some("0x" | "0X") => (16, &body[2..]),
+        Some("0b" | "0B") => (2, &body[2..]),
+        Some("0o" | "0O") => (8, &body[2..]),
+        _ => (10, body),
+    };
+    if digits.is_empty() || digits.starts_with('_') || digits.ends_with('_') {
+        return Err(invalid());
+    }
+    let clean: String = digits.chars().filter(|ch| *ch != '_').collect();
+
+    if radix == 10 {
+        if clean.contains(['.', 'e', 'E']) && is_float(&clean) {
+            if suffix.is_some() {
+                return Err(invalid());
+            }
+            let value = clean.parse::<f64>().map_err(|_| invalid())?;
+            if !value.is_finite() {
+                return Err(LexerError::NumberOutOfRange(
+                    span,
+                    text.to_string(),
+                    "float".to_string(),
+                ));
+            }
+            return Ok(Token::Float(value));
+        }
+        if !clean.chars().all(|ch| ch.is_ascii_digit()) {
+            return Err(LexerError::NumberAndAlpha);
+        }
+        if clean.starts_with('0') && clean.len() > 1 {
+            return Err(LexerError::CannotStartZeroNumber(span, text.to_string()));
+        }
+    } else if !clean.chars().all(|ch| ch.is_digit(radix)) {
+        return Err(invalid());
+    }
+
+    let target = suffix.unwrap_or(NumberSuffix::BigInt);
+    let out_of_range =
+        || LexerError::NumberOutOfRange(span.clone(), text.to_string(), target.to_string());
+    let value = u64::from_str_radix(&clean, radix).map_err(|_| out_of_range())?;
+    if value > target.magnitude() {
+        return Err(out_of_range());
+    }
+    // `bigint` üçün `2^63` `i64::MIN` kimi saxlanılır; işarəsiz yoxlamanı parser edir.
+    let value = value as i64;
+    Ok(match suffix {
+        Some(suffix) => Token::TypedNumber(value, suffix),
+        None => Token::Number(value),
+    })
+}
+
+/// `255natural` və `255_natural` yazılışlarının hər ikisi qəbul olunur.
+fn split_suffix(text: &str) -> (&str, Option<NumberSuffix>) {
+    for (name, suffix) in SUFFIXES {
+        if let Some(body) = text.strip_suffix(name) {
+            return (body.strip_suffix('_').unwrap_or(body), Some(suffix));
+        }
+    }
+    (text, None)
+}
+
+fn is_float(text: &str) -> bool {
+    let (mantissa, exponent) = match text.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (text, None),
+    };
+    let mantissa_ok = match mantissa.split_once('.') {
+        Some((int, frac)) => {
+            !int.is_empty()
+                && int.chars().all(|ch| ch.is_ascii_digit())
+                && !frac.is_empty()
+                && frac.chars().all(|ch| ch.is_ascii_digit())
+        }
+        None => !mantissa.is_empty() && mantissa.chars().all(|ch| ch.is_ascii_digit()),
+    };
+    let exponent_ok = exponent.is_none_or(|exponent| {
+        let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        !digits.is_empty() && digits.chars().all(|ch| ch.is_ascii_digit())
+    });
+    mantissa_ok && exponent_ok
+}
@@ -0,0 +1,112 @@
+use std::{cell::RefCell, fmt, rc::Rc};
+
+use locale::t;
+
+/// Mənbə faylının identifikatoru. `0` heç bir fayla bağlı olmayan span-lar üçündür.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FileId(pub u32);
+
+/// Sətir və sütun, hər ikisi `1`-dən başlayır. Sütun baytla deyil, simvolla sayılır.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: u32,
+    pub column: u32,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            t!("location", line = self.line, column = self.column)
+        )
+    }
+}
+
+/// Hər sətrin başladığı bayt mövqeyi. Sətir/sütun yalnız lazım olanda hesablanır.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineIndex {
+    line_starts: Vec<u32>,
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(
+            text.bytes()
+                .enumerate()
+                .filter(|(_, byte)| *byte == b'\n')
+                .map(|(index, _)| index as u32 + 1),
+        );
+        Self { line_starts }
+    }
+
+    /// `offset` baytının yerləşdiyi sətrin indeksi (`0`-dan) və həmin sətrin başlanğıcı.
+    pub fn line(&self, offset: u32) -> (usize, u32) {
+        let index = self.line_starts.partition_point(|start| *start <= offset) - 1;
+        (index, self.line_starts[index])
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+}
+
+#[derive(Debug)]
+pub struct SourceFile {
+    pub id: FileId,
+    pub name: String,
+    pub text: String,
+    pub lines: LineIndex,
+}
+
+impl SourceFile {
+    pub fn location(&self, offset: u32) -> Location {
+        let offset = offset.min(self.text.len() as u32);
+        let (line, line_start) = self.lines.line(offset);
+        let column = self
+            .text
+            .get(line_start as usize..offset as usize)
+            .map_or(0, |prefix| prefix.chars().count());
+        Location {
+            line: line as u32 + 1,
+            column: column as u32 + 1,
+        }
+    }
+
+    /// `line` sətrinin (`1`-dən) mətni, sətir sonu simvolu olmadan.
+    pub fn line_text(&self, line: u32) -> Option<&str> {
+        let index = line.checked_sub(1)? as usize;
+        let start = *self.lines.line_starts.get(index)? as usize;
+        let rest = &self.text[start..];
+        Some(
+            rest.split('\n')
+                .next()
+                .unwrap_or(rest)
+                .trim_end_matches('\r'),
+        )
+    }
+}
+
+thread_local! {
+    static FILES: RefCell<Vec<Rc<SourceFile>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Faylı mənbə xəritəsinə əlavə edir və ona yeni [`FileId`] verir.
+pub fn add_file(name: &str, text: &str) -> FileId {
+    FILES.with_borrow_mut(|files| {
+        let id = FileId(files.len() as u32 + 1);
+        files.push(Rc::new(SourceFile {
+            id,
+            name: name.to_string(),
+            text: text.to_string(),
+            lines: LineIndex::new(text),
+        }));
+        id
+    })
+}
+
+pub fn get_file(id: FileId) -> Option<Rc<SourceFile>> {
+    let index = id.0.checked_sub(1)? as usize;
+    FILES.with_borrow(|files| files.get(index).cloned())
+}
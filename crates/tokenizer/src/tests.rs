@@ -2,9 +2,13 @@ use std::f64::consts::PI;
 
 use crate::{
     Lexer,
+    convert::convert_keywords,
     errors::LexerError,
     iterator::{SourceSpan, Tokens},
-    tokens::Token,
+    source::{FileId, Location, add_file, get_file},
+    tokens::{NumberSuffix, Token},
+    trivia::{LosslessToken, TriviaKind},
+    words::{AZ_KEYWORDS, KEYWORDS, KeywordSet, pragma},
 };
 
 fn tokenize(input: &str) -> Vec<Token> {
@@ -15,12 +19,15 @@ fn tokenize(input: &str) -> Vec<Token> {
         .collect()
 }
 
+/// Xəta yerləri yoxlandığı üçün mətn adlı fayl kimi qeydə alınır.
 fn tokenize_err(input: &str) -> LexerError {
-    Lexer::new(input).tokenize().unwrap_err()
+    Lexer::with_file(input, add_file("test.az", input))
+        .tokenize()
+        .unwrap_err()
 }
 
 fn tokenize_spanned(input: &str) -> Vec<(Token, SourceSpan)> {
-    Lexer::new(input)
+    Lexer::with_file(input, add_file("test.az", input))
         .tokenize()
         .unwrap()
         .map(|x| (x.token, x.span))
@@ -137,8 +144,66 @@ fn test_not_equal() {
     assert_eq!(tokenize("!="), vec![Token::NotEqual]);
 }
 
-// `&` and `|` aren't in the operator match arm, so they fall through to read_word
-// causing an infinite loop (char never consumed). These are tracked as known bugs.
+#[test]
+fn test_greater_equal() {
+    assert_eq!(tokenize(">="), vec![Token::GreaterEqual]);
+}
+
+#[test]
+fn test_less_equal() {
+    assert_eq!(tokenize("<="), vec![Token::LessEqual]);
+}
+
+#[test]
+fn test_double_and() {
+    assert_eq!(tokenize("&&"), vec![Token::DoubleAnd]);
+}
+
+#[test]
+fn test_double_or() {
+    assert_eq!(tokenize("||"), vec![Token::DoubleOr]);
+}
+
+#[test]
+fn test_single_ampersand_and_pipe_are_errors() {
+    for (source, operator) in [("a & b", "&"), ("a | b", "|"), ("&=", "&")] {
+        match tokenize_err(source) {
+            LexerError::UnknownOperator(span, text) => {
+                assert_eq!(text, operator);
+                assert_eq!(span.len(), 1);
+            }
+            other => panic!("UnknownOperator gözlənilirdi, {other:?} alındı"),
+        }
+    }
+}
+
+#[test]
+fn test_compound_assign() {
+    assert_eq!(
+        tokenize("+= -= *= /= %="),
+        vec![
+            Token::AddAssign,
+            Token::SubtractAssign,
+            Token::MultiplyAssign,
+            Token::DivideAssign,
+            Token::ModuloAssign
+        ]
+    );
+}
+
+#[test]
+fn test_operator_does_not_swallow_next_char() {
+    assert_eq!(
+        tokenize("a=1 !b"),
+        vec![
+            Token::Identifier("a".into()),
+            Token::Assign,
+            Token::Number(1),
+            Token::Not,
+            Token::Identifier("b".into())
+        ]
+    );
+}
 
 // ── Numbers ──
 
@@ -189,6 +254,90 @@ fn test_number_followed_by_alpha_error() {
     }
 }
 
+#[test]
+fn test_number_radix_prefixes() {
+    assert_eq!(tokenize("0xFF"), vec![Token::Number(255)]);
+    assert_eq!(tokenize("0b1010"), vec![Token::Number(10)]);
+    assert_eq!(tokenize("0o17"), vec![Token::Number(15)]);
+}
+
+#[test]
+fn test_number_separators() {
+    assert_eq!(tokenize("1_000_000"), vec![Token::Number(1_000_000)]);
+    assert_eq!(tokenize("0xFF_FF"), vec![Token::Number(0xFFFF)]);
+    assert!(matches!(
+        tokenize_err("1_"),
+        LexerError::InvalidNumber(_, _)
+    ));
+    assert!(matches!(
+        tokenize_err("0x_1"),
+        LexerError::InvalidNumber(_, _)
+    ));
+}
+
+#[test]
+fn test_number_exponent() {
+    assert_eq!(tokenize("1.5e-3"), vec![Token::Float(1.5e-3)]);
+    assert_eq!(tokenize("2e10"), vec![Token::Float(2e10)]);
+    assert!(matches!(tokenize_err("1e"), LexerError::NumberAndAlpha));
+}
+
+#[test]
+fn test_number_type_suffixes() {
+    assert_eq!(
+        tokenize("7tinyint 1_000bigint 0xFF_natural"),
+        vec![
+            Token::TypedNumber(7, NumberSuffix::TinyInt),
+            Token::TypedNumber(1000, NumberSuffix::BigInt),
+            Token::TypedNumber(255, NumberSuffix::Natural),
+        ]
+    );
+    assert!(matches!(
+        tokenize_err("1.5tinyint"),
+        LexerError::InvalidNumber(_, _)
+    ));
+}
+
+#[test]
+fn test_number_out_of_range_has_span() {
+    match tokenize_err("x = 129tinyint") {
+        LexerError::NumberOutOfRange(span, literal, typ) => {
+            assert_eq!(literal, "129tinyint");
+            assert_eq!(typ, "tinyint");
+            assert_eq!(span.end - span.start, "129tinyint".len() as u32);
+        }
+        other => panic!("Expected NumberOutOfRange, got {:?}", other),
+    }
+    assert!(matches!(
+        tokenize_err("9223372036854775809"),
+        LexerError::NumberOutOfRange(_, _, _)
+    ));
+    // İşarəli tiplərin mənfi sərhədi: mənfi işarəni parser tətbiq edir.
+    assert_eq!(
+        tokenize("128tinyint 9223372036854775808"),
+        vec![
+            Token::TypedNumber(128, NumberSuffix::TinyInt),
+            Token::Number(i64::MIN),
+        ]
+    );
+    assert!(matches!(
+        tokenize_err("0x1_0000_0000natural"),
+        LexerError::NumberOutOfRange(_, _, _)
+    ));
+}
+
+#[test]
+fn test_number_dot_method_is_not_float() {
+    assert_eq!(
+        tokenize("1.max"),
+        vec![
+            Token::Number(1),
+            Token::Dot,
+            Token::Identifier("max".into())
+        ]
+    );
+}
+
 // ── Strings ──
 
 #[test]
@@ -214,8 +363,8 @@ fn test_string_with_spaces() {
 
 #[test]
 fn test_string_single_quotes_not_closed() {
-    match tokenize_err("'hello'") {
-        LexerError::UnClosedString(_, ref s) => assert_eq!(s, "hello'"),
+    match tokenize_err("'hello") {
+        LexerError::UnClosedString(_, ref s) => assert_eq!(s, "hello"),
         other => panic!("Expected UnClosedString, got {:?}", other),
     }
 }
@@ -236,6 +385,133 @@ fn test_string_unclosed_newline() {
     }
 }
 
+#[test]
+fn test_string_escapes() {
+    assert_eq!(
+        tokenize(r#""a\nb\tc\rd\\e\"f\'g\0""#),
+        vec![Token::StringLiteral("a\nb\tc\rd\\e\"f'g\0".into())]
+    );
+}
+
+#[test]
+fn test_string_unicode_escape() {
+    assert_eq!(
+        tokenize(r#""\u{259}\u{1F600}""#),
+        vec![Token::StringLiteral("ə😀".into())]
+    );
+}
+
+#[test]
+fn test_string_invalid_escape() {
+    match tokenize_err(r#""ab\q""#) {
+        LexerError::InvalidEscape(span, ref s) => {
+            assert_eq!(s, "\\q");
+            assert_eq!(span.location().unwrap().line, 1);
+        }
+        other => panic!("Expected InvalidEscape, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_string_invalid_unicode_escape() {
+    for input in [
+        r#""\u{}""#,
+        r#""\u{D800}""#,
+        r#""\u{1234567}""#,
+        r#""\u12""#,
+    ] {
+        assert!(matches!(tokenize_err(input), LexerError::InvalidEscape(..)));
+    }
+}
+
+#[test]
+fn test_raw_string_keeps_backslashes() {
+    assert_eq!(
+        tokenize(r#"r"C:\yol\n""#),
+        vec![Token::StringLiteral(r"C:\yol\n".into())]
+    );
+}
+
+#[test]
+fn test_text_block() {
+    assert_eq!(
+        tokenize("\"\"\"\nbir\n\\tiki\n\"\"\" x"),
+        vec![
+            Token::StringLiteral("bir\n\tiki\n".into()),
+            Token::Identifier("x".into())
+        ]
+    );
+}
+
+#[test]
+fn test_raw_text_block() {
+    assert_eq!(
+        tokenize("r\"\"\"a\\n\"b\"\"\""),
+        vec![Token::StringLiteral("a\\n\"b".into())]
+    );
+}
+
+#[test]
+fn test_text_block_unclosed() {
+    assert!(matches!(
+        tokenize_err("\"\"\"\nbir\n"),
+        LexerError::UnClosedString(..)
+    ));
+}
+
+#[test]
+fn test_identifier_r_is_not_raw_string() {
+    assert_eq!(tokenize("r"), vec![Token::Identifier("r".into())]);
+}
+
+// ── Chars ──
+
+#[test]
+fn test_char_literal() {
+    assert_eq!(tokenize("'a'"), vec![Token::CharLiteral('a')]);
+}
+
+#[test]
+fn test_char_literal_azerbaijani_letters() {
+    assert_eq!(
+        tokenize("'ə' 'ğ' 'Ş'"),
+        vec![
+            Token::CharLiteral('ə'),
+            Token::CharLiteral('ğ'),
+            Token::CharLiteral('Ş')
+        ]
+    );
+}
+
+#[test]
+fn test_char_literal_escapes() {
+    assert_eq!(
+        tokenize(r"'\n' '\'' '\\' '\u{259}'"),
+        vec![
+            Token::CharLiteral('\n'),
+            Token::CharLiteral('\''),
+            Token::CharLiteral('\\'),
+            Token::CharLiteral('ə')
+        ]
+    );
+}
+
+#[test]
+fn test_char_literal_empty() {
+    assert!(matches!(
+        tokenize_err("''"),
+        LexerError::EmptyCharLiteral(_)
+    ));
+}
+
+#[test]
+fn test_char_literal_multiple_chars() {
+    match tokenize_err("'hello'") {
+        LexerError::MultiCharLiteral(_, ref s) => assert_eq!(s, "hello"),
+        other => panic!("Expected MultiCharLiteral, got {:?}", other),
+    }
+}
+
 // ── Keywords / Words ──
 
 #[test]
@@ -248,6 +524,11 @@ fn test_keyword_constant_decl() {
     assert_eq!(tokenize("const"), vec![Token::ConstantDecl]);
 }
 
+#[test]
+fn test_keyword_exit() {
+    assert_eq!(tokenize("exit"), vec![Token::Exit]);
+}
+
 #[test]
 fn test_keyword_conditional() {
     assert_eq!(tokenize("if"), vec![Token::Conditional]);
@@ -474,11 +755,20 @@ fn test_template_unclosed() {
     }
 }
 
-// `${` at start of template has a bug where empty content before `${` is not handled
-// This test documents the actual current behavior
 #[test]
-fn test_template_interpolation_at_start_errors() {
-    assert!(Lexer::new("`${1 + 2}`").tokenize().is_err());
+fn test_template_interpolation_at_start() {
+    assert_eq!(
+        tokenize("`${1 + 2}`"),
+        vec![
+            Token::Backtick,
+            Token::InterpolationStart,
+            Token::Number(1),
+            Token::Add,
+            Token::Number(2),
+            Token::InterpolationEnd,
+            Token::Backtick,
+        ]
+    );
 }
 
 // ── Indentation ──
@@ -493,6 +783,7 @@ fn test_indent_simple() {
             Token::Newline,
             Token::Indent,
             Token::Identifier("b".into()),
+            Token::Dedent,
         ]
     );
 }
@@ -527,6 +818,8 @@ fn test_multiple_indent_levels() {
             Token::Newline,
             Token::Indent,
             Token::Identifier("c".into()),
+            Token::Dedent,
+            Token::Dedent,
         ]
     );
 }
@@ -555,10 +848,114 @@ fn test_dedent_multiple_levels() {
             Token::Newline,
             Token::Dedent,
             Token::Identifier("d".into()),
+            Token::Dedent,
+        ]
+    );
+}
+
+#[test]
+fn test_dedent_all_levels_at_eof() {
+    let tokens = tokenize("a\n    b\n        c\n");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Identifier("a".into()),
+            Token::Newline,
+            Token::Indent,
+            Token::Identifier("b".into()),
+            Token::Newline,
+            Token::Indent,
+            Token::Identifier("c".into()),
+            Token::Newline,
+            Token::Dedent,
+            Token::Dedent,
+        ]
+    );
+}
+
+#[test]
+fn test_blank_lines_do_not_change_indent() {
+    let tokens = tokenize("a\n    b\n\n  \n    c\nd");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Identifier("a".into()),
+            Token::Newline,
+            Token::Indent,
+            Token::Identifier("b".into()),
+            Token::Newline,
+            Token::Newline,
+            Token::Newline,
+            Token::Identifier("c".into()),
+            Token::Newline,
+            Token::Dedent,
+            Token::Identifier("d".into()),
+        ]
+    );
+}
+
+#[test]
+fn test_comment_only_line_does_not_change_indent() {
+    let tokens = tokenize("a\n    b\n/* şərh */\n    c");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Identifier("a".into()),
+            Token::Newline,
+            Token::Indent,
+            Token::Identifier("b".into()),
+            Token::Newline,
+            Token::Newline,
+            Token::Identifier("c".into()),
+            Token::Dedent,
         ]
     );
 }
 
+#[test]
+fn test_indent_too_far_says_how_far() {
+    match tokenize_err("a\n        b") {
+        LexerError::IndentedTooFar(_, 4, 8) => {}
+        other => panic!("Expected IndentedTooFar, got {:?}", other),
+    }
+    match tokenize_err("a\n    b\n            c") {
+        LexerError::IndentedTooFar(_, 8, 12) => {}
+        other => panic!("Expected IndentedTooFar, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_dedent_to_unknown_level() {
+    match tokenize_err("a\n    b\n        c\n  d") {
+        LexerError::InCorrectSpaceSize(span) => assert_eq!(span.location().unwrap().line, 4),
+        other => panic!("Expected InCorrectSpaceSize, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_tab_indentation() {
+    match tokenize_err("a\n\tb") {
+        LexerError::TabIndentation(span) => assert_eq!(span.location().unwrap().line, 2),
+        other => panic!("Expected TabIndentation, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_mixed_indentation() {
+    assert!(matches!(
+        tokenize_err("a\n  \t  b"),
+        LexerError::MixedIndentation(_)
+    ));
+}
+
+#[test]
+fn test_tab_inside_line_is_whitespace() {
+    assert_eq!(
+        tokenize("a\tb"),
+        vec![Token::Identifier("a".into()), Token::Identifier("b".into())]
+    );
+}
+
 // ── Comments ──
 
 #[test]
@@ -585,45 +982,144 @@ fn test_comment_at_end() {
     assert_eq!(tokens, vec![Token::Identifier("a".into()),]);
 }
 
-// ── Composite expressions ──
-
 #[test]
-fn test_simple_expression() {
-    let tokens = tokenize("var a = 42");
+fn test_line_comments_are_skipped() {
+    let tokens = tokenize("a # şərh\nb // şərh\nc //// ayırıcı");
     assert_eq!(
         tokens,
         vec![
-            Token::MutableDecl,
             Token::Identifier("a".into()),
-            Token::Assign,
-            Token::Number(42),
+            Token::Newline,
+            Token::Identifier("b".into()),
+            Token::Newline,
+            Token::Identifier("c".into()),
         ]
     );
 }
 
 #[test]
-fn test_arithmetic_expression() {
-    let tokens = tokenize("1 + 2 * 3");
+fn test_doc_comment_token() {
+    let tokens = tokenize("/// Toplama funksiyası\n///\nfunc");
     assert_eq!(
         tokens,
         vec![
-            Token::Number(1),
-            Token::Add,
-            Token::Number(2),
-            Token::Multiply,
-            Token::Number(3),
+            Token::Comment("Toplama funksiyası".into()),
+            Token::Newline,
+            Token::Comment("".into()),
+            Token::Newline,
+            Token::FunctionDef,
         ]
     );
 }
 
 #[test]
-fn test_comparison_chain() {
-    let tokens = tokenize("a == b != c");
+fn test_hash_slash_is_not_doc_comment() {
+    assert_eq!(tokenize("#/ x"), vec![]);
+}
+
+#[test]
+fn test_line_comment_only_line_does_not_change_indent() {
+    let tokens = tokenize("a\n    b\n# şərh\n  // şərh\n    c");
     assert_eq!(
         tokens,
         vec![
             Token::Identifier("a".into()),
-            Token::Equal,
+            Token::Newline,
+            Token::Indent,
+            Token::Identifier("b".into()),
+            Token::Newline,
+            Token::Newline,
+            Token::Newline,
+            Token::Identifier("c".into()),
+            Token::Dedent,
+        ]
+    );
+}
+
+#[test]
+fn test_doc_comment_line_is_indented() {
+    let tokens = tokenize("a\n    /// sənəd\n    b");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Identifier("a".into()),
+            Token::Newline,
+            Token::Indent,
+            Token::Comment("sənəd".into()),
+            Token::Newline,
+            Token::Identifier("b".into()),
+            Token::Dedent,
+        ]
+    );
+}
+
+#[test]
+fn test_comment_before_indented_line_keeps_indent() {
+    let expected = vec![
+        Token::Conditional,
+        Token::Identifier("x".into()),
+        Token::Colon,
+        Token::Newline,
+        Token::Indent,
+        Token::Identifier("y".into()),
+        Token::Dedent,
+    ];
+    assert_eq!(tokenize("if x: # şərh\n    y"), expected);
+    assert_eq!(tokenize("if x: /* şərh */\n    y"), expected);
+    assert_eq!(
+        tokenize("if x:\n    # şərh\n    y"),
+        vec![
+            Token::Conditional,
+            Token::Identifier("x".into()),
+            Token::Colon,
+            Token::Newline,
+            Token::Newline,
+            Token::Indent,
+            Token::Identifier("y".into()),
+            Token::Dedent,
+        ]
+    );
+}
+
+// ── Composite expressions ──
+
+#[test]
+fn test_simple_expression() {
+    let tokens = tokenize("var a = 42");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::MutableDecl,
+            Token::Identifier("a".into()),
+            Token::Assign,
+            Token::Number(42),
+        ]
+    );
+}
+
+#[test]
+fn test_arithmetic_expression() {
+    let tokens = tokenize("1 + 2 * 3");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Number(1),
+            Token::Add,
+            Token::Number(2),
+            Token::Multiply,
+            Token::Number(3),
+        ]
+    );
+}
+
+#[test]
+fn test_comparison_chain() {
+    let tokens = tokenize("a == b != c");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Identifier("a".into()),
+            Token::Equal,
             Token::Identifier("b".into()),
             Token::NotEqual,
             Token::Identifier("c".into()),
@@ -668,31 +1164,74 @@ fn test_span_single_token() {
     assert_eq!(spanned.len(), 1);
     let (token, span) = &spanned[0];
     assert_eq!(*token, Token::Identifier("abc".into()));
-    assert_eq!(span.line, 1);
-    assert_eq!(span.start, 1);
-    assert_eq!(span.end, 5);
+    assert_eq!((span.start, span.end), (0, 3));
+    assert_eq!(span.location(), Some(Location { line: 1, column: 1 }));
 }
 
 #[test]
 fn test_span_multiple_tokens() {
-    let spanned = tokenize_spanned("a + b");
+    let spanned = tokenize_spanned("a + bc");
     assert_eq!(spanned.len(), 3);
     assert_eq!(spanned[0].0, Token::Identifier("a".into()));
-    assert_eq!(spanned[0].1.start, 1);
+    assert_eq!((spanned[0].1.start, spanned[0].1.end), (0, 1));
     assert_eq!(spanned[1].0, Token::Add);
-    assert_eq!(spanned[1].1.start, 4);
-    assert_eq!(spanned[2].0, Token::Identifier("b".into()));
-    assert_eq!(spanned[2].1.start, 5);
+    assert_eq!((spanned[1].1.start, spanned[1].1.end), (2, 3));
+    assert_eq!(spanned[2].0, Token::Identifier("bc".into()));
+    assert_eq!((spanned[2].1.start, spanned[2].1.end), (4, 6));
 }
 
 #[test]
 fn test_span_newline_increments_line() {
     let spanned = tokenize_spanned("a\nb");
     assert_eq!(spanned.len(), 3);
-    assert_eq!(spanned[0].1.line, 1);
-    assert_eq!(spanned[0].1.start, 1);
-    assert_eq!(spanned[1].1.line, 2);
-    assert_eq!(spanned[2].1.line, 2);
+    let lines: Vec<u32> = spanned
+        .iter()
+        .map(|(_, span)| span.location().unwrap().line)
+        .collect();
+    assert_eq!(lines, vec![1, 1, 2]);
+    assert_eq!((spanned[1].1.start, spanned[1].1.end), (1, 2));
+    assert_eq!(spanned[2].1.location().unwrap().column, 1);
+}
+
+#[test]
+fn test_span_covers_strings_and_operators() {
+    let spanned = tokenize_spanned("x >= \"salam\" + `a${b}`");
+    let ranges: Vec<(u32, u32)> = spanned.iter().map(|(_, s)| (s.start, s.end)).collect();
+    assert_eq!(ranges[..4], [(0, 1), (2, 4), (5, 12), (13, 14)]);
+    assert_eq!(ranges[4], (15, 16));
+}
+
+#[test]
+fn test_span_columns_count_chars_not_bytes() {
+    let spanned = tokenize_spanned("əşi + ğ");
+    assert_eq!((spanned[0].1.start, spanned[0].1.end), (0, 5));
+    assert_eq!(spanned[2].1.location().unwrap().column, 7);
+}
+
+#[test]
+fn test_span_after_block_comment_keeps_lines() {
+    let spanned = tokenize_spanned("/* bir\niki */ x");
+    assert_eq!(spanned[0].0, Token::Identifier("x".into()));
+    assert_eq!(
+        spanned[0].1.location(),
+        Some(Location { line: 2, column: 8 })
+    );
+}
+
+#[test]
+fn test_span_carries_file_id() {
+    let file = add_file("esas.az", "a\nb");
+    let tokens = Lexer::with_file("a\nb", file).tokenize().unwrap();
+    assert!(tokens.into_iter().all(|token| token.span.file == file));
+    assert_eq!(get_file(file).unwrap().name, "esas.az");
+}
+
+#[test]
+fn test_anonymous_input_is_not_registered() {
+    let token = Lexer::new("a").tokenize().unwrap().next().unwrap();
+    assert_eq!(token.span.file, FileId::default());
+    assert_eq!(token.span.location(), None);
+    assert_eq!(token.span.to_string(), "Bayt 0..1");
 }
 
 // ── Tokens iterator ──
@@ -700,11 +1239,7 @@ fn test_span_newline_increments_line() {
 #[test]
 fn test_tokens_iterator() {
     let mut tokens = Tokens::default();
-    let span = SourceSpan {
-        start: 1,
-        end: 1,
-        line: 1,
-    };
+    let span = SourceSpan::new(FileId::default(), 1, 1);
     tokens.push(Token::Number(1), span.clone());
     tokens.push(Token::Add, span.clone());
     tokens.push(Token::Number(2), span.clone());
@@ -718,11 +1253,7 @@ fn test_tokens_iterator() {
 #[test]
 fn test_tokens_peek() {
     let mut tokens = Tokens::default();
-    let span = SourceSpan {
-        start: 1,
-        end: 1,
-        line: 1,
-    };
+    let span = SourceSpan::new(FileId::default(), 1, 1);
     tokens.push(Token::Number(42), span.clone());
     assert_eq!(
         tokens.peek(),
@@ -736,11 +1267,7 @@ fn test_tokens_peek() {
 #[test]
 fn test_tokens_peek_nth() {
     let mut tokens = Tokens::default();
-    let span = SourceSpan {
-        start: 1,
-        end: 1,
-        line: 1,
-    };
+    let span = SourceSpan::new(FileId::default(), 1, 1);
     tokens.push(Token::Number(1), span.clone());
     tokens.push(Token::Number(2), span.clone());
     assert_eq!(
@@ -757,11 +1284,7 @@ fn test_tokens_peek_nth() {
 #[test]
 fn test_tokens_push_front() {
     let mut tokens = Tokens::default();
-    let span = SourceSpan {
-        start: 1,
-        end: 1,
-        line: 1,
-    };
+    let span = SourceSpan::new(FileId::default(), 1, 1);
     tokens.push(Token::Number(2), span.clone());
     tokens.push_front(crate::iterator::SpannedToken {
         token: Token::Number(1),
@@ -777,33 +1300,90 @@ fn test_tokens_empty_peek() {
     assert!(tokens.peek().is_none());
 }
 
+#[test]
+fn test_lazy_tokens_match_tokenize() {
+    let source =
+        "func f(const str ad): # şərh\n    print(`Salam, ${ad:>5}`)\n    return \"a\\tb\"\n";
+    let lazy: Vec<(Token, u32, u32)> = Lexer::new(source)
+        .into_tokens()
+        .map(|x| (x.token, x.span.start, x.span.end))
+        .collect();
+    let eager: Vec<(Token, u32, u32)> = tokenize_spanned(source)
+        .into_iter()
+        .map(|(token, span)| (token, span.start, span.end))
+        .collect();
+    assert_eq!(lazy, eager);
+}
+
+#[test]
+fn test_lazy_tokens_keep_lookahead() {
+    let mut tokens = Lexer::new("a = b + c").into_tokens();
+    assert_eq!(
+        tokens.peek_nth(2).map(|x| &x.token),
+        Some(&Token::Identifier("b".into()))
+    );
+    tokens.next();
+    assert_eq!(tokens.peek_nth(2).map(|x| &x.token), Some(&Token::Add));
+}
+
+#[test]
+fn test_lazy_tokens_finish_reports_all_errors() {
+    let mut tokens = Lexer::new("a $ b\nc ; d\ne").into_tokens();
+    assert_eq!(
+        tokens.next().map(|x| x.token),
+        Some(Token::Identifier("a".into()))
+    );
+    let errors = tokens.finish();
+    assert!(matches!(
+        errors.as_slice(),
+        [
+            LexerError::UnexpectedToken(_, '$'),
+            LexerError::UnexpectedToken(_, ';')
+        ]
+    ));
+}
+
+#[test]
+fn test_string_literal_escapes_between_source_text() {
+    assert_eq!(
+        tokenize("\"ab\\ncd\\u{259}\""),
+        vec![Token::StringLiteral("ab\ncdə".into())]
+    );
+    assert_eq!(
+        tokenize("`a\\`b${x}c\\$d`"),
+        vec![
+            Token::Backtick,
+            Token::StringLiteral("a`b".into()),
+            Token::InterpolationStart,
+            Token::Identifier("x".into()),
+            Token::InterpolationEnd,
+            Token::StringLiteral("c$d".into()),
+            Token::Backtick,
+        ]
+    );
+}
+
 // ── LexerError display ──
 
 #[test]
 fn test_error_display_unexpected_token() {
-    let err = LexerError::UnexpectedToken(
-        SourceSpan {
-            start: 5,
-            end: 5,
-            line: 3,
-        },
-        '#',
-    );
+    let err = LexerError::UnexpectedToken(SourceSpan::new(FileId::default(), 5, 5), '#');
     let msg = format!("{}", err);
     assert!(msg.contains("Uyğunluq olmayan token"));
     assert!(msg.contains("#"));
 }
 
+#[test]
+fn test_error_display_invalid_escape() {
+    let err = LexerError::InvalidEscape(SourceSpan::new(FileId::default(), 3, 5), "\\q".into());
+    let msg = format!("{}", err);
+    assert!(msg.contains("Yanlış escape"));
+    assert!(msg.contains("\\q"));
+}
+
 #[test]
 fn test_error_display_unclosed_string() {
-    let err = LexerError::UnClosedString(
-        SourceSpan {
-            start: 1,
-            end: 5,
-            line: 1,
-        },
-        "hello".into(),
-    );
+    let err = LexerError::UnClosedString(SourceSpan::new(FileId::default(), 1, 5), "hello".into());
     let msg = format!("{}", err);
     assert!(msg.contains("String düzgün bağlanmayıb"));
     assert!(msg.contains("hello"));
@@ -816,41 +1396,16 @@ fn test_error_display_number_and_alpha() {
     assert_eq!(msg, "Ədəddən sonra hərf gələ bilməz!");
 }
 
-#[test]
-fn test_error_display_variable_cannot_be_number() {
-    let err = LexerError::VariableCannotBeNumber;
-    let msg = format!("{}", err);
-    assert_eq!(msg, "Başlangıc ədədlə adlandırıla bilməz!");
-}
-
-#[test]
-fn test_error_display_double_dot() {
-    let err = LexerError::DoubleDotNumber;
-    let msg = format!("{}", err);
-    assert_eq!(msg, "İki dəfə nöqtə qoya bilməzsiniz");
-}
-
 #[test]
 fn test_error_display_incorrect_space() {
-    let err = LexerError::InCorrectSpaceSize(SourceSpan {
-        start: 1,
-        end: 1,
-        line: 1,
-    });
+    let err = LexerError::InCorrectSpaceSize(SourceSpan::new(FileId::default(), 1, 1));
     let msg = format!("{}", err);
     assert!(msg.contains("Uyğunsuz boşluq var."));
 }
 
 #[test]
 fn test_error_display_unknown_operator() {
-    let err = LexerError::UnknownOperator(
-        SourceSpan {
-            start: 1,
-            end: 1,
-            line: 1,
-        },
-        "^^".into(),
-    );
+    let err = LexerError::UnknownOperator(SourceSpan::new(FileId::default(), 1, 1), "^^".into());
     let msg = format!("{}", err);
     assert!(msg.contains("Uyğunluq olmayan operator"));
     assert!(msg.contains("^^"));
@@ -970,28 +1525,11 @@ fn test_token_display_types() {
 
 #[test]
 fn test_source_span_display() {
-    let span = SourceSpan {
-        start: 5,
-        end: 7,
-        line: 3,
-    };
+    let file = add_file("test.az", "a\nb\nc = 1");
+    let span = SourceSpan::new(file, 8, 9);
     assert_eq!(format!("{}", span), "Sətir 3, sütun 5");
-}
-
-// ── Float parse error ──
-
-#[test]
-fn test_float_unknown_display() {
-    let err = LexerError::FloatUnKnow("invalid float".parse::<f64>().unwrap_err());
-    let msg = format!("{}", err);
-    assert!(msg.contains("Kəsr tokenizerdə bilinməyən problem oldu"));
-}
-
-#[test]
-fn test_number_unknown_display() {
-    let err = LexerError::NumberUnKnow("not a number".parse::<i64>().unwrap_err());
-    let msg = format!("{}", err);
-    assert!(msg.contains("Ədəd tokenizerdə bilinməyən problem oldu"));
+    let unknown = SourceSpan::new(FileId::default(), 5, 7);
+    assert_eq!(format!("{}", unknown), "Bayt 5..7");
 }
 
 // ── The single existing test pattern ──
@@ -1058,10 +1596,96 @@ fn test_template_dollar_without_brace() {
     );
 }
 
-// `${` at start of template isn't handled correctly; this documents current behavior
 #[test]
-fn test_template_consecutive_interpolations_error() {
-    assert!(Lexer::new("`${a}${b}`").tokenize().is_err());
+fn test_template_consecutive_interpolations() {
+    assert_eq!(
+        tokenize("`${a}${b}`"),
+        vec![
+            Token::Backtick,
+            Token::InterpolationStart,
+            Token::Identifier("a".into()),
+            Token::InterpolationEnd,
+            Token::InterpolationStart,
+            Token::Identifier("b".into()),
+            Token::InterpolationEnd,
+            Token::Backtick,
+        ]
+    );
+}
+
+#[test]
+fn test_template_escapes() {
+    assert_eq!(
+        tokenize(r"`\` costs \${x} \$y\n`"),
+        vec![
+            Token::Backtick,
+            Token::StringLiteral("` costs ${x} $y\n".into()),
+            Token::Backtick,
+        ]
+    );
+    match tokenize_err(r"`\q`") {
+        LexerError::InvalidEscape(_, text) => assert_eq!(text, r"\q"),
+        other => panic!("Expected InvalidEscape, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_template_nested_in_interpolation() {
+    assert_eq!(
+        tokenize("`a ${`b ${c}`} d`"),
+        vec![
+            Token::Backtick,
+            Token::StringLiteral("a ".into()),
+            Token::InterpolationStart,
+            Token::Backtick,
+            Token::StringLiteral("b ".into()),
+            Token::InterpolationStart,
+            Token::Identifier("c".into()),
+            Token::InterpolationEnd,
+            Token::Backtick,
+            Token::InterpolationEnd,
+            Token::StringLiteral(" d".into()),
+            Token::Backtick,
+        ]
+    );
+}
+
+#[test]
+fn test_template_format_spec() {
+    assert_eq!(
+        tokenize("`${price:.2} ${n:>5}`"),
+        vec![
+            Token::Backtick,
+            Token::InterpolationStart,
+            Token::Identifier("price".into()),
+            Token::FormatSpec(".2".into()),
+            Token::InterpolationEnd,
+            Token::StringLiteral(" ".into()),
+            Token::InterpolationStart,
+            Token::Identifier("n".into()),
+            Token::FormatSpec(">5".into()),
+            Token::InterpolationEnd,
+            Token::Backtick,
+        ]
+    );
+    assert!(matches!(
+        tokenize_err("`${x:>5`"),
+        LexerError::UnClosedString(..)
+    ));
+}
+
+#[test]
+fn test_colon_outside_interpolation_is_a_token() {
+    assert_eq!(
+        tokenize("a: `b`"),
+        vec![
+            Token::Identifier("a".into()),
+            Token::Colon,
+            Token::Backtick,
+            Token::StringLiteral("b".into()),
+            Token::Backtick,
+        ]
+    );
 }
 
 #[test]
@@ -1103,6 +1727,473 @@ fn test_large_indent_multiline() {
             Token::Newline,
             Token::Indent,
             Token::Identifier("d".into()),
+            Token::Dedent,
+            Token::Dedent,
+            Token::Dedent,
+        ]
+    );
+}
+
+#[test]
+fn test_unknown_char_is_error() {
+    assert!(matches!(
+        tokenize_err("a $ b"),
+        LexerError::UnexpectedToken(_, '$')
+    ));
+}
+
+#[test]
+fn test_recovering_skips_bad_characters() {
+    let (tokens, errors) = Lexer::new("a $ b\nc ; d").tokenize_recovering();
+    let tokens: Vec<Token> = tokens.map(|x| x.token).collect();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Identifier("a".into()),
+            Token::Identifier("b".into()),
+            Token::Newline,
+            Token::Identifier("c".into()),
+            Token::Identifier("d".into()),
+        ]
+    );
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(errors[0], LexerError::UnexpectedToken(_, '$')));
+    assert!(matches!(errors[1], LexerError::UnexpectedToken(_, ';')));
+}
+
+#[test]
+fn test_recovering_skips_rest_of_line_after_bad_literal() {
+    let (tokens, errors) = Lexer::new("a = \"x\\q y\"\nb = 012\nc").tokenize_recovering();
+    let tokens: Vec<Token> = tokens.map(|x| x.token).collect();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Identifier("a".into()),
+            Token::Assign,
+            Token::Newline,
+            Token::Identifier("b".into()),
+            Token::Assign,
+            Token::Newline,
+            Token::Identifier("c".into()),
+        ]
+    );
+    assert!(matches!(errors[0], LexerError::InvalidEscape(..)));
+    assert!(matches!(errors[1], LexerError::CannotStartZeroNumber(..)));
+}
+
+// ── Azerbaijani keywords ──
+
+fn tokenize_az(input: &str) -> Vec<Token> {
+    Lexer::with_keywords(input, FileId::default(), KeywordSet::Az)
+        .tokenize()
+        .unwrap()
+        .map(|x| x.token)
+        .collect()
+}
+
+#[test]
+fn test_every_keyword_has_an_azerbaijani_spelling() {
+    assert_eq!(KEYWORDS.len(), AZ_KEYWORDS.len());
+    for (en, az) in KEYWORDS.iter().zip(AZ_KEYWORDS) {
+        let token = KeywordSet::En.tokenize(en);
+        assert_eq!(KeywordSet::Az.tokenize(az), token, "{en} / {az}");
+        assert_eq!(KeywordSet::Az.spelling(&token), Some(*az));
+        assert_eq!(KeywordSet::En.spelling(&token), Some(*en));
+    }
+}
+
+#[test]
+fn test_azerbaijani_keywords() {
+    assert_eq!(
+        tokenize_az("sabit tam a = doğru"),
+        vec![
+            Token::ConstantDecl,
+            Token::IntegerType,
+            Token::Identifier("a".into()),
+            Token::Assign,
+            Token::True,
+        ]
+    );
+    // İngiliscə sözlər Azərbaycan dəstində adi addır.
+    assert_eq!(
+        tokenize_az("const"),
+        vec![Token::Identifier("const".into())]
+    );
+    assert_eq!(tokenize("sabit"), vec![Token::Identifier("sabit".into())]);
+}
+
+#[test]
+fn test_azerbaijani_multi_word_keywords() {
+    assert_eq!(
+        tokenize_az("nə qədər ki a və ya b və c"),
+        vec![
+            Token::While,
+            Token::Identifier("a".into()),
+            Token::Or,
+            Token::Identifier("b".into()),
+            Token::And,
+            Token::Identifier("c".into()),
+        ]
+    );
+    assert_eq!(tokenize_az("əks halda"), vec![Token::Else]);
+    assert_eq!(tokenize_az("yoxsa əgər"), vec![Token::ElseIf]);
+    // Növbəti söz yalnız başlanğıcı uyğun gəlirsə ayrıca ad olaraq qalır.
+    assert_eq!(
+        tokenize_az("a və yaş"),
+        vec![
+            Token::Identifier("a".into()),
+            Token::And,
+            Token::Identifier("yaş".into()),
+        ]
+    );
+    assert_eq!(tokenize_az("əks"), vec![Token::Identifier("əks".into())]);
+}
+
+#[test]
+fn test_multi_word_keyword_span_covers_every_word() {
+    let tokens: Vec<_> = Lexer::with_keywords("əks halda", FileId::default(), KeywordSet::Az)
+        .tokenize()
+        .unwrap()
+        .collect();
+    assert_eq!(tokens[0].span.start, 0);
+    assert_eq!(tokens[0].span.end as usize, "əks halda".len());
+}
+
+#[test]
+fn test_keyword_pragma_selects_set() {
+    assert_eq!(
+        tokenize("# keywords: az\nəgər"),
+        vec![Token::Newline, Token::Conditional]
+    );
+    // Direktiv layihənin dəstindən üstündür.
+    assert_eq!(
+        tokenize_az("# keywords: en\nif"),
+        vec![Token::Newline, Token::Conditional]
+    );
+    // Koddan sonra gələn şərh direktiv sayılmır.
+    assert_eq!(
+        tokenize("a\n# keywords: az\nəgər"),
+        vec![
+            Token::Identifier("a".into()),
+            Token::Newline,
+            Token::Newline,
+            Token::Identifier("əgər".into()),
+        ]
+    );
+}
+
+#[test]
+fn test_keyword_pragma_position() {
+    let source = "\n#  keywords:   e \n";
+    let (name, range) = pragma(source).unwrap();
+    assert_eq!(name, "e");
+    assert_eq!(&source[range], "e");
+    assert_eq!(pragma("# başqa şərh\n# keywords: az").unwrap().0, "az");
+    assert_eq!(pragma("exit(0)\n# keywords: az"), None);
+}
+
+#[test]
+fn test_unknown_keyword_set_is_error() {
+    match tokenize_err("# keywords: fr\nexit(0)") {
+        LexerError::UnknownKeywordSet(span, name) => {
+            assert_eq!(name, "fr");
+            assert_eq!((span.start, span.end), (12, 14));
+        }
+        other => panic!("UnknownKeywordSet gözlənilirdi, {other:?} alındı"),
+    }
+}
+
+#[test]
+fn test_convert_keywords_round_trip() {
+    let english = "func f(const int x): int\n    return x\nif a && b or c\n    exit(f(1))\nelse\n    exit(0)\n";
+    let file = FileId::default();
+    let azerbaijani = convert_keywords(english, file, KeywordSet::En, KeywordSet::Az).unwrap();
+    assert_eq!(
+        azerbaijani,
+        "# keywords: az\nfunksiya f(sabit tam x): tam\n    qaytar x\nəgər a && b və ya c\n    çıx(f(1))\nəks halda\n    çıx(0)\n"
+    );
+    let back = convert_keywords(&azerbaijani, file, KeywordSet::En, KeywordSet::En).unwrap();
+    assert_eq!(back, format!("# keywords: en\n{}", english));
+    // Layihənin dəsti hədəflə eynidirsə direktiv əlavə olunmur.
+    assert_eq!(
+        convert_keywords("sabit tam a = 1\n", file, KeywordSet::Az, KeywordSet::Az).unwrap(),
+        "sabit tam a = 1\n"
+    );
+}
+
+#[test]
+fn test_convert_keywords_refuses_identifier_that_becomes_keyword() {
+    let file = FileId::default();
+    let source = "const int son = 1\nexit(son)\n";
+    match convert_keywords(source, file, KeywordSet::En, KeywordSet::Az) {
+        Err(LexerError::KeywordCollision(span, name, set)) => {
+            assert_eq!(name, "son");
+            assert_eq!(set, "az");
+            assert_eq!((span.start, span.end), (10, 13));
+        }
+        other => panic!("KeywordCollision gözlənilirdi, {other:?} alındı"),
+    }
+    // Geri çevirmədə də eyni yoxlama: `az` faylındakı `if` adı.
+    assert!(matches!(
+        convert_keywords("sabit tam if = 1\n", file, KeywordSet::Az, KeywordSet::En),
+        Err(LexerError::KeywordCollision(..))
+    ));
+
+    let renamed = "const int sonuncu = 1\nexit(sonuncu)\n";
+    let azerbaijani = convert_keywords(renamed, file, KeywordSet::En, KeywordSet::Az).unwrap();
+    let back = convert_keywords(&azerbaijani, file, KeywordSet::En, KeywordSet::En).unwrap();
+    assert_eq!(back, format!("# keywords: en\n{renamed}"));
+}
+
+// ── Unicode identifiers ──
+
+#[test]
+fn test_unicode_identifiers() {
+    assert_eq!(
+        tokenize("ağac_1 ölçü Şəki"),
+        vec![
+            Token::Identifier("ağac_1".into()),
+            Token::Identifier("ölçü".into()),
+            Token::Identifier("Şəki".into()),
+        ]
+    );
+    assert_eq!(tokenize("кот"), vec![Token::Identifier("кот".into())]);
+}
+
+#[test]
+fn test_identifier_cannot_start_with_non_xid_start() {
+    // Ərəb-hind rəqəmi adın davamında ola bilər, əvvəlində yox.
+    assert_eq!(tokenize("a٣"), vec![Token::Identifier("a٣".into())]);
+    assert!(matches!(
+        tokenize_err("٣a"),
+        LexerError::UnexpectedToken(_, '٣')
+    ));
+}
+
+#[test]
+fn test_identifiers_are_nfc_normalized() {
+    let composed = "\u{00F6}l\u{00E7}\u{00FC}";
+    let decomposed = "o\u{0308}lc\u{0327}u\u{0308}";
+    assert_eq!(
+        tokenize(decomposed),
+        vec![Token::Identifier(composed.into())]
+    );
+    // Normallaşdırmadan sonra açar sözlər də tanınır.
+    let az = Lexer::with_keywords("dog\u{0306}ru", FileId::default(), KeywordSet::Az)
+        .tokenize()
+        .unwrap()
+        .map(|x| x.token)
+        .collect::<Vec<_>>();
+    assert_eq!(az, vec![Token::True]);
+}
+
+#[test]
+fn test_invisible_character_in_code_is_error() {
+    match tokenize_err("a\u{200B} = 1") {
+        LexerError::InvisibleCharacter(span, '\u{200B}') => {
+            assert_eq!((span.start, span.end), (1, 4));
+        }
+        other => panic!("InvisibleCharacter gözlənilirdi, {other:?} alındı"),
+    }
+}
+
+#[test]
+fn test_zero_width_joiner_inside_identifier_is_error() {
+    match tokenize_err("ab\u{200C}c = 1") {
+        LexerError::InvisibleCharacter(span, '\u{200C}') => {
+            assert_eq!((span.start, span.end), (2, 5));
+        }
+        other => panic!("InvisibleCharacter gözlənilirdi, {other:?} alındı"),
+    }
+    assert!(matches!(
+        tokenize_err("a\u{200D}"),
+        LexerError::InvisibleCharacter(_, '\u{200D}')
+    ));
+}
+
+#[test]
+fn test_bidi_control_in_string_and_comment_is_error() {
+    assert!(matches!(
+        tokenize_err("\"a\u{202E}b\""),
+        LexerError::InvisibleCharacter(_, '\u{202E}')
+    ));
+    assert!(matches!(
+        tokenize_err("# şərh \u{2066}\na"),
+        LexerError::InvisibleCharacter(_, '\u{2066}')
+    ));
+    assert!(matches!(
+        tokenize_err("/* \u{202D} */ a"),
+        LexerError::InvisibleCharacter(_, '\u{202D}')
+    ));
+    // Sıfır enli birləşdirici yazıda mətnin bir hissəsidir.
+    assert_eq!(
+        tokenize("\"a\u{200D}b\""),
+        vec![Token::StringLiteral("a\u{200D}b".into())]
+    );
+}
+
+#[test]
+fn test_byte_order_mark_is_skipped() {
+    let tokens = tokenize_spanned("\u{FEFF}a");
+    assert_eq!(tokens[0].0, Token::Identifier("a".into()));
+    assert_eq!((tokens[0].1.start, tokens[0].1.end), (3, 4));
+}
+
+#[test]
+fn test_mixed_script_identifier_is_error() {
+    // İkinci hərf kiril `а`-dır.
+    match tokenize_err("p\u{0430}rol") {
+        LexerError::MixedScriptIdentifier(span, name) => {
+            assert_eq!(name, "p\u{0430}rol");
+            assert_eq!((span.start, span.end), (0, 6));
+        }
+        other => panic!("MixedScriptIdentifier gözlənilirdi, {other:?} alındı"),
+    }
+}
+
+#[test]
+fn test_confusable_character_is_error() {
+    assert!(matches!(
+        tokenize_err("a \u{2212} 1"),
+        LexerError::ConfusableCharacter(_, '\u{2212}', '-')
+    ));
+    assert!(matches!(
+        tokenize_err("a\u{00A0}= 1"),
+        LexerError::ConfusableCharacter(_, '\u{00A0}', ' ')
+    ));
+    let message = tokenize_err("“").message();
+    assert!(message.contains("U+201C"), "{message}");
+}
+
+#[test]
+fn test_recovering_continues_after_unicode_errors() {
+    let (tokens, errors) =
+        Lexer::new("a\u{200B} = 1\nb \u{2212} 2\np\u{0430}rol").tokenize_recovering();
+    let tokens: Vec<Token> = tokens.map(|x| x.token).collect();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Identifier("a".into()),
+            Token::Assign,
+            Token::Number(1),
+            Token::Newline,
+            Token::Identifier("b".into()),
+            Token::Number(2),
+            Token::Newline,
+        ]
+    );
+    assert_eq!(errors.len(), 3);
+}
+
+// ── Lossless token stream ──
+
+fn lossless(input: &str) -> Vec<LosslessToken<'_>> {
+    let (tokens, errors) = Lexer::new(input).tokenize_lossless();
+    assert!(errors.is_empty(), "{errors:?}");
+    tokens
+}
+
+fn round_trip(input: &str) -> String {
+    let (tokens, _) = Lexer::new(input).tokenize_lossless();
+    tokens.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn test_lossless_round_trips_input() {
+    let sources = [
+        "",
+        "\u{FEFF}const a = 1",
+        "# keywords: az\nfunksiya f():\n    qaytar 1\n",
+        "func f():   # şərh\n\n    /* blok\n şərh */\n    return  `a ${b:>5} \\` c`\n\n\n",
+        "/// sənəd\nconst str s = \"\"\"\nçox\n  sətir\"\"\"\t\n",
+        "if x:\n    if y:\n        z\n// son\n",
+        "a \u{2212} 1 $ ; \"bağlanmayan\nb",
+    ];
+    for source in sources {
+        assert_eq!(round_trip(source), source);
+    }
+}
+
+#[test]
+fn test_lossless_round_trips_examples() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../../examples");
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().is_some_and(|ext| ext == "az") {
+            let source = std::fs::read_to_string(&path).unwrap();
+            assert_eq!(round_trip(&source), source, "{}", path.display());
+        }
+    }
+}
+
+#[test]
+fn test_lossless_tokens_match_tokenize() {
+    let source = "func f(): # şərh\n    /* a */ return 1\n\n// son\n";
+    let tokens: Vec<Token> = lossless(source)
+        .into_iter()
+        .map(|x| x.token)
+        .filter(|token| *token != Token::Eof)
+        .collect();
+    assert_eq!(tokens, tokenize(source));
+}
+
+#[test]
+fn test_lossless_attaches_leading_trivia() {
+    let tokens = lossless("a  # şərh\n    b /* c */");
+    let kinds = |token: &LosslessToken| -> Vec<(TriviaKind, String)> {
+        token
+            .leading
+            .iter()
+            .map(|trivia| (trivia.kind, trivia.text.to_string()))
+            .collect()
+    };
+
+    assert_eq!(tokens[0].text, "a");
+    assert_eq!(tokens[1].token, Token::Newline);
+    assert_eq!(
+        kinds(&tokens[1]),
+        vec![
+            (TriviaKind::Whitespace, "  ".to_string()),
+            (TriviaKind::Comment, "# şərh".to_string()),
+        ]
+    );
+    assert_eq!(tokens[2].token, Token::Indent);
+    assert_eq!(tokens[2].text, "");
+    assert_eq!(
+        kinds(&tokens[2]),
+        vec![(TriviaKind::Whitespace, "    ".to_string())]
+    );
+    assert_eq!(tokens[3].text, "b");
+
+    // Faylın sonunda bağlanan blokun `Dedent`-i şərhdən sonra gəlir.
+    let dedent = &tokens[4];
+    assert_eq!(dedent.token, Token::Dedent);
+    assert_eq!(
+        kinds(dedent),
+        vec![
+            (TriviaKind::Whitespace, " ".to_string()),
+            (TriviaKind::Comment, "/* c */".to_string()),
         ]
     );
+    let comment = &dedent.leading[1];
+    assert_eq!((comment.span.start, comment.span.end), (18, 25));
+
+    let eof = &tokens[5];
+    assert_eq!(eof.token, Token::Eof);
+    assert!(eof.leading.is_empty());
+}
+
+#[test]
+fn test_lossless_keeps_skipped_text() {
+    let (tokens, errors) = Lexer::new("a $ b").tokenize_lossless();
+    assert_eq!(errors.len(), 1);
+    let b = &tokens[1];
+    assert_eq!(b.text, "b");
+    let skipped: Vec<_> = b
+        .leading
+        .iter()
+        .filter(|trivia| trivia.kind == TriviaKind::Skipped)
+        .map(|trivia| trivia.text)
+        .collect();
+    assert_eq!(skipped, ["$"]);
 }
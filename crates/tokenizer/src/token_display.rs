@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::tokens::Token;
+use crate::tokens::{NumberSuffix, Token};
 impl fmt::Display for Token {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -8,8 +8,10 @@ impl fmt::Display for Token {
             Token::While => write!(f, "while"),
             Token::Identifier(name) => write!(f, "{}", name),
             Token::Number(num) => write!(f, "{}", num),
+            Token::TypedNumber(num, suffix) => write!(f, "{}{}", num, suffix),
             Token::Float(num) => write!(f, "{}", num),
             Token::StringLiteral(string) => write!(f, "{}", string),
+            Token::CharLiteral(ch) => write!(f, "'{}'", ch),
             Token::Operator(op) => write!(f, "{}", op),
             Token::Op(op) => write!(f, "{}", op),
             Token::LParen => write!(f, "("),
@@ -23,6 +25,7 @@ impl fmt::Display for Token {
             Token::Backtick => write!(f, "`"),
             Token::InterpolationStart => write!(f, "${{"),
             Token::InterpolationEnd => write!(f, "}}"),
+            Token::FormatSpec(spec) => write!(f, ":{}", spec),
             Token::Array => write!(f, "array"),
             Token::Object => write!(f, "object"),
             Token::Else => write!(f, "else"),
@@ -92,8 +95,19 @@ impl fmt::Display for Token {
             Token::Less => write!(f, "<"),
             Token::LessEqual => write!(f, "<="),
             Token::Assign => write!(f, "="),
+            Token::AddAssign => write!(f, "+="),
+            Token::SubtractAssign => write!(f, "-="),
+            Token::MultiplyAssign => write!(f, "*="),
+            Token::DivideAssign => write!(f, "/="),
+            Token::ModuloAssign => write!(f, "%="),
             Token::Not => write!(f, "!"),
             Token::Exit => write!(f, "exit"),
         }
     }
 }
+
+impl fmt::Display for NumberSuffix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
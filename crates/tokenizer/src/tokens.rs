@@ -1,3 +1,6 @@
+/// Adlar və yazılar daxili cədvələ salınır; qısa mətnlər isə `Atom`-un özündə saxlanır.
+pub use string_cache::DefaultAtom as Atom;
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum Token {
     Exit,
@@ -13,6 +16,8 @@ pub enum Token {
     Backtick,
     InterpolationStart,
     InterpolationEnd,
+    /// `${price:.2}` içində `:`-dan sonrakı mətn, bax: `parser::template`.
+    FormatSpec(String),
     Array,
     Object,
     Else,
@@ -32,12 +37,14 @@ pub enum Token {
     True,
     False,
     Enum,
-    Identifier(String),
+    Identifier(Atom),
     Number(i64),
+    TypedNumber(i64, NumberSuffix),
     Indent,
     Dedent,
     ElseIf,
-    StringLiteral(String),
+    StringLiteral(Atom),
+    CharLiteral(char),
     Operator(String),
     Op(char),
     LParen,
@@ -75,6 +82,11 @@ pub enum Token {
     BigIntegerType,
     LowIntegerType,
     Assign,
+    AddAssign,
+    SubtractAssign,
+    MultiplyAssign,
+    DivideAssign,
+    ModuloAssign,
     /* Operations */
     Add,
     Not,
@@ -89,3 +101,39 @@ pub enum Token {
     Less,
     LessEqual,
 }
+
+/// Ədəd literalının tipini təyin edən sonluq: `7tinyint`, `1_000bigint`, `0xFFnatural`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum NumberSuffix {
+    TinyInt,
+    BigInt,
+    Natural,
+}
+
+impl NumberSuffix {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            NumberSuffix::TinyInt => "tinyint",
+            NumberSuffix::BigInt => "bigint",
+            NumberSuffix::Natural => "natural",
+        }
+    }
+
+    /// Tipin saxlaya biləcəyi ən böyük dəyər.
+    pub const fn max(self) -> u64 {
+        match self {
+            NumberSuffix::TinyInt => i8::MAX as u64,
+            NumberSuffix::BigInt => i64::MAX as u64,
+            NumberSuffix::Natural => u32::MAX as u64,
+        }
+    }
+
+    /// Literalın ala biləcəyi ən böyük mütləq dəyər. İşarəli tiplərdə mənfi tərəf bir
+    /// vahid genişdir (`-128tinyint`); mənfi işarəni parser tətbiq edir.
+    pub const fn magnitude(self) -> u64 {
+        match self {
+            NumberSuffix::Natural => self.max(),
+            _ => self.max() + 1,
+        }
+    }
+}
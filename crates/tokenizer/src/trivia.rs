@@ -0,0 +1,83 @@
+use std::fmt;
+
+use crate::{iterator::SourceSpan, source::FileId, tokens::Token};
+
+/// Parserin görmədiyi mətnin növü.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriviaKind {
+    /// Boşluq, tab, `\r` və BOM.
+    Whitespace,
+    /// `#`, `//` və `/* */` şərhləri. `///` sənəd şərhi token olduğu üçün bura düşmür.
+    Comment,
+    /// Xəta səbəbindən lexerin ötürdüyü mətn.
+    Skipped,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trivia<'a> {
+    pub kind: TriviaKind,
+    pub text: &'a str,
+    pub span: SourceSpan,
+}
+
+/// Mənbə mətni ilə birlikdə token. `leading` tokendən əvvəlki trivia-dır,
+/// faylın sonundakı trivia isə sonuncu [`Token::Eof`] tokenindədir.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LosslessToken<'a> {
+    pub leading: Vec<Trivia<'a>>,
+    pub token: Token,
+    /// Tokenin mətni olduğu kimi; girinti tokenləri üçün boşdur.
+    pub text: &'a str,
+    pub span: SourceSpan,
+}
+
+/// Trivia və token mətnini ardıcıl yazır, beləliklə bütün tokenlərin
+/// birləşməsi giriş mətninin özüdür.
+impl fmt::Display for LosslessToken<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for trivia in &self.leading {
+            f.write_str(trivia.text)?;
+        }
+        f.write_str(self.text)
+    }
+}
+
+/// Tokenlərin arasında qalan mətni trivia hissələrinə bölür.
+pub(crate) fn split_trivia(gap: &str, file: FileId, offset: usize) -> Vec<Trivia<'_>> {
+    let mut trivia = Vec::new();
+    let mut rest = gap;
+    while !rest.is_empty() {
+        let (kind, length) = if rest.starts_with(is_space) {
+            let length = rest.find(|ch| !is_space(ch)).unwrap_or(rest.len());
+            (TriviaKind::Whitespace, length)
+        } else if let Some(body) = rest.strip_prefix("/*") {
+            let length = body.find("*/").map_or(rest.len(), |end| end + 4);
+            (TriviaKind::Comment, length)
+        } else if is_comment_start(rest) {
+            (TriviaKind::Comment, rest.find('\n').unwrap_or(rest.len()))
+        } else {
+            let length = rest
+                .char_indices()
+                .skip(1)
+                .find(|(i, ch)| is_space(*ch) || is_comment_start(&rest[*i..]))
+                .map_or(rest.len(), |(i, _)| i);
+            (TriviaKind::Skipped, length)
+        };
+        let start = offset + gap.len() - rest.len();
+        trivia.push(Trivia {
+            kind,
+            text: &rest[..length],
+            span: SourceSpan::new(file, start as u32, (start + length) as u32),
+        });
+        rest = &rest[length..];
+    }
+    trivia
+}
+
+fn is_space(ch: char) -> bool {
+    matches!(ch, ' ' | '\t' | '\r' | '\n' | '\u{FEFF}')
+}
+
+fn is_comment_start(text: &str) -> bool {
+    text.starts_with('#') || text.starts_with("//") || text.starts_with("/*")
+}
@@ -0,0 +1,94 @@
+use unicode_normalization::{IsNormalized, UnicodeNormalization, is_nfc_quick};
+
+/// Adın ilk simvolu: Unicode `XID_Start`. `_` ayrıca token olduğu üçün bura daxil deyil.
+pub fn is_ident_start(ch: char) -> bool {
+    unicode_ident::is_xid_start(ch)
+}
+
+/// Adın davamı: Unicode `XID_Continue`, o cümlədən rəqəmlər, `_` və birləşən işarələr.
+pub fn is_ident_continue(ch: char) -> bool {
+    unicode_ident::is_xid_continue(ch)
+}
+
+/// Adı NFC formasına salır ki, `ö` ilə `o` + `◌̈` eyni ad olsun.
+pub fn normalize(word: String) -> String {
+    match is_nfc_quick(word.chars()) {
+        IsNormalized::Yes => word,
+        _ => word.nfc().collect(),
+    }
+}
+
+/// Mətnin görünən sırasını dəyişən istiqamət simvolları. Kodu olduğundan fərqli
+/// göstərə bildikləri üçün yazı və şərhlərdə də qəbul edilmir.
+pub fn is_bidi_control(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+/// Kodda görünməyən simvollar: sıfır enli boşluqlar, yumşaq defis və istiqamət simvolları.
+pub fn is_invisible(ch: char) -> bool {
+    is_bidi_control(ch)
+        || matches!(
+            ch,
+            '\u{00AD}' | '\u{180E}' | '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}'
+        )
+}
+
+/// ASCII simvoluna bənzəyən, amma dildə mənası olmayan simvol üçün onun ASCII qarşılığı.
+pub fn confusable(ch: char) -> Option<char> {
+    let ascii = match ch {
+        '\u{00A0}' | '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{205F}' | '\u{3000}' => ' ',
+        '“' | '”' | '„' | '″' | '＂' => '"',
+        '‘' | '’' | '′' | '＇' => '\'',
+        '−' | '–' | '—' | '‐' | '‑' | '－' => '-',
+        '＋' => '+',
+        '×' | '＊' => '*',
+        '÷' | '∕' | '／' => '/',
+        '＝' => '=',
+        '＜' => '<',
+        '＞' => '>',
+        '（' => '(',
+        '）' => ')',
+        '［' => '[',
+        '］' => ']',
+        '｛' => '{',
+        '｝' => '}',
+        '，' | '‚' => ',',
+        '：' | '∶' => ':',
+        '．' => '.',
+        '！' => '!',
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+}
+
+/// Bir-birinə bənzəyən hərfləri olan əlifbalar. Digər simvollar yoxlanmır.
+fn script(ch: char) -> Option<Script> {
+    match ch {
+        'a'..='z' | 'A'..='Z' | '\u{00C0}'..='\u{024F}' | '\u{1E00}'..='\u{1EFF}' => {
+            (ch != '×' && ch != '÷').then_some(Script::Latin)
+        }
+        '\u{0370}'..='\u{03FF}' | '\u{1F00}'..='\u{1FFF}' => Some(Script::Greek),
+        '\u{0400}'..='\u{052F}' => Some(Script::Cyrillic),
+        _ => None,
+    }
+}
+
+/// Ad latın, yunan və kiril hərflərindən birdən çoxunu qarışdırırsa `true`,
+/// məsələn kiril `а` ilə yazılmış `pаrol`.
+pub fn is_mixed_script(word: &str) -> bool {
+    let mut scripts = word.chars().filter_map(script);
+    let Some(first) = scripts.next() else {
+        return false;
+    };
+    scripts.any(|other| other != first)
+}
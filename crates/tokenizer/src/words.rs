@@ -1,44 +1,175 @@
-use crate::tokens::Token;
-
-pub fn tokenize_word(word: &str) -> Token {
-    match word {
-        "while" => Token::While,
-        "var" => Token::MutableDecl,
-        "const" => Token::ConstantDecl,
-        "if" => Token::Conditional,
-        "elif" => Token::ElseIf,
-        "else" => Token::Else,
-        "func" => Token::FunctionDef,
-        "callable" => Token::FnType,
-        "list" => Token::Array,
-        "object" => Token::Object,
-        "self" => Token::This,
-        "match" => Token::Match,
-        "break" => Token::Break,
-        "continue" => Token::Continue,
-        "loop" => Token::Loop,
-        "end" => Token::End,
-        "return" => Token::Return,
-        "drop" => Token::Drop,
-        "true" => Token::True,
-        "false" => Token::False,
-        "in" => Token::In,
-        "and" => Token::And,
-        "or" => Token::Or,
-        "int" => Token::IntegerType,
-        "any" => Token::AnyType,
-        "str" => Token::StringType,
-        "natural" => Token::NaturalType,
-        "char" => Token::CharType,
-        "bigint" => Token::BigIntegerType,
-        "tinyint" => Token::LowIntegerType,
-        "float" => Token::FloatType,
-        "bool" => Token::BoolType,
-        "void" => Token::Void,
-        "enum" => Token::Enum,
-        "method" => Token::Method,
-        "import" => Token::Import,
-        "type" => Token::Type,
-        other => Token::Identifier(other.to_string()),
+use std::{ops::Range, sync::LazyLock};
+
+use crate::tokens::{Atom, Token};
+
+/// Açar sözlərin yazılış dəsti. Hər iki dəst eyni tokenlərə çevrilir.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordSet {
+    #[default]
+    En,
+    /// `əgər`, `əks halda`, `funksiya` kimi Azərbaycan dilindəki sözlər.
+    Az,
+}
+
+macro_rules! keywords {
+    ($($word:literal, $az:literal => $token:path),* $(,)?) => {
+        /// Bütün ingiliscə açar sözlər, cədvəldəki sıra ilə.
+        pub const KEYWORDS: &[&str] = &[$($word),*];
+
+        /// Azərbaycan dilindəki açar sözlər; bəziləri bir neçə sözdən ibarətdir.
+        pub const AZ_KEYWORDS: &[&str] = &[$($az),*];
+
+        pub fn tokenize_word(word: &str) -> Token {
+            match word {
+                $($word => $token,)*
+                other => Token::Identifier(Atom::from(other)),
+            }
+        }
+
+        fn tokenize_az_word(word: &str) -> Token {
+            match word {
+                $($az => $token,)*
+                other => Token::Identifier(Atom::from(other)),
+            }
+        }
+
+        /// Token açar sözdən yaranıbsa `true`.
+        pub fn is_keyword(token: &Token) -> bool {
+            matches!(token, $($token)|*)
+        }
+
+        impl KeywordSet {
+            /// Açar söz tokeninin bu dəstdəki yazılışı.
+            pub fn spelling(self, token: &Token) -> Option<&'static str> {
+                match (self, token) {
+                    $(
+                        (KeywordSet::En, $token) => Some($word),
+                        (KeywordSet::Az, $token) => Some($az),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+keywords! {
+    "while", "nə qədər ki" => Token::While,
+    "var", "dəyişən" => Token::MutableDecl,
+    "const", "sabit" => Token::ConstantDecl,
+    "if", "əgər" => Token::Conditional,
+    "elif", "yoxsa əgər" => Token::ElseIf,
+    "else", "əks halda" => Token::Else,
+    "func", "funksiya" => Token::FunctionDef,
+    "callable", "çağırılan" => Token::FnType,
+    "list", "siyahı" => Token::Array,
+    "object", "obyekt" => Token::Object,
+    "self", "özü" => Token::This,
+    "match", "uyğun" => Token::Match,
+    "break", "dayan" => Token::Break,
+    "continue", "davam" => Token::Continue,
+    "loop", "dövr" => Token::Loop,
+    "end", "son" => Token::End,
+    "return", "qaytar" => Token::Return,
+    "exit", "çıx" => Token::Exit,
+    "drop", "burax" => Token::Drop,
+    "true", "doğru" => Token::True,
+    "false", "yanlış" => Token::False,
+    "in", "içində" => Token::In,
+    "and", "və" => Token::And,
+    "or", "və ya" => Token::Or,
+    "int", "tam" => Token::IntegerType,
+    "any", "istənilən" => Token::AnyType,
+    "str", "mətn" => Token::StringType,
+    "natural", "təbii" => Token::NaturalType,
+    "char", "simvol" => Token::CharType,
+    "bigint", "böyüktam" => Token::BigIntegerType,
+    "tinyint", "kiçiktam" => Token::LowIntegerType,
+    "float", "kəsr" => Token::FloatType,
+    "bool", "məntiqi" => Token::BoolType,
+    "void", "boş" => Token::Void,
+    "enum", "sadalama" => Token::Enum,
+    "method", "metod" => Token::Method,
+    "import", "idxal" => Token::Import,
+    "type", "tip" => Token::Type,
+}
+
+/// Hər dəstin çoxsözlü açar sözləri, [`KeywordSet::ALL`] sırası ilə. Hər sözdən sonra
+/// bütün cədvələ baxmamaq üçün əvvəlcədən seçilir.
+static MULTI_WORD: LazyLock<[Vec<&'static str>; 2]> = LazyLock::new(|| {
+    KeywordSet::ALL.map(|set| {
+        set.words()
+            .iter()
+            .copied()
+            .filter(|word| word.contains(' '))
+            .collect()
+    })
+});
+
+/// Dəsti faylın əvvəlindəki şərhlə seçən direktiv: `# keywords: az`.
+pub const PRAGMA: &str = "keywords:";
+
+impl KeywordSet {
+    pub const ALL: [KeywordSet; 2] = [KeywordSet::En, KeywordSet::Az];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            KeywordSet::En => "en",
+            KeywordSet::Az => "az",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<KeywordSet> {
+        KeywordSet::ALL.into_iter().find(|set| set.name() == name)
+    }
+
+    pub fn words(self) -> &'static [&'static str] {
+        match self {
+            KeywordSet::En => KEYWORDS,
+            KeywordSet::Az => AZ_KEYWORDS,
+        }
+    }
+
+    /// Sözü bu dəstə görə tokenə çevirir; açar söz deyilsə ad olur.
+    pub fn tokenize(self, word: &str) -> Token {
+        match self {
+            KeywordSet::En => tokenize_word(word),
+            KeywordSet::Az => tokenize_az_word(word),
+        }
+    }
+
+    /// `first` ilə başlayan çoxsözlü açar sözlərin qalan hissəsi (`" halda"`),
+    /// ən uzunu birinci.
+    pub fn continuations(self, first: &str) -> Vec<&'static str> {
+        let mut rests: Vec<&'static str> = MULTI_WORD[self as usize]
+            .iter()
+            .filter_map(|word| word.strip_prefix(first))
+            .filter(|rest| rest.starts_with(' '))
+            .collect();
+        rests.sort_by_key(|rest| std::cmp::Reverse(rest.len()));
+        rests
+    }
+}
+
+/// Faylın əvvəlindəki boş və `#` şərh sətirləri arasında `# keywords: <ad>` direktivi.
+/// Dəstin adını və onun bayt aralığını qaytarır.
+pub fn pragma(source: &str) -> Option<(&str, Range<usize>)> {
+    let mut offset = 0;
+    for line in source.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let text = line.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let comment = text.strip_prefix('#')?;
+        let Some(rest) = comment.trim_start().strip_prefix(PRAGMA) else {
+            continue;
+        };
+        let name = rest.trim();
+        let rest_start = start + line.trim_end().len() - rest.len();
+        let name_start = rest_start + rest.len() - rest.trim_start().len();
+        return Some((name, name_start..name_start + name.len()));
     }
+    None
 }
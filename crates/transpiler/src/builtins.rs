@@ -0,0 +1,9 @@
+/// Validatorun tanıdığı daxili funksiyaların runtime-dakı (`env.s`) adları.
+/// `len` burada yoxdur, o yerində açılır (bax: `list.rs`).
+pub(crate) fn runtime_symbol(name: &str) -> Option<&'static str> {
+    match name {
+        "args" => Some("az_args"),
+        "env" => Some("az_env"),
+        _ => None,
+    }
+}
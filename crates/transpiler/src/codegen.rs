@@ -0,0 +1,348 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+};
+
+use parser::shared_ast::Type;
+use validator::ast::{Ast, AstKind, Function, Program};
+
+use crate::{
+    OverflowMode,
+    builtins::runtime_symbol,
+    errors::TranspileError,
+    mangle::mangle,
+    types::{QbeType, qbe_type},
+};
+
+#[derive(Debug, Clone)]
+pub(crate) struct Local {
+    pub slot: String,
+    pub typ: Type,
+}
+
+#[derive(Debug)]
+pub struct Codegen {
+    pub(crate) overflow: OverflowMode,
+    module: String,
+    externals: HashSet<String>,
+    data: String,
+    allocs: String,
+    body: String,
+    temp_count: usize,
+    label_count: usize,
+    data_count: usize,
+    terminated: bool,
+    top_level: bool,
+    pub(crate) locals: HashMap<String, Local>,
+    pub(crate) globals: HashMap<String, Local>,
+    pub(crate) loops: Vec<(String, String)>,
+}
+
+impl Codegen {
+    pub fn new(module: &str, overflow: OverflowMode) -> Self {
+        Self {
+            overflow,
+            module: module.to_string(),
+            externals: HashSet::new(),
+            data: String::new(),
+            allocs: String::new(),
+            body: String::new(),
+            temp_count: 0,
+            label_count: 0,
+            data_count: 0,
+            terminated: false,
+            top_level: false,
+            locals: HashMap::new(),
+            globals: HashMap::new(),
+            loops: Vec::new(),
+        }
+    }
+
+    pub fn program(mut self, program: Program) -> Result<String, TranspileError> {
+        self.externals = program
+            .external_functions
+            .iter()
+            .map(|function| function.name.clone())
+            .collect();
+        self.collect_globals(&program.expressions);
+        let mut functions = String::new();
+        for function in &program.functions {
+            functions.push_str(&self.function(function)?);
+        }
+        let main = self.main_function(&program.expressions)?;
+        Ok(format!("{}{functions}{main}", self.data))
+    }
+
+    fn function(&mut self, function: &Function) -> Result<String, TranspileError> {
+        self.begin_function();
+        let mut params = Vec::new();
+        for param in &function.params {
+            let typ = qbe_type(&param.typ).ok_or_else(|| {
+                TranspileError::Unsupported(format!("void parameter '{}'", param.name))
+            })?;
+            let incoming = format!("%p.{}", params.len());
+            params.push(format!("{} {incoming}", typ.as_str()));
+            let slot = self.declare_local(param.name.as_ref(), param.typ.clone());
+            self.store(typ, &incoming, &slot);
+        }
+        for stmt in &function.body {
+            self.statement(stmt)?;
+        }
+        let return_type = qbe_type(&function.return_typ);
+        if !self.terminated {
+            match return_type {
+                Some(QbeType::Double) => self.terminate("ret d_0"),
+                Some(_) => self.terminate("ret 0"),
+                None => self.terminate("ret"),
+            }
+        }
+        let return_type = return_type.map(|t| format!("{} ", t.as_str()));
+        Ok(format!(
+            "export function {}${}({}) {{\n@start\n{}{}}}\n",
+            return_type.unwrap_or_default(),
+            self.symbol(&function.name),
+            params.join(", "),
+            self.allocs,
+            self.body,
+        ))
+    }
+
+    /// Yuxarı səviyyəli ifadələr proqramın `main` funksiyasını təşkil edir.
+    /// Onun qaytardığı dəyəri `_start` prosesin çıxış kodu kimi istifadə edir.
+    fn main_function(&mut self, body: &[Ast]) -> Result<String, TranspileError> {
+        self.begin_function();
+        self.top_level = true;
+        for stmt in body {
+            self.statement(stmt)?;
+        }
+        if !self.terminated {
+            self.terminate("ret 0");
+        }
+        Ok(format!(
+            "export function w $main() {{\n@start\n{}{}}}\n",
+            self.allocs, self.body
+        ))
+    }
+
+    fn begin_function(&mut self) {
+        self.allocs.clear();
+        self.body.clear();
+        self.locals.clear();
+        self.loops.clear();
+        self.terminated = false;
+        self.top_level = false;
+    }
+
+    pub(crate) fn statement(&mut self, stmt: &Ast) -> Result<(), TranspileError> {
+        match &stmt.kind {
+            AstKind::Decl {
+                name, typ, value, ..
+            } => {
+                let qbe = qbe_type(typ).ok_or_else(|| {
+                    TranspileError::Unsupported(format!("void variable '{name}'"))
+                })?;
+                let value = self.expr(value)?;
+                let slot = match self.globals.get(name) {
+                    Some(global) if self.top_level => global.slot.clone(),
+                    _ => self.declare_local(name, typ.clone()),
+                };
+                self.store(qbe, &value, &slot);
+            }
+            AstKind::Assignment { name, value } => {
+                let local = self
+                    .lookup(name)
+                    .ok_or_else(|| TranspileError::UnknownVariable(name.clone()))?;
+                let value = self.expr(value)?;
+                if let Some(qbe) = qbe_type(&local.typ) {
+                    self.store(qbe, &value, &local.slot);
+                }
+            }
+            AstKind::Exit(code) => {
+                let code = self.expr(code)?;
+                self.emit(format!("call $exit(w {code})"));
+                self.terminate("hlt");
+            }
+            AstKind::Condition { main, elif, other } => {
+                let end = self.new_label("endif");
+                for branch in std::iter::once(main).chain(elif) {
+                    let then = self.new_label("then");
+                    let next = self.new_label("else");
+                    let condition = self.expr(&branch.condition)?;
+                    self.terminate(format!("jnz {condition}, {then}, {next}"));
+                    self.label(&then);
+                    for stmt in &branch.body {
+                        self.statement(stmt)?;
+                    }
+                    self.terminate(format!("jmp {end}"));
+                    self.label(&next);
+                }
+                if let Some(other) = other {
+                    for stmt in &other.body {
+                        self.statement(stmt)?;
+                    }
+                }
+                self.terminate(format!("jmp {end}"));
+                self.label(&end);
+            }
+            AstKind::While { condition, body } => {
+                let head = self.new_label("while");
+                let inner = self.new_label("body");
+                let end = self.new_label("endwhile");
+                self.terminate(format!("jmp {head}"));
+                self.label(&head);
+                let condition = self.expr(condition)?;
+                self.terminate(format!("jnz {condition}, {inner}, {end}"));
+                self.label(&inner);
+                self.loops.push((head.clone(), end.clone()));
+                for stmt in body {
+                    self.statement(stmt)?;
+                }
+                self.loops.pop();
+                self.terminate(format!("jmp {head}"));
+                self.label(&end);
+            }
+            AstKind::Expr(expr) => {
+                self.expr(expr)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn lookup(&self, name: &str) -> Option<Local> {
+        self.locals
+            .get(name)
+            .or_else(|| self.globals.get(name))
+            .cloned()
+    }
+
+    /// Funksiyanın assembler adı. Runtime və `@link` funksiyaları olduğu kimi qalır,
+    /// istifadəçi funksiyaları isə modul adı ilə kodlanır ki, `exit` və ya `print`
+    /// adlı funksiya runtime ilə toqquşmasın.
+    pub(crate) fn symbol(&self, name: &str) -> String {
+        if let Some(runtime) = runtime_symbol(name) {
+            return runtime.to_string();
+        }
+        if self.externals.contains(name) {
+            return name.to_string();
+        }
+        mangle([self.module.as_str(), name])
+    }
+
+    fn declare_local(&mut self, name: &str, typ: Type) -> String {
+        self.temp_count += 1;
+        let slot = format!("%v.{}", self.temp_count);
+        let _ = writeln!(self.allocs, "    {slot} =l alloc8 8");
+        self.locals.insert(
+            name.to_string(),
+            Local {
+                slot: slot.clone(),
+                typ,
+            },
+        );
+        slot
+    }
+
+    /// Yuxarı səviyyəli dəyərlər funksiyalardan da görünür, ona görə onlar
+    /// funksiyalar yaradılmamışdan əvvəl `data` bölməsində yer alır.
+    fn collect_globals(&mut self, body: &[Ast]) {
+        for stmt in body {
+            match &stmt.kind {
+                AstKind::Decl { name, typ, .. } => {
+                    let Some(qbe) = qbe_type(typ) else { continue };
+                    if self.globals.contains_key(name) {
+                        continue;
+                    }
+                    let slot = format!("$g.{}", self.globals.len());
+                    let zero = match qbe {
+                        QbeType::Double => "d_0",
+                        _ => "0",
+                    };
+                    let _ = writeln!(self.data, "data {slot} = {{ {} {zero} }}", qbe.as_str());
+                    self.globals.insert(
+                        name.clone(),
+                        Local {
+                            slot,
+                            typ: typ.clone(),
+                        },
+                    );
+                }
+                AstKind::Condition { main, elif, other } => {
+                    for branch in std::iter::once(main).chain(elif) {
+                        self.collect_globals(&branch.body);
+                    }
+                    if let Some(other) = other {
+                        self.collect_globals(&other.body);
+                    }
+                }
+                AstKind::While { body, .. } => self.collect_globals(body),
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn store(&mut self, typ: QbeType, value: &str, slot: &str) {
+        self.emit(format!("store{} {value}, {slot}", typ.as_str()));
+    }
+
+    pub(crate) fn load(&mut self, typ: QbeType, slot: &str) -> String {
+        let temp = self.new_temp();
+        self.emit(format!("{temp} ={0} load{0} {slot}", typ.as_str()));
+        temp
+    }
+
+    /// Yazını `data` bölməsinə əlavə edir və onun ünvanını qaytarır.
+    pub(crate) fn string_data(&mut self, value: &str) -> String {
+        let name = format!("$str.{}", self.data_count);
+        self.data_count += 1;
+        let mut items = Vec::new();
+        let mut chunk = String::new();
+        for ch in value.chars() {
+            if !ch.is_control() && ch != '"' && ch != '\\' {
+                chunk.push(ch);
+                continue;
+            }
+            if !chunk.is_empty() {
+                items.push(format!("b \"{}\"", std::mem::take(&mut chunk)));
+            }
+            let mut buf = [0; 4];
+            for byte in ch.encode_utf8(&mut buf).bytes() {
+                items.push(format!("b {byte}"));
+            }
+        }
+        if !chunk.is_empty() {
+            items.push(format!("b \"{chunk}\""));
+        }
+        items.push("b 0".to_string());
+        let _ = writeln!(self.data, "data {name} = {{ {} }}", items.join(", "));
+        name
+    }
+
+    pub(crate) fn new_temp(&mut self) -> String {
+        self.temp_count += 1;
+        format!("%t.{}", self.temp_count)
+    }
+
+    pub(crate) fn new_label(&mut self, prefix: &str) -> String {
+        self.label_count += 1;
+        format!("@{prefix}.{}", self.label_count)
+    }
+
+    pub(crate) fn label(&mut self, label: &str) {
+        let _ = writeln!(self.body, "{label}");
+        self.terminated = false;
+    }
+
+    /// Terminatordan (`jmp`, `ret`...) sonra gələn təlimatlar üçün yeni blok açır.
+    pub(crate) fn emit(&mut self, instruction: impl AsRef<str>) {
+        if self.terminated {
+            let label = self.new_label("dead");
+            self.label(&label);
+        }
+        let _ = writeln!(self.body, "    {}", instruction.as_ref());
+    }
+
+    pub(crate) fn terminate(&mut self, instruction: impl AsRef<str>) {
+        self.emit(instruction);
+        self.terminated = true;
+    }
+}
@@ -0,0 +1,22 @@
+use std::fmt::Display;
+
+use locale::t;
+
+#[derive(Debug, PartialEq)]
+pub enum TranspileError {
+    Unsupported(String),
+    UnknownVariable(String),
+}
+
+impl Display for TranspileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TranspileError::Unsupported(what) => {
+                write!(f, "{}", t!("transpile.unsupported", what = what))
+            }
+            TranspileError::UnknownVariable(name) => {
+                write!(f, "{}", t!("transpile.unknown-variable", name = name))
+            }
+        }
+    }
+}
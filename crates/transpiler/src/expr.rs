@@ -0,0 +1,227 @@
+use parser::{
+    ast::{Operation, SourceSpan},
+    shared_ast::Type,
+};
+use validator::ast::{Expr, ExprKind};
+
+use crate::{
+    codegen::Codegen,
+    errors::TranspileError,
+    types::{IntKind, QbeType, expr_type, qbe_type},
+};
+
+impl Codegen {
+    pub(crate) fn expr(&mut self, expr: &Expr) -> Result<String, TranspileError> {
+        match &expr.kind {
+            ExprKind::Number(n) => Ok(n.to_string()),
+            ExprKind::TypedNumber { value, .. } => Ok(value.to_string()),
+            ExprKind::Float(f) => Ok(format!("d_{f}")),
+            ExprKind::Bool(b) => Ok(u8::from(*b).to_string()),
+            ExprKind::Char(c) => Ok((*c as u32).to_string()),
+            ExprKind::String(s) => Ok(self.string_data(s)),
+            ExprKind::Void => Ok("0".to_string()),
+            ExprKind::VariableRef { name, symbol } => match self.lookup(name) {
+                Some(local) => match qbe_type(&local.typ) {
+                    Some(typ) => Ok(self.load(typ, &local.slot)),
+                    None => Ok("0".to_string()),
+                },
+                None if symbol.typ == Type::Function => Ok(format!("${}", self.symbol(name))),
+                None => Err(TranspileError::UnknownVariable(name.clone())),
+            },
+            ExprKind::Return(value) => {
+                if matches!(value.kind, ExprKind::Void) {
+                    self.terminate("ret");
+                } else {
+                    let value = self.expr(value)?;
+                    self.terminate(format!("ret {value}"));
+                }
+                Ok("0".to_string())
+            }
+            ExprKind::Break | ExprKind::Continue => {
+                let (head, end) =
+                    self.loops.last().cloned().ok_or_else(|| {
+                        TranspileError::Unsupported("break outside a loop".into())
+                    })?;
+                let target = if matches!(expr.kind, ExprKind::Break) {
+                    end
+                } else {
+                    head
+                };
+                self.terminate(format!("jmp {target}"));
+                Ok("0".to_string())
+            }
+            ExprKind::Call {
+                target: None,
+                name,
+                args,
+                returned_type,
+            } => {
+                let ExprKind::VariableRef { name, .. } = &name.kind else {
+                    return Err(TranspileError::Unsupported("indirect calls".into()));
+                };
+                if let ("len", [list]) = (name.as_str(), args.as_slice()) {
+                    return self.length(list);
+                }
+                let mut values = Vec::new();
+                for arg in args {
+                    let typ = qbe_type(&expr_type(arg)).unwrap_or(QbeType::Long);
+                    let value = self.expr(arg)?;
+                    values.push(format!("{} {value}", typ.as_str()));
+                }
+                let call = format!("call ${}({})", self.symbol(name), values.join(", "));
+                match qbe_type(returned_type) {
+                    Some(typ) => {
+                        let temp = self.new_temp();
+                        self.emit(format!("{temp} ={} {call}", typ.as_str()));
+                        Ok(temp)
+                    }
+                    None => {
+                        self.emit(call);
+                        Ok("0".to_string())
+                    }
+                }
+            }
+            ExprKind::Call { .. } => Err(TranspileError::Unsupported("method calls".into())),
+            ExprKind::BinaryOp {
+                left,
+                right,
+                op,
+                return_type,
+            } => self.binary_op(left, right, *op, return_type, &expr.span),
+            ExprKind::TemplateString(chunks) => self.template(chunks),
+            ExprKind::Index {
+                target,
+                index,
+                element_type,
+            } => self.index(target, index, element_type, &expr.span),
+            // Siyahı literalları üçün hələ yaddaş ayrılmır, bax: `docs/lists.md`.
+            ExprKind::List(_) => Err(TranspileError::Unsupported("list literals".into())),
+        }
+    }
+
+    fn binary_op(
+        &mut self,
+        left: &Expr,
+        right: &Expr,
+        op: Operation,
+        return_type: &Type,
+        span: &SourceSpan,
+    ) -> Result<String, TranspileError> {
+        // Unar əməliyyatlar validatordan sol tərəfi `Void` olan BinaryOp kimi gəlir.
+        let operand = match (expr_type(left), expr_type(right)) {
+            (Type::Void, right) => right,
+            (Type::Float, _) | (_, Type::Float) => Type::Float,
+            (left, _) => left,
+        };
+        let lhs = self.expr(left)?;
+        let rhs = self.expr(right)?;
+
+        if operand == Type::Float && op == Operation::Subtract && expr_type(left) == Type::Void {
+            let temp = self.new_temp();
+            self.emit(format!("{temp} =d neg {rhs}"));
+            return Ok(temp);
+        }
+
+        if matches!(
+            op,
+            Operation::Add | Operation::Subtract | Operation::Multiply
+        ) && let Some(kind) = IntKind::of(return_type)
+        {
+            return Ok(self.integer_arithmetic(op, kind, &lhs, &rhs, return_type, span));
+        }
+
+        let temp = self.new_temp();
+        match op {
+            Operation::Not => self.emit(format!("{temp} =w ceqw {rhs}, 0")),
+            Operation::And => self.emit(format!("{temp} =w and {lhs}, {rhs}")),
+            Operation::Or => self.emit(format!("{temp} =w or {lhs}, {rhs}")),
+            Operation::Equal
+            | Operation::NotEqual
+            | Operation::Less
+            | Operation::LessEqual
+            | Operation::Greater
+            | Operation::GreaterEqual => {
+                let class = qbe_type(&operand).unwrap_or(QbeType::Word);
+                let lhs = self.convert(lhs, &expr_type(left), class);
+                let rhs = self.convert(rhs, &expr_type(right), class);
+                self.emit(format!(
+                    "{temp} =w {}{} {lhs}, {rhs}",
+                    comparison(op, &operand),
+                    class.as_str()
+                ));
+            }
+            Operation::Add
+            | Operation::Subtract
+            | Operation::Multiply
+            | Operation::Divide
+            | Operation::Modulo => {
+                let class = match (return_type, IntKind::of(return_type)) {
+                    (Type::Float, _) => QbeType::Double,
+                    (_, Some(kind)) => kind.qbe_type(),
+                    (other, None) => {
+                        return Err(TranspileError::Unsupported(format!(
+                            "'{}' on {other}",
+                            op.as_str()
+                        )));
+                    }
+                };
+                let instruction = match (op, IntKind::of(return_type)) {
+                    (Operation::Add, _) => "add",
+                    (Operation::Subtract, _) => "sub",
+                    (Operation::Multiply, _) => "mul",
+                    (Operation::Divide, Some(IntKind::Natural)) => "udiv",
+                    (Operation::Divide, _) => "div",
+                    (Operation::Modulo, Some(IntKind::Natural)) => "urem",
+                    (Operation::Modulo, Some(_)) => "rem",
+                    _ => {
+                        return Err(TranspileError::Unsupported(format!(
+                            "'{}' on {return_type}",
+                            op.as_str()
+                        )));
+                    }
+                };
+                let lhs = self.convert(lhs, &expr_type(left), class);
+                let rhs = self.convert(rhs, &expr_type(right), class);
+                self.emit(format!(
+                    "{temp} ={} {instruction} {lhs}, {rhs}",
+                    class.as_str()
+                ));
+            }
+        }
+        Ok(temp)
+    }
+
+    /// Tam ədədi onluq ədədlə qarışıq əməliyyatlarda `d` tipinə çevirir.
+    fn convert(&mut self, value: String, from: &Type, to: QbeType) -> String {
+        let instruction = match (IntKind::of(from), to) {
+            (Some(IntKind::Big), QbeType::Double) => "sltof",
+            (Some(IntKind::Natural), QbeType::Double) => "uwtof",
+            (Some(_), QbeType::Double) => "swtof",
+            _ => return value,
+        };
+        let temp = self.new_temp();
+        self.emit(format!("{temp} =d {instruction} {value}"));
+        temp
+    }
+}
+
+fn comparison(op: Operation, operand: &Type) -> &'static str {
+    let is_float = matches!(operand, Type::Float | Type::ZigFloat);
+    let is_unsigned = IntKind::of(operand) == Some(IntKind::Natural);
+    match op {
+        Operation::Equal => "ceq",
+        Operation::NotEqual => "cne",
+        Operation::Less if is_float => "clt",
+        Operation::LessEqual if is_float => "cle",
+        Operation::Greater if is_float => "cgt",
+        Operation::GreaterEqual if is_float => "cge",
+        Operation::Less if is_unsigned => "cult",
+        Operation::LessEqual if is_unsigned => "cule",
+        Operation::Greater if is_unsigned => "cugt",
+        Operation::GreaterEqual if is_unsigned => "cuge",
+        Operation::Less => "cslt",
+        Operation::LessEqual => "csle",
+        Operation::Greater => "csgt",
+        _ => "csge",
+    }
+}
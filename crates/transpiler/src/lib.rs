@@ -1,24 +1,35 @@
 use validator::ast::Program;
 
-pub fn transpile_program(program: Program) -> String {
-    format!(
-        "
-export function w $main() {{               
-    @start 
-        call $exit(w 0)
-        ret
-    }}
-"
-    )
-}
+use crate::{codegen::Codegen, errors::TranspileError};
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+mod builtins;
+mod codegen;
+pub mod errors;
+mod expr;
+mod list;
+pub mod mangle;
+mod overflow;
+mod template;
+mod types;
+
+/// Sabit ölçülü tam ədədlərdə `+ - *` daşdıqda nə baş verəcəyini seçir.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowMode {
+    /// Daşma mənbə yeri göstərilən xəta ilə proqramı dayandırır (debug rejimi).
+    #[default]
+    Trap,
+    /// Nəticə tipin bit enində dövr edir (release rejimi).
+    Wrap,
+}
 
-    // #[test]
-    // fn it_works() {
-    //     let result = add(2, 2);
-    //     assert_eq!(result, 4);
-    // }
+/// `module` funksiya adlarının önünə əlavə olunur, bax: [`mangle::mangle`].
+pub fn transpile_program(
+    program: Program,
+    module: &str,
+    overflow: OverflowMode,
+) -> Result<String, TranspileError> {
+    Codegen::new(module, overflow).program(program)
 }
+
+#[cfg(test)]
+mod tests;
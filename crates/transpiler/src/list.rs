@@ -0,0 +1,58 @@
+use locale::t;
+use parser::{ast::SourceSpan, shared_ast::Type};
+use validator::ast::Expr;
+
+use crate::{
+    codegen::Codegen,
+    errors::TranspileError,
+    overflow::trap_location,
+    types::{IntKind, QbeType, expr_type, qbe_type},
+};
+
+/// Siyahı `l` ünvanıdır: ilk 8 bayt uzunluq, ardınca hər biri 8 bayt olan elementlər.
+/// `args()`-ın qaytardığı argc/argv bloku da elə bu quruluşdadır.
+impl Codegen {
+    /// `target[index]`; sərhəddən kənar indeks proqramı dayandırır.
+    pub(crate) fn index(
+        &mut self,
+        target: &Expr,
+        index: &Expr,
+        element_type: &Type,
+        span: &SourceSpan,
+    ) -> Result<String, TranspileError> {
+        let base = self.expr(target)?;
+        let value = self.expr(index)?;
+        let position = match IntKind::of(&expr_type(index)) {
+            Some(IntKind::Big) => value,
+            Some(IntKind::Natural) => self.extend("extuw", &value),
+            _ => self.extend("extsw", &value),
+        };
+        let length = self.load(QbeType::Long, &base);
+        // İşarəsiz müqayisədə mənfi indeks də uzunluqdan böyük olur.
+        let outside = self.new_temp();
+        self.emit(format!("{outside} =w cugel {position}, {length}"));
+        self.trap_if(&outside, index_message(span));
+
+        let offset = self.new_temp();
+        self.emit(format!("{offset} =l mul {position}, 8"));
+        let slot = self.new_temp();
+        self.emit(format!("{slot} =l add {base}, {offset}"));
+        let address = self.new_temp();
+        self.emit(format!("{address} =l add {slot}, 8"));
+        match qbe_type(element_type) {
+            Some(typ) => Ok(self.load(typ, &address)),
+            None => Ok("0".to_string()),
+        }
+    }
+
+    /// `len(list)` runtime çağırışı deyil, uzunluq sözü yerində oxunur.
+    pub(crate) fn length(&mut self, list: &Expr) -> Result<String, TranspileError> {
+        let base = self.expr(list)?;
+        Ok(self.load(QbeType::Word, &base))
+    }
+}
+
+fn index_message(span: &SourceSpan) -> String {
+    let location = trap_location(span);
+    format!("{}\n", t!("trap.index", location = location))
+}
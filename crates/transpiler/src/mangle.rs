@@ -0,0 +1,123 @@
+use std::fmt::Write;
+
+/// Kodlanmış hər adın başlanğıcı.
+pub const PREFIX: &str = "_AZ";
+
+/// Modul yolu və funksiya adından assembler və QBE üçün keçərli ad yaradır:
+/// `_AZ` + hissələr + `E`. Hər hissə uzunluğu ilə yazılır (`5salam`); ASCII hərf,
+/// rəqəm və `_` xaricində simvolu olan və ya rəqəmlə başlayan hissə `u` ilə işarələnir,
+/// orada `_` `__`, digər simvollar isə `_<hex>_` kimi yazılır (`ş` → `_15f_`).
+pub fn mangle<'a>(path: impl IntoIterator<Item = &'a str>) -> String {
+    let mut out = PREFIX.to_string();
+    for part in path {
+        let plain = part.bytes().next().is_some_and(|b| !b.is_ascii_digit())
+            && part.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_');
+        if plain {
+            let _ = write!(out, "{}{part}", part.len());
+            continue;
+        }
+        let mut encoded = String::new();
+        for (i, ch) in part.chars().enumerate() {
+            match ch {
+                '_' => encoded.push_str("__"),
+                ch if ch.is_ascii_alphanumeric() && !(i == 0 && ch.is_ascii_digit()) => {
+                    encoded.push(ch)
+                }
+                ch => {
+                    let _ = write!(encoded, "_{:x}_", ch as u32);
+                }
+            }
+        }
+        let _ = write!(out, "u{}{encoded}", encoded.len());
+    }
+    out.push('E');
+    out
+}
+
+/// `mangle`-in tərsi: `_AZ5salam8salamVerE` → `salam::salamVer`. Ad tam olaraq
+/// kodlanmış ad deyilsə `None`.
+pub fn demangle(symbol: &str) -> Option<String> {
+    let (path, length) = parse(symbol)?;
+    (length == symbol.len()).then(|| path.join("::"))
+}
+
+/// Mətndəki bütün kodlanmış adları (məsələn, stack trace sətirlərində) AzLang adları
+/// ilə əvəz edir; qalan mətn dəyişmir.
+pub fn demangle_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for (start, _) in text.match_indices(PREFIX) {
+        if start < last
+            || text[..start]
+                .chars()
+                .next_back()
+                .is_some_and(is_symbol_char)
+        {
+            continue;
+        }
+        let Some((path, length)) = parse(&text[start..]) else {
+            continue;
+        };
+        let end = start + length;
+        if text[end..].chars().next().is_some_and(is_symbol_char) {
+            continue;
+        }
+        out.push_str(&text[last..start]);
+        out.push_str(&path.join("::"));
+        last = end;
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+fn is_symbol_char(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || ch == '_' || ch == '.'
+}
+
+/// Mətnin əvvəlindəki kodlanmış adın hissələri və onun bayt uzunluğu.
+fn parse(text: &str) -> Option<(Vec<String>, usize)> {
+    let mut rest = text.strip_prefix(PREFIX)?;
+    let mut path = Vec::new();
+    while !rest.starts_with('E') {
+        let (escaped, tail) = match rest.strip_prefix('u') {
+            Some(tail) => (true, tail),
+            None => (false, rest),
+        };
+        let digits = tail.bytes().take_while(u8::is_ascii_digit).count();
+        let length: usize = tail[..digits].parse().ok()?;
+        // Çox böyük uzunluq daşmasın deyə `checked_add`.
+        let end = digits.checked_add(length)?;
+        let part = tail.get(digits..end)?;
+        path.push(if escaped {
+            unescape(part)?
+        } else {
+            part.to_string()
+        });
+        rest = &tail[end..];
+    }
+    (!path.is_empty()).then(|| (path, text.len() - rest.len() + 1))
+}
+
+fn unescape(part: &str) -> Option<String> {
+    let mut out = String::new();
+    let mut chars = part.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '_' {
+            out.push(ch);
+            continue;
+        }
+        let mut hex = String::new();
+        for ch in chars.by_ref() {
+            if ch == '_' {
+                break;
+            }
+            hex.push(ch);
+        }
+        if hex.is_empty() {
+            out.push('_');
+        } else {
+            out.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+        }
+    }
+    Some(out)
+}
@@ -0,0 +1,222 @@
+use parser::{
+    ast::{Operation, SourceSpan},
+    shared_ast::Type,
+};
+
+use locale::t;
+use tokenizer::source::get_file;
+
+use crate::{OverflowMode, codegen::Codegen, types::IntKind};
+
+impl Codegen {
+    /// `+ - *` əməliyyatlarını seçilmiş [`OverflowMode`] rejiminə görə yaradır.
+    pub(crate) fn integer_arithmetic(
+        &mut self,
+        op: Operation,
+        kind: IntKind,
+        lhs: &str,
+        rhs: &str,
+        typ: &Type,
+        span: &SourceSpan,
+    ) -> String {
+        let instruction = match op {
+            Operation::Add => "add",
+            Operation::Subtract => "sub",
+            _ => "mul",
+        };
+        match (self.overflow, kind) {
+            (OverflowMode::Wrap, IntKind::Tiny) => {
+                let wide = self.new_temp();
+                self.emit(format!("{wide} =w {instruction} {lhs}, {rhs}"));
+                let temp = self.new_temp();
+                self.emit(format!("{temp} =w extsb {wide}"));
+                temp
+            }
+            (OverflowMode::Wrap, _) => {
+                let temp = self.new_temp();
+                self.emit(format!(
+                    "{temp} ={} {instruction} {lhs}, {rhs}",
+                    kind.qbe_type().as_str()
+                ));
+                temp
+            }
+            (OverflowMode::Trap, IntKind::Big) => {
+                self.checked_long(op, instruction, lhs, rhs, typ, span)
+            }
+            (OverflowMode::Trap, _) => {
+                self.checked_word(op, kind, instruction, lhs, rhs, typ, span)
+            }
+        }
+    }
+
+    /// 32 bitdən kiçik tiplər 64 bitdə hesablanır və nəticə tipin aralığı ilə yoxlanılır.
+    #[allow(clippy::too_many_arguments)]
+    fn checked_word(
+        &mut self,
+        op: Operation,
+        kind: IntKind,
+        instruction: &str,
+        lhs: &str,
+        rhs: &str,
+        typ: &Type,
+        span: &SourceSpan,
+    ) -> String {
+        let extend = if kind == IntKind::Natural {
+            "extuw"
+        } else {
+            "extsw"
+        };
+        let (min, max) = kind.range();
+        let a = self.new_temp();
+        self.emit(format!("{a} =l {extend} {lhs}"));
+        let b = self.new_temp();
+        self.emit(format!("{b} =l {extend} {rhs}"));
+        let wide = self.new_temp();
+        self.emit(format!("{wide} =l {instruction} {a}, {b}"));
+
+        if kind == IntKind::Natural {
+            // İki `natural`-ın cəmi və hasili 64 bitə işarəsiz sığır, amma hasil `i64`-dən
+            // böyük ola bilər. Ona görə yuxarı sərhəd işarəsiz, mənfilik isə yalnız
+            // çıxmada yoxlanılır.
+            if op == Operation::Subtract {
+                let below = self.new_temp();
+                self.emit(format!("{below} =w csltl {wide}, {min}"));
+                self.trap_if(&below, negative_message(op, typ, span));
+            }
+            let above = self.new_temp();
+            self.emit(format!("{above} =w cugtl {wide}, {max}"));
+            self.trap_if(&above, overflow_message(op, typ, span));
+        } else {
+            let below = self.new_temp();
+            self.emit(format!("{below} =w csltl {wide}, {min}"));
+            let above = self.new_temp();
+            self.emit(format!("{above} =w csgtl {wide}, {max}"));
+            let outside = self.new_temp();
+            self.emit(format!("{outside} =w or {below}, {above}"));
+            self.trap_if(&outside, overflow_message(op, typ, span));
+        }
+
+        let temp = self.new_temp();
+        self.emit(format!("{temp} =w copy {wide}"));
+        temp
+    }
+
+    /// 64 bit üçün daha geniş tip olmadığından daşma işarə bitləri ilə yoxlanılır.
+    fn checked_long(
+        &mut self,
+        op: Operation,
+        instruction: &str,
+        lhs: &str,
+        rhs: &str,
+        typ: &Type,
+        span: &SourceSpan,
+    ) -> String {
+        let result = self.new_temp();
+        self.emit(format!("{result} =l {instruction} {lhs}, {rhs}"));
+        let message = overflow_message(op, typ, span);
+
+        match op {
+            Operation::Add | Operation::Subtract => {
+                // add: (a ^ r) & (b ^ r) < 0,  sub: (a ^ b) & (a ^ r) < 0
+                let x = self.new_temp();
+                let y = self.new_temp();
+                if op == Operation::Add {
+                    self.emit(format!("{x} =l xor {lhs}, {result}"));
+                    self.emit(format!("{y} =l xor {rhs}, {result}"));
+                } else {
+                    self.emit(format!("{x} =l xor {lhs}, {rhs}"));
+                    self.emit(format!("{y} =l xor {lhs}, {result}"));
+                }
+                let both = self.new_temp();
+                self.emit(format!("{both} =l and {x}, {y}"));
+                let negative = self.new_temp();
+                self.emit(format!("{negative} =w csltl {both}, 0"));
+                self.trap_if(&negative, message);
+            }
+            _ => {
+                // a == 0 olduqda daşma yoxdur, a == -1 olduqda isə r / a özü daşa
+                // biləcəyi üçün yalnız b == MIN yoxlanılır.
+                let zero = self.new_label("mul.zero");
+                let minus_one = self.new_label("mul.neg");
+                let divide = self.new_label("mul.div");
+                let done = self.new_label("mul.done");
+
+                let is_zero = self.new_temp();
+                self.emit(format!("{is_zero} =w ceql {lhs}, 0"));
+                let check = self.new_label("mul.check");
+                self.terminate(format!("jnz {is_zero}, {zero}, {check}"));
+                self.label(&check);
+                let is_minus_one = self.new_temp();
+                self.emit(format!("{is_minus_one} =w ceql {lhs}, -1"));
+                self.terminate(format!("jnz {is_minus_one}, {minus_one}, {divide}"));
+
+                self.label(&minus_one);
+                let is_min = self.new_temp();
+                self.emit(format!("{is_min} =w ceql {rhs}, {}", i64::MIN));
+                self.trap_if(&is_min, message.clone());
+                self.terminate(format!("jmp {done}"));
+
+                self.label(&divide);
+                let quotient = self.new_temp();
+                self.emit(format!("{quotient} =l div {result}, {lhs}"));
+                let differs = self.new_temp();
+                self.emit(format!("{differs} =w cnel {quotient}, {rhs}"));
+                self.trap_if(&differs, message);
+                self.terminate(format!("jmp {done}"));
+
+                self.label(&zero);
+                self.terminate(format!("jmp {done}"));
+                self.label(&done);
+            }
+        }
+        result
+    }
+
+    pub(crate) fn trap_if(&mut self, condition: &str, message: String) {
+        let trap = self.new_label("trap");
+        let ok = self.new_label("ok");
+        self.terminate(format!("jnz {condition}, {trap}, {ok}"));
+        self.label(&trap);
+        let data = self.string_data(&message);
+        self.emit(format!("call $az_trap(l {data}, l {})", message.len()));
+        self.terminate("hlt");
+        self.label(&ok);
+    }
+}
+
+fn overflow_message(op: Operation, typ: &Type, span: &SourceSpan) -> String {
+    let location = trap_location(span);
+    format!(
+        "{}\n",
+        t!(
+            "trap.overflow",
+            location = location,
+            op = op.as_str(),
+            typ = typ
+        )
+    )
+}
+
+fn negative_message(op: Operation, typ: &Type, span: &SourceSpan) -> String {
+    let location = trap_location(span);
+    format!(
+        "{}\n",
+        t!(
+            "trap.negative",
+            location = location,
+            op = op.as_str(),
+            typ = typ
+        )
+    )
+}
+
+/// Trap mesajının yeri diaqnostikalardakı kimi `fayl:sətir:sütun` yazılır.
+pub(crate) fn trap_location(span: &SourceSpan) -> String {
+    match get_file(span.file) {
+        Some(file) => {
+            let location = file.location(span.start);
+            format!("{}:{}:{}", file.name, location.line, location.column)
+        }
+        None => "<unknown>".to_string(),
+    }
+}
@@ -0,0 +1,113 @@
+use parser::{
+    ast::{Align, FormatKind, FormatSpec},
+    shared_ast::Type,
+};
+use validator::ast::TemplateChunk;
+
+use crate::{
+    codegen::Codegen,
+    errors::TranspileError,
+    types::{IntKind, expr_type},
+};
+
+enum Part {
+    Text(String),
+    Value(String, Type, Option<FormatSpec>),
+}
+
+impl Codegen {
+    /// Şablon yazısını runtime-dakı `az_fmt_*` funksiyaları (`format.s`) ilə qurur.
+    /// Bütün ifadələr yazıya başlamazdan əvvəl hesablanır ki, daxili şablonlar
+    /// xarici yazının ortasında qurulmasın.
+    pub(crate) fn template(&mut self, chunks: &[TemplateChunk]) -> Result<String, TranspileError> {
+        let mut parts = Vec::new();
+        for chunk in chunks {
+            match chunk {
+                TemplateChunk::Literal(text) => parts.push(Part::Text(self.string_data(text))),
+                TemplateChunk::Expr(expr, spec) => {
+                    let value = self.expr(expr)?;
+                    parts.push(Part::Value(value, expr_type(expr), spec.clone()));
+                }
+            }
+        }
+        self.emit("call $az_fmt_begin()");
+        for part in parts {
+            match part {
+                Part::Text(data) => {
+                    self.emit(format!("call $az_fmt_str(l {data}, w 0, w 1, w 32)"));
+                }
+                Part::Value(value, typ, spec) => self.format_value(&value, &typ, spec.as_ref())?,
+            }
+        }
+        let result = self.new_temp();
+        self.emit(format!("{result} =l call $az_fmt_end()"));
+        Ok(result)
+    }
+
+    fn format_value(
+        &mut self,
+        value: &str,
+        typ: &Type,
+        spec: Option<&FormatSpec>,
+    ) -> Result<(), TranspileError> {
+        let kind = IntKind::of(typ);
+        let is_float = matches!(typ, Type::Float | Type::ZigFloat);
+        let align = match spec.and_then(|spec| spec.align) {
+            Some(Align::Left) => 1,
+            Some(Align::Right) => 2,
+            Some(Align::Center) => 3,
+            // Ədədlər sağa, qalanları sola düzlənir.
+            None if kind.is_some() || is_float => 2,
+            None => 1,
+        };
+        let (width, fill) = spec.map_or((0, ' '), |spec| (spec.width, spec.fill));
+        let layout = format!("w {width}, w {align}, w {}", fill as u32);
+        let zero = u32::from(spec.is_some_and(|spec| spec.zero));
+
+        if is_float {
+            let precision = spec.and_then(|spec| spec.precision).map_or(-1, i64::from);
+            self.emit(format!(
+                "call $az_fmt_float(d {value}, {layout}, w {precision}, w {zero})"
+            ));
+            return Ok(());
+        }
+        if let Some(kind) = kind {
+            let wide = match kind {
+                IntKind::Big => value.to_string(),
+                IntKind::Natural => self.extend("extuw", value),
+                IntKind::Tiny | IntKind::Int => self.extend("extsw", value),
+            };
+            let (base, upper) = match spec.map_or(FormatKind::Display, |spec| spec.kind) {
+                FormatKind::Display => (10, 0),
+                FormatKind::Hex => (16, 0),
+                FormatKind::UpperHex => (16, 2),
+                FormatKind::Octal => (8, 0),
+                FormatKind::Binary => (2, 0),
+            };
+            let unsigned = if kind == IntKind::Natural { 4 } else { 0 };
+            self.emit(format!(
+                "call $az_fmt_int(l {wide}, {layout}, w {base}, w {})",
+                zero | upper | unsigned
+            ));
+            return Ok(());
+        }
+        let function = match typ {
+            Type::Bool => "az_fmt_bool(w",
+            Type::Char => "az_fmt_char(w",
+            Type::String(_) => "az_fmt_str(l",
+            other => {
+                return Err(TranspileError::Unsupported(format!(
+                    "'{other}' in a template string"
+                )));
+            }
+        };
+        self.emit(format!("call ${function} {value}, {layout})"));
+        Ok(())
+    }
+
+    pub(crate) fn extend(&mut self, instruction: &str, value: &str) -> String {
+        let temp = self.new_temp();
+        self.emit(format!("{temp} =l {instruction} {value}"));
+        temp
+    }
+}
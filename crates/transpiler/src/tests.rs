@@ -0,0 +1,356 @@
+use crate::{
+    OverflowMode,
+    mangle::{demangle, demangle_text, mangle},
+    transpile_program,
+};
+
+fn transpile(source: &str, overflow: OverflowMode) -> String {
+    let statements = parser::parse_file("test.az", source).expect("parse edilmədi");
+    let (_, program) = validator::Validator::default()
+        .validate(statements)
+        .expect("validate edilmədi");
+    transpile_program(program, "test", overflow).expect("transpile edilmədi")
+}
+
+#[test]
+fn test_int_add_traps_by_default() {
+    let ssa = transpile(
+        "func add(const int a, const int b): int\n    return a + b\n",
+        OverflowMode::default(),
+    );
+    assert!(ssa.contains("=l extsw"));
+    assert!(ssa.contains("=l add"));
+    assert!(ssa.contains("csltl"));
+    assert!(ssa.contains(&format!("{}", i32::MAX)));
+    assert!(ssa.contains("call $az_trap("));
+    assert!(ssa.contains("'+' əməliyyatında"));
+}
+
+#[test]
+fn test_trap_message_has_source_location() {
+    let ssa = transpile(
+        "func mul(const int a, const int b): int\n    return a * b\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("test.az:2:12: '*' əməliyyatında"));
+}
+
+#[test]
+fn test_trap_message_follows_the_diagnostic_language() {
+    locale::set_lang(locale::Lang::En);
+    let ssa = transpile(
+        "func sub(const natural a, const natural b): natural\n    return a - b\n",
+        OverflowMode::Trap,
+    );
+    locale::set_lang(locale::Lang::Az);
+    assert!(ssa.contains("test.az:2:12: the result of '-' is negative"));
+    assert!(!ssa.contains("əməliyyat"));
+}
+
+#[test]
+fn test_int_add_wraps_in_wrap_mode() {
+    let ssa = transpile(
+        "func add(const int a, const int b): int\n    return a + b\n",
+        OverflowMode::Wrap,
+    );
+    assert!(ssa.contains("=w add"));
+    assert!(!ssa.contains("az_trap"));
+}
+
+#[test]
+fn test_tinyint_wraps_to_byte() {
+    let ssa = transpile(
+        "func add(const tinyint a, const tinyint b): tinyint\n    return a + b\n",
+        OverflowMode::Wrap,
+    );
+    assert!(ssa.contains("extsb"));
+}
+
+#[test]
+fn test_natural_subtraction_traps_on_negative() {
+    let ssa = transpile(
+        "func sub(const natural a, const natural b): natural\n    return a - b\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("=l extuw"));
+    assert!(ssa.contains("mənfi ola bilməz"));
+}
+
+#[test]
+fn test_natural_product_overflow_is_not_reported_as_negative() {
+    let ssa = transpile(
+        "func mul(const natural a, const natural b): natural\n    return a * b\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("=l mul"));
+    assert!(ssa.contains("=w cugtl"));
+    assert!(ssa.contains(&format!(", {}\n", u32::MAX)));
+    assert!(!ssa.contains("csltl"));
+    assert!(!ssa.contains("mənfi ola bilməz"));
+    assert!(ssa.contains("'*' əməliyyatında"));
+}
+
+#[test]
+fn test_untyped_literal_uses_the_natural_checks() {
+    let ssa = transpile(
+        "func dec(const natural n): natural\n    return n - 1\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("=l extuw 1"));
+    assert!(ssa.contains("mənfi ola bilməz"));
+}
+
+#[test]
+fn test_bigint_mul_checks_with_division() {
+    let ssa = transpile(
+        "func mul(const bigint a, const bigint b): bigint\n    return a * b\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("export function l $_AZ4test3mulE(l %p.0, l %p.1)"));
+    assert!(ssa.contains(&i64::MIN.to_string()));
+    assert!(ssa.contains("=l div"));
+}
+
+#[test]
+fn test_float_arithmetic_is_unchecked() {
+    let ssa = transpile(
+        "func add(const float a, const float b): float\n    return a + b\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("=d add"));
+    assert!(!ssa.contains("az_trap"));
+}
+
+#[test]
+fn test_float_negation_uses_neg() {
+    let ssa = transpile(
+        "func neg(const float a, const float b): float\n    return -(a + b)\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("=d add"));
+    assert!(ssa.contains("=d neg"));
+    assert!(!ssa.contains("mul"));
+}
+
+#[test]
+fn test_int_negation_traps_on_overflow() {
+    let ssa = transpile(
+        "func neg(const int a): int\n    return -a\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("=l sub"));
+    assert!(ssa.contains("'-' əməliyyatında"));
+    assert!(ssa.contains("call $az_trap("));
+}
+
+#[test]
+fn test_top_level_statements_form_main() {
+    let ssa = transpile("const int x = 1\nexit(x)\n", OverflowMode::Trap);
+    assert!(ssa.contains("export function w $main()"));
+    assert!(ssa.contains("call $exit(w %t."));
+    assert!(ssa.contains("hlt"));
+}
+
+#[test]
+fn test_main_returns_zero_without_exit() {
+    let ssa = transpile("const int x = 1\nx + 1\n", OverflowMode::Trap);
+    assert!(ssa.contains("ret 0"));
+    assert!(!ssa.contains("$exit"));
+}
+
+#[test]
+fn test_builtins_lower_to_runtime_calls() {
+    let ssa = transpile(
+        "const str home = env(\"HOME\")\nconst list<str> argv = args()\nexit(0)\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("=l call $az_env(l $str."));
+    assert!(ssa.contains("=l call $az_args()"));
+}
+
+#[test]
+fn test_list_index_is_bounds_checked() {
+    let ssa = transpile(
+        "const list<str> argv = args()\nif len(argv) > 1\n    const str first = argv[1]\nexit(0)\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("=w cugel"));
+    assert!(ssa.contains("test.az:3:23: siyahının indeksi"));
+    assert!(ssa.contains("=l mul"));
+    assert!(!ssa.contains("$len"));
+}
+
+#[test]
+fn test_char_literal_is_code_point() {
+    let ssa = transpile("func letter(): char\n    return 'ə'\n", OverflowMode::Trap);
+    assert!(ssa.contains("export function w $_AZ4test6letterE()"));
+    assert!(ssa.contains("ret 601"));
+}
+
+#[test]
+fn test_nested_blocks_close_together() {
+    let ssa = transpile(
+        "func f(const int a): int\n    if a > 0\n        return 1\n    return 0\nfunc g(): int\n    while true\n        if false\n            break\n    return 2\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("export function w $_AZ4test1fE(w %p.0)"));
+    assert!(ssa.contains("export function w $_AZ4test1gE()"));
+    assert!(ssa.contains("ret 2"));
+
+    let ssa = transpile("func h(): int\n    return 3", OverflowMode::Trap);
+    assert!(ssa.contains("ret 3"));
+}
+
+#[test]
+fn test_compound_assignment_in_loop() {
+    let ssa = transpile(
+        "func count(): int\n    var int i = 0\n    while i <= 10 && i >= 0\n        i += 1\n    return i\n",
+        OverflowMode::Wrap,
+    );
+    assert!(ssa.contains("csle"));
+    assert!(ssa.contains("csge"));
+    assert!(ssa.contains("=w and"));
+    assert!(ssa.contains("=w add"));
+}
+
+#[test]
+fn test_suffixed_literals_keep_their_type() {
+    let ssa = transpile(
+        "func big(): bigint\n    return 0xFF_FFbigint\nfunc small(): tinyint\n    return -0b101tinyint\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("export function l $_AZ4test3bigE()"));
+    assert!(ssa.contains("ret 65535"));
+    assert!(ssa.contains("export function w $_AZ4test5smallE()"));
+    assert!(ssa.contains("ret -5"));
+}
+
+#[test]
+fn test_mangle_plain_names() {
+    assert_eq!(mangle(["salam", "salamVer"]), "_AZ5salam8salamVerE");
+    assert_eq!(mangle(["main", "sum_all"]), "_AZ4main7sum_allE");
+}
+
+#[test]
+fn test_mangle_escapes_unicode_and_leading_digits() {
+    assert_eq!(mangle(["main", "çap"]), "_AZ4mainu6_e7_apE");
+    assert_eq!(mangle(["main", "iş_gün"]), "_AZ4mainu14i_15f___g_fc_nE");
+    assert_eq!(mangle(["2048", "oyna"]), "_AZu7_32_0484oynaE");
+    for path in [
+        ["main", "çap"],
+        ["main", "iş_gün"],
+        ["2048", "oyna"],
+        ["my-app", "ı"],
+    ] {
+        let symbol = mangle(path);
+        assert!(
+            symbol
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'_')
+        );
+        assert_eq!(demangle(&symbol), Some(path.join("::")));
+    }
+}
+
+#[test]
+fn test_demangle_rejects_other_symbols() {
+    assert_eq!(demangle("main"), None);
+    assert_eq!(demangle("_AZ"), None);
+    assert_eq!(demangle("_AZ4mainE"), Some("main".to_string()));
+    assert_eq!(demangle("_AZ9mainE"), None);
+    assert_eq!(demangle("_AZ4main3fooEx"), None);
+    assert_eq!(demangle("_AZ18446744073709551615aE"), None);
+    assert_eq!(demangle("_AZu18446744073709551615aE"), None);
+}
+
+#[test]
+fn test_demangle_text_in_stack_trace() {
+    let trace = "#0  0x401020 in _AZ4main8salamVerE ()\n#1  0x401050 in main ()";
+    assert_eq!(
+        demangle_text(trace),
+        "#0  0x401020 in main::salamVer ()\n#1  0x401050 in main ()"
+    );
+    assert_eq!(demangle_text("x_AZ4main3fooE"), "x_AZ4main3fooE");
+}
+
+#[test]
+fn test_user_functions_do_not_collide_with_runtime() {
+    let ssa = transpile(
+        "func print(const int x): int\n    return x\nfunc main2(): int\n    return print(1)\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("export function w $_AZ4test5printE(w %p.0)"));
+    assert!(ssa.contains("call $_AZ4test5printE(w 1)"));
+    assert!(!ssa.contains("$print("));
+    assert!(ssa.contains("export function w $main()"));
+}
+
+#[test]
+fn test_unicode_function_names_are_mangled() {
+    let ssa = transpile(
+        "func salamVer(): int\n    return 1\nfunc şəkil(): int\n    return salamVer()\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("export function w $_AZ4test8salamVerE()"));
+    assert!(ssa.contains("export function w $_AZ4testu13_15f__259_kilE()"));
+    assert!(ssa.contains("call $_AZ4test8salamVerE()"));
+}
+
+#[test]
+fn test_linked_externals_keep_their_names() {
+    let ssa = transpile(
+        "@link(\"libm.so\")\nfunc cos(const float x): float\nfunc f(): float\n    return cos(1.0)\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("call $cos(d d_1"));
+    assert!(!ssa.contains("function d $cos"));
+}
+
+#[test]
+fn test_template_lowers_to_runtime_formatting() {
+    let ssa = transpile(
+        "func show(const float price, const int n, const str name): str\n    return `${name:*^9}: ${price:.2} ${n:>5} ${n:08X}`\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("call $az_fmt_begin()"));
+    assert!(ssa.contains("call $az_fmt_str(l %t."));
+    assert!(ssa.contains(", w 9, w 3, w 42)"));
+    assert!(ssa.contains("call $az_fmt_str(l $str."));
+    assert!(ssa.contains("call $az_fmt_float(d %t.") && ssa.contains("w 0, w 2, w 32, w 2, w 0)"));
+    assert!(ssa.contains("=l extsw"));
+    assert!(ssa.contains("w 5, w 2, w 32, w 10, w 0)"));
+    assert!(ssa.contains("w 8, w 2, w 32, w 16, w 3)"));
+    assert!(ssa.contains("=l call $az_fmt_end()"));
+}
+
+#[test]
+fn test_template_defaults_and_other_types() {
+    let ssa = transpile(
+        "func show(const bool ok, const char c, const float f, const natural n): str\n    return `${ok}${c:>2}${f}${n}`\n",
+        OverflowMode::Trap,
+    );
+    assert!(ssa.contains("call $az_fmt_bool(w %t."));
+    assert!(ssa.contains("call $az_fmt_char(w %t.") && ssa.contains(", w 2, w 2, w 32)"));
+    assert!(ssa.contains("w 0, w 2, w 32, w -1, w 0)"));
+    assert!(ssa.contains("=l extuw"));
+    assert!(ssa.contains("w 10, w 4)"));
+}
+
+#[test]
+fn test_nested_template_is_built_first() {
+    let ssa = transpile(
+        "func show(const int n): str\n    return `a ${`b ${n}`} c`\n",
+        OverflowMode::Trap,
+    );
+    let calls: Vec<&str> = ssa
+        .lines()
+        .filter_map(|line| line.split("call $az_fmt_").nth(1))
+        .map(|call| &call[..call.find('(').unwrap()])
+        .collect();
+    assert_eq!(
+        calls,
+        [
+            "begin", "str", "int", "end", "begin", "str", "str", "str", "end"
+        ]
+    );
+}
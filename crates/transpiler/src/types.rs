@@ -0,0 +1,108 @@
+use parser::shared_ast::{StringEnum, Type};
+use validator::ast::{Expr, ExprKind};
+
+/// QBE-nin əsas tipləri: `w` (32 bit), `l` (64 bit) və `d` (onluq ədəd).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QbeType {
+    Word,
+    Long,
+    Double,
+}
+
+impl QbeType {
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            QbeType::Word => "w",
+            QbeType::Long => "l",
+            QbeType::Double => "d",
+        }
+    }
+}
+
+/// Sabit ölçülü tam ədəd tipləri və onların aralıqları.
+///
+/// `tinyint` 8 bit, `int` 32 bit, `bigint` 64 bit işarəli, `natural` isə
+/// 32 bit işarəsiz ədəddir.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntKind {
+    Tiny,
+    Int,
+    Natural,
+    Big,
+}
+
+impl IntKind {
+    pub fn of(typ: &Type) -> Option<IntKind> {
+        match typ {
+            Type::LowInteger => Some(IntKind::Tiny),
+            Type::Integer | Type::ZigInteger => Some(IntKind::Int),
+            Type::Natural | Type::ZigNatural => Some(IntKind::Natural),
+            Type::BigInteger => Some(IntKind::Big),
+            _ => None,
+        }
+    }
+
+    /// Aralıq validatorla eyni cədvəldən, [`Type::integer_range`]-dən götürülür.
+    pub fn range(self) -> (i64, i64) {
+        let typ = match self {
+            IntKind::Tiny => Type::LowInteger,
+            IntKind::Int => Type::Integer,
+            IntKind::Natural => Type::Natural,
+            IntKind::Big => Type::BigInteger,
+        };
+        typ.integer_range()
+            .expect("hər IntKind tam ədəd tipinə uyğundur")
+    }
+
+    pub const fn qbe_type(self) -> QbeType {
+        match self {
+            IntKind::Big => QbeType::Long,
+            _ => QbeType::Word,
+        }
+    }
+}
+
+/// `void` üçün `None` qaytarır, çünki onun QBE qarşılığı yoxdur.
+pub fn qbe_type(typ: &Type) -> Option<QbeType> {
+    match typ {
+        Type::Void => None,
+        Type::Float | Type::ZigFloat => Some(QbeType::Double),
+        Type::Integer
+        | Type::Natural
+        | Type::LowInteger
+        | Type::ZigInteger
+        | Type::ZigNatural
+        | Type::Bool
+        | Type::Char => Some(QbeType::Word),
+        Type::BigInteger
+        | Type::String(_)
+        | Type::Array(_)
+        | Type::User(_)
+        | Type::Allocator
+        | Type::Any
+        | Type::Error
+        | Type::ZigArray
+        | Type::ZigConstArray
+        | Type::Function => Some(QbeType::Long),
+    }
+}
+
+pub fn expr_type(expr: &Expr) -> Type {
+    match &expr.kind {
+        ExprKind::Number(_) => Type::Integer,
+        ExprKind::TypedNumber { typ, .. } => typ.clone(),
+        ExprKind::Float(_) => Type::Float,
+        ExprKind::Bool(_) => Type::Bool,
+        ExprKind::Char(_) => Type::Char,
+        ExprKind::String(_) => Type::String(StringEnum::LiteralString),
+        ExprKind::TemplateString(_) => Type::String(StringEnum::DynamicString),
+        ExprKind::List(_) => Type::Array(Box::new(Type::Any)),
+        ExprKind::Index { element_type, .. } => element_type.clone(),
+        ExprKind::Void | ExprKind::Break | ExprKind::Continue => Type::Void,
+        ExprKind::Return(e) => expr_type(e),
+        ExprKind::VariableRef { symbol, .. } => symbol.typ.clone(),
+        ExprKind::BinaryOp { return_type, .. } => return_type.clone(),
+        ExprKind::Call { returned_type, .. } => returned_type.clone(),
+    }
+}
@@ -1,5 +1,5 @@
 use parser::{
-    ast::{Operation, Parameter, Symbol},
+    ast::{FormatSpec, Operation, Parameter, SourceSpan, Symbol},
     shared_ast::Type,
 };
 
@@ -9,6 +9,7 @@ pub struct Function {
     pub body: Vec<Ast>,
     pub params: Vec<Parameter>,
     pub return_typ: Type,
+    pub span: SourceSpan,
 }
 #[derive(Debug, Clone, PartialEq)]
 pub struct ExternalFunctionDef {
@@ -16,6 +17,7 @@ pub struct ExternalFunctionDef {
     pub params: Vec<Parameter>,
     pub return_typ: Type,
     pub library: String,
+    pub span: SourceSpan,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -39,18 +41,44 @@ pub struct Else {
 #[derive(Debug, Clone, PartialEq)]
 pub enum TemplateChunk {
     Literal(String),
-    Expr(Box<Expr>),
+    Expr(Box<Expr>, Option<FormatSpec>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub enum Expr {
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: SourceSpan,
+}
+impl Expr {
+    pub fn new(kind: ExprKind, span: SourceSpan) -> Self {
+        Self { kind, span }
+    }
+}
+impl From<ExprKind> for Expr {
+    fn from(kind: ExprKind) -> Self {
+        Self::new(kind, SourceSpan::default())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprKind {
     String(String),
     Number(i64),
+    TypedNumber {
+        value: i64,
+        typ: Type,
+    },
     Float(f64),
     Bool(bool),
     Char(char),
     TemplateString(Vec<TemplateChunk>),
     List(Vec<Expr>),
+    /// `target[index]`; `element_type` siyahının element tipidir.
+    Index {
+        target: Box<Expr>,
+        index: Box<Expr>,
+        element_type: Type,
+    },
     Void,
     Break,
     Continue,
@@ -74,7 +102,23 @@ pub enum Expr {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub enum Ast {
+pub struct Ast {
+    pub kind: AstKind,
+    pub span: SourceSpan,
+}
+impl Ast {
+    pub fn new(kind: AstKind, span: SourceSpan) -> Self {
+        Self { kind, span }
+    }
+}
+impl From<AstKind> for Ast {
+    fn from(kind: AstKind) -> Self {
+        Self::new(kind, SourceSpan::default())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AstKind {
     Decl {
         name: String,
         typ: Type,
@@ -0,0 +1,54 @@
+use parser::{
+    ast::{Atom, Parameter, SourceSpan},
+    shared_ast::{StringEnum, Type},
+};
+
+use crate::FunctionInfo;
+
+/// Runtime tərəfindən verilən və elan edilmədən çağırıla bilən funksiyalar.
+pub fn builtin_functions() -> Vec<(&'static str, FunctionInfo)> {
+    vec![
+        (
+            "args",
+            // Elementlər kernelin verdiyi argv yazılarıdır, proqrama məxsus deyil.
+            FunctionInfo {
+                return_type: Type::Array(Box::new(Type::String(StringEnum::LiteralConstString))),
+                parameters: vec![],
+            },
+        ),
+        (
+            "env",
+            // Nəticə mühitin öz yaddaşındadır, proqrama məxsus deyil.
+            FunctionInfo {
+                return_type: Type::String(StringEnum::LiteralConstString),
+                parameters: vec![parameter(
+                    "name",
+                    Type::String(StringEnum::LiteralConstString),
+                )],
+            },
+        ),
+        (
+            "len",
+            FunctionInfo {
+                return_type: Type::Integer,
+                parameters: vec![parameter("list", Type::Array(Box::new(Type::Any)))],
+            },
+        ),
+    ]
+}
+
+pub fn is_builtin(name: &str) -> bool {
+    builtin_functions()
+        .iter()
+        .any(|(builtin, _)| *builtin == name)
+}
+
+fn parameter(name: &str, typ: Type) -> Parameter {
+    Parameter {
+        name: Atom::from(name),
+        typ,
+        is_pointer: false,
+        span: SourceSpan::default(),
+        type_span: SourceSpan::default(),
+    }
+}
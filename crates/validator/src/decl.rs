@@ -1,31 +1,65 @@
 use std::rc::Rc;
 
 use parser::{
-    ast::{Atom, Expr, Symbol},
+    ast::{Atom, Expr, SourceSpan, Symbol},
     shared_ast::Type,
+    suggest::similar_name,
+    types::type_names,
 };
 
 use crate::{
     Validator,
-    ast::Ast,
+    ast::{Ast, AstKind},
     errors::ValidatorError,
     expr::validate_expr,
-    helper::{get_type, reconcile_type},
+    helper::{check_literal_range, get_type, literal_type, reconcile_type, typed_literal},
 };
 
 pub fn validate_decl(
     name: Atom,
     typ: Rc<Type>,
+    type_span: SourceSpan,
     is_mutable: bool,
     value: Expr,
+    span: SourceSpan,
     ctx: &mut Validator,
 ) -> Result<Ast, ValidatorError> {
     if ctx.lookup_variable(name.as_ref()).is_some() {
-        return Err(ValidatorError::AlreadyDecl(name.to_string()));
+        return Err(ValidatorError::AlreadyDecl(span.clone(), name.to_string()));
     }
 
-    let mut inferred = get_type(&value, ctx)?;
-    reconcile_type(typ, &mut inferred, name.as_ref())?;
+    // Elan edilən tam ədəd tipinə sığan literal o tipdə yazılır: `const bigint b = 5000000000`.
+    let literal = literal_type(&value, &typ);
+    let value = typed_literal(value, literal);
+    let mismatch_span = if type_span.is_empty() {
+        &value.span
+    } else {
+        &type_span
+    };
+    let checked = check_known_type(&typ, &type_span)
+        .and_then(|()| check_literal_range(&value, &typ))
+        .and_then(|()| get_type(&value, ctx))
+        .and_then(|mut inferred| {
+            reconcile_type(typ, &mut inferred, name.as_ref(), mismatch_span)?;
+            Ok(inferred)
+        });
+    let inferred = match checked {
+        Ok(inferred) => inferred,
+        Err(error) => {
+            // Dəyişən xəta tipi ilə elan edilir ki, sonrakı istifadələri yeni xəta yaratmasın.
+            ctx.declare_variable(
+                name.to_string(),
+                Symbol {
+                    typ: Type::Error,
+                    is_used: true,
+                    is_mutable,
+                    is_changed: true,
+                    span,
+                },
+            );
+            return Err(error);
+        }
+    };
 
     ctx.declare_variable(
         name.to_string(),
@@ -34,14 +68,31 @@ pub fn validate_decl(
             is_used: false,
             is_mutable,
             is_changed: false,
+            span: span.clone(),
         },
     );
     let val = validate_expr(value, ctx)?;
 
-    Ok(Ast::Decl {
-        name: name.to_string(),
-        typ: inferred,
-        is_mutable,
-        value: Box::new(val),
-    })
+    Ok(Ast::new(
+        AstKind::Decl {
+            name: name.to_string(),
+            typ: inferred,
+            is_mutable,
+            value: Box::new(val),
+        },
+        span,
+    ))
+}
+
+/// Tipdə tanınmayan ad varsa, ona bənzəyən daxili tipin adı ilə xəta.
+fn check_known_type(typ: &Type, span: &SourceSpan) -> Result<(), ValidatorError> {
+    match typ {
+        Type::User(name) => Err(ValidatorError::UnknownType(
+            span.clone(),
+            name.to_string(),
+            similar_name(name, type_names()).map(str::to_string),
+        )),
+        Type::Array(inner) => check_known_type(inner, span),
+        _ => Ok(()),
+    }
 }
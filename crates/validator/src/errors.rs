@@ -1,200 +1,234 @@
 use core::fmt;
 use std::fmt::Display;
 
-use parser::{ast::Operation, shared_ast::Type};
+use crate::lint::Lint;
+use locale::t;
+use parser::{
+    ast::{Operation, SourceSpan},
+    shared_ast::Type,
+};
 
 #[derive(Debug, PartialEq)]
 pub enum ValidatorError {
-    UnknownType(String),
-    InvalidFunctionCall(String),
-    AlreadyDecl(String),
+    /// Üçüncü sahə ona bənzəyən məlum tipin adıdır, əgər varsa.
+    UnknownType(SourceSpan, String, Option<String>),
+    InvalidFunctionCall(SourceSpan, String),
+    AlreadyDecl(SourceSpan, String),
     InvalidOperation {
+        span: SourceSpan,
         op: Operation,
         left: Type,
         right: Type,
     },
     DeclTypeMismatch {
+        span: SourceSpan,
         name: String,
         expected: String,
         found: String,
     },
-    DeclTypeUnknown(String),
-    AssignmentToImmutableVariable(String),
+    AssignmentToImmutableVariable(SourceSpan, String),
     AssignmentTypeMismatch {
+        span: SourceSpan,
         name: String,
         expected: String,
         found: String,
     },
-    UndefinedVariable(String),
-    DuplicateUnion(String),
+    /// Üçüncü sahə görünən dəyişənlərdən ona bənzəyənin adıdır.
+    UndefinedVariable(SourceSpan, String, Option<String>),
     InvalidArgumentCount {
+        span: SourceSpan,
         name: String,
         expected: usize,
         found: usize,
     },
     InvalidArgumentType {
+        span: SourceSpan,
         name: String,
         expected: String,
         found: String,
     },
     TypeMismatch {
+        span: SourceSpan,
         expected: Type,
         found: Type,
     },
-    UnknownStruct(String),
-    DuplicateStruct(String),
-    DuplicateEnum(String),
-    IfConditionTypeUnknown,
-    IfConditionTypeMismatch(String),
-    LoopIterableTypeNotFound,
-    LoopRequiresList,
-    UnionNotFound(String),
-    FunctionNotFound(String),
-    FunctionArgCountMismatch {
-        name: String,
-        expected: usize,
-        found: usize,
-    },
-    IndexTargetTypeNotFound,
-    NestedFunctionDefinition,
-    NeverChangedMuttableVariable(String),
-    FunctionAlreadyDefined(String),
-    FunctionReturnTypeErr(String),
-    FunctionNameType(String),
-    NotUsedVariable(String),
+    /// Üçüncü sahə ona bənzəyən funksiyanın adıdır.
+    FunctionNotFound(SourceSpan, String, Option<String>),
+    NeverChangedMuttableVariable(SourceSpan, String),
+    /// Üçüncü sahə ilk tərifin yeridir; daxili funksiyalar üçün `None`.
+    FunctionAlreadyDefined(SourceSpan, String, Option<SourceSpan>),
+    NotUsedVariable(SourceSpan, String),
+    MainFunctionDefined(SourceSpan),
+    UnknownLint(SourceSpan, String),
+    /// `${...}` formatı bu tipə aid deyil, məsələn `int` üçün `.2`.
+    FormatSpecMismatch(SourceSpan, String, Type),
+    /// Şablonda göstərilə bilməyən tip, məsələn siyahı.
+    NotFormattable(SourceSpan, Type),
+    /// `a.ad` müraciətində tipin belə sahəsi yoxdur.
+    UnknownField(SourceSpan, Type, String),
+    /// Sonluqsuz ədəd literalı aldığı tipə sığmır: `const int x = 5000000000`.
+    NumberOutOfRange(SourceSpan, i64, Type),
+    /// Bərpadan sonra toplanmış bir neçə xəta, aşkarlanma sırası ilə.
+    Multiple(Vec<ValidatorError>),
 }
 
-impl Display for ValidatorError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl ValidatorError {
+    /// Tək xətanı olduğu kimi, bir neçəsini isə [`ValidatorError::Multiple`] kimi qaytarır.
+    pub fn collect(mut errors: Vec<ValidatorError>) -> ValidatorError {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            ValidatorError::Multiple(errors)
+        }
+    }
+
+    /// Toplanmış xətaları tək-tək siyahı kimi qaytarır.
+    pub fn into_errors(self) -> Vec<ValidatorError> {
         match self {
-            ValidatorError::UnknownType(string) => write!(f, "Gözlənilməyən token '{string}'"),
-            ValidatorError::InvalidFunctionCall(found) => write!(f, "{found} bir funksiya deyil."),
-            ValidatorError::InvalidOperation { op, left, right } => {
-                write!(
-                    f,
-                    "'{}' əməliyyatı '{left}' və '{right}' tipləri üzərində icra edilə bilməz.",
-                    op.as_str()
-                )
-            }
-            ValidatorError::FunctionNameType(name) => write!(f, "'{name}' tipi funksiya deyil.  "),
-            ValidatorError::AlreadyDecl(string) => write!(f, "'{string}' Dəyəri onsuzda var.  "),
+            ValidatorError::Multiple(errors) => errors,
+            error => vec![error],
+        }
+    }
+
+    /// Səviyyəsi `-W`/`-D`/`-A` ilə idarə olunan yoxlamanın adı.
+    pub fn lint(&self) -> Option<Lint> {
+        match self {
+            ValidatorError::NotUsedVariable(..) => Some(Lint::Unused),
+            ValidatorError::NeverChangedMuttableVariable(..) => Some(Lint::UnusedMut),
+            _ => None,
+        }
+    }
+
+    /// Xətanın mənbədəki yeri. Toplanmış xətalar üçün birincinin yeri, siyahı boşdursa `None`.
+    pub fn span(&self) -> Option<&SourceSpan> {
+        match self {
+            ValidatorError::UnknownType(span, ..)
+            | ValidatorError::InvalidFunctionCall(span, ..)
+            | ValidatorError::AlreadyDecl(span, ..)
+            | ValidatorError::AssignmentToImmutableVariable(span, ..)
+            | ValidatorError::UndefinedVariable(span, ..)
+            | ValidatorError::FunctionNotFound(span, ..)
+            | ValidatorError::NeverChangedMuttableVariable(span, ..)
+            | ValidatorError::FunctionAlreadyDefined(span, ..)
+            | ValidatorError::NotUsedVariable(span, ..)
+            | ValidatorError::MainFunctionDefined(span, ..)
+            | ValidatorError::UnknownLint(span, ..)
+            | ValidatorError::FormatSpecMismatch(span, ..)
+            | ValidatorError::NotFormattable(span, ..)
+            | ValidatorError::UnknownField(span, ..)
+            | ValidatorError::NumberOutOfRange(span, ..)
+            | ValidatorError::InvalidOperation { span, .. }
+            | ValidatorError::DeclTypeMismatch { span, .. }
+            | ValidatorError::AssignmentTypeMismatch { span, .. }
+            | ValidatorError::InvalidArgumentCount { span, .. }
+            | ValidatorError::InvalidArgumentType { span, .. }
+            | ValidatorError::TypeMismatch { span, .. } => Some(span),
+            ValidatorError::Multiple(errors) => errors.first().and_then(ValidatorError::span),
+        }
+    }
+
+    /// Dəyişməz xəta kodu. Toplanmış xətalar üçün `None`.
+    pub fn code(&self) -> Option<&'static str> {
+        match self {
+            ValidatorError::UnknownType(..) => Some("AZ0201"),
+            ValidatorError::InvalidFunctionCall(..) => Some("AZ0202"),
+            ValidatorError::AlreadyDecl(..) => Some("AZ0203"),
+            ValidatorError::InvalidOperation { .. } => Some("AZ0204"),
+            ValidatorError::DeclTypeMismatch { .. } => Some("AZ0205"),
+            ValidatorError::AssignmentToImmutableVariable(..) => Some("AZ0207"),
+            ValidatorError::AssignmentTypeMismatch { .. } => Some("AZ0208"),
+            ValidatorError::UndefinedVariable(..) => Some("AZ0209"),
+            ValidatorError::InvalidArgumentCount { .. } => Some("AZ0211"),
+            ValidatorError::InvalidArgumentType { .. } => Some("AZ0212"),
+            ValidatorError::TypeMismatch { .. } => Some("AZ0213"),
+            ValidatorError::FunctionNotFound(..) => Some("AZ0222"),
+            ValidatorError::NeverChangedMuttableVariable(..) => Some("AZ0226"),
+            ValidatorError::FunctionAlreadyDefined(..) => Some("AZ0227"),
+            ValidatorError::NotUsedVariable(..) => Some("AZ0230"),
+            ValidatorError::MainFunctionDefined(..) => Some("AZ0231"),
+            ValidatorError::UnknownLint(..) => Some("AZ0232"),
+            ValidatorError::FormatSpecMismatch(..) => Some("AZ0233"),
+            ValidatorError::NotFormattable(..) => Some("AZ0234"),
+            ValidatorError::UnknownField(..) => Some("AZ0235"),
+            ValidatorError::NumberOutOfRange(..) => Some("AZ0236"),
+            ValidatorError::Multiple(_) => None,
+        }
+    }
+
+    /// Yer göstərilmədən xəta mətni, cari dildə.
+    pub fn message(&self) -> String {
+        let code = self.code().unwrap_or_default();
+        match self {
+            ValidatorError::UnknownType(_, what, _)
+            | ValidatorError::InvalidFunctionCall(_, what) => {
+                t!(code, what = what)
+            }
+            ValidatorError::AlreadyDecl(_, name)
+            | ValidatorError::AssignmentToImmutableVariable(_, name)
+            | ValidatorError::UndefinedVariable(_, name, _)
+            | ValidatorError::FunctionNotFound(_, name, _)
+            | ValidatorError::NeverChangedMuttableVariable(_, name)
+            | ValidatorError::FunctionAlreadyDefined(_, name, _)
+            | ValidatorError::NotUsedVariable(_, name)
+            | ValidatorError::UnknownLint(_, name) => t!(code, name = name),
+            ValidatorError::InvalidOperation {
+                op, left, right, ..
+            } => t!(code, op = op.as_str(), left = left, right = right),
             ValidatorError::DeclTypeMismatch {
                 name,
                 expected,
                 found,
-            } => write!(
-                f,
-                "'{name}' Dəyəri '{expected}' tipində olmalıdır, ancak '{found}' tipində var."
-            ),
-            ValidatorError::InvalidArgumentType {
-                name,
-                expected,
-                found,
-            } => write!(
-                f,
-                "'{name}' funksiyası '{expected}' tipində argumenti olmalıdır, ancak '{found}' tipində argumenti var."
-            ),
-            ValidatorError::DeclTypeUnknown(string) => {
-                write!(f, "'{string}' Dəyəri tipi bildirilməyib.")
+                ..
             }
-            ValidatorError::AssignmentToImmutableVariable(string) => {
-                write!(f, "'{string}' Dəyəri dəyişən olmalıdır.")
-            }
-            ValidatorError::NeverChangedMuttableVariable(string) => {
-                write!(
-                    f,
-                    "'{string}' Dəyəri dəyişən olaraq qeyd edilib amma dəyişdirilməyib."
-                )
-            }
-
-            ValidatorError::AssignmentTypeMismatch {
+            | ValidatorError::AssignmentTypeMismatch {
                 name,
                 expected,
                 found,
-            } => write!(
-                f,
-                "'{name}' Dəyəri '{expected}' tipində olmalıdır, ancak '{found}' tipində yazılıb."
-            ),
-            ValidatorError::UndefinedVariable(string) => {
-                write!(f, "'{string}' Dəyəri bildirilməyib.")
-            }
-            ValidatorError::DuplicateUnion(string) => {
-                write!(f, "'{string}' Union tərifi onsuzda var.")
+                ..
             }
-            ValidatorError::InvalidArgumentCount {
+            | ValidatorError::InvalidArgumentType {
                 name,
                 expected,
                 found,
-            } => {
-                write!(
-                    f,
-                    "'{name}' funksiyası '{expected}' argumenti olmalıdır, ancak '{found}' argumenti var."
-                )
-            }
-            ValidatorError::TypeMismatch { expected, found } => {
-                write!(
-                    f,
-                    "'{expected}' tipində olmalıdır, ancak '{found}' tipində var."
-                )
-            }
-            ValidatorError::UnknownStruct(string) => {
-                write!(f, "'{string}' Struct tərifi bildirilməyib.")
-            }
-            ValidatorError::DuplicateStruct(string) => {
-                write!(f, "'{string}' Struct tərifi onsuzda var.")
-            }
-            ValidatorError::DuplicateEnum(string) => {
-                write!(f, "'{string}' Enum tərifi onsuzda var.")
-            }
-            ValidatorError::IfConditionTypeUnknown => {
-                write!(f, "Şərt tipi müəyyən edilə bilmədi.")
-            }
-            ValidatorError::IfConditionTypeMismatch(typ) => {
-                write!(f, "Şərt '{typ}' tipində olmalıdır.")
-            }
-            ValidatorError::LoopIterableTypeNotFound => {
-                write!(f, "Dövr iterable tipi müəyyən edilə bilmədi.")
-            }
-            ValidatorError::LoopRequiresList => {
-                write!(f, "Dövr iterable tipi müəyyən edilə bilmədi.")
-            }
-            ValidatorError::UnionNotFound(string) => {
-                write!(f, "'{string}' Union tərifi bildirilməyib.")
-            }
-            ValidatorError::FunctionNotFound(string) => {
-                write!(f, "'{string}' funksiyası bildirilməyib.")
-            }
-            ValidatorError::FunctionArgCountMismatch {
+                ..
+            } => t!(code, name = name, expected = expected, found = found),
+            ValidatorError::InvalidArgumentCount {
                 name,
                 expected,
                 found,
-            } => {
-                write!(
-                    f,
-                    "'{name}' funksiyası '{expected}' argumenti olmalıdır, ancak '{found}' argumenti var."
-                )
-            }
-            ValidatorError::IndexTargetTypeNotFound => {
-                write!(f, "Indeks tipi müəyyən edilə bilmədi.")
-            }
-            ValidatorError::NestedFunctionDefinition => {
-                write!(f, "Funksiya içərisində funksiya yaradıla bilməz.")
-            }
-            ValidatorError::FunctionAlreadyDefined(string) => {
-                write!(f, "Funksiya '{string}' onsuzda var.")
-            }
-            ValidatorError::FunctionReturnTypeErr(string) => {
-                write!(
-                    f,
-                    "Funskiyada səhv dəyər qaytarır '{string}' tipində olmalıydı  "
-                )
-            }
-            ValidatorError::NotUsedVariable(var) => {
-                write!(
-                    f,
-                    "'{var}' dəyəri heç istifadə olunmayıb. İstifadə olunmamış dəyəri isə qeyd etməyə eytiyac yoxdur. "
-                )
+                ..
+            } => t!(code, name = name, expected = expected, found = found),
+            ValidatorError::TypeMismatch {
+                expected, found, ..
+            } => t!(code, expected = expected, found = found),
+            ValidatorError::FormatSpecMismatch(_, spec, typ) => t!(code, spec = spec, typ = typ),
+            ValidatorError::NotFormattable(_, typ) => t!(code, typ = typ),
+            ValidatorError::UnknownField(_, typ, name) => t!(code, typ = typ, name = name),
+            ValidatorError::NumberOutOfRange(_, value, typ) => t!(code, value = value, typ = typ),
+            ValidatorError::MainFunctionDefined(_) => t!(code),
+            ValidatorError::Multiple(errors) => {
+                locale::lang().tr_count("validator.multiple", errors.len())
             }
         }
     }
 }
+
+impl Display for ValidatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidatorError::Multiple(errors) => {
+                for (index, error) in errors.iter().enumerate() {
+                    if index > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{error}")?;
+                }
+                Ok(())
+            }
+            error => match error.span() {
+                Some(span) => write!(f, "{span} {}", error.message()),
+                None => write!(f, "{}", error.message()),
+            },
+        }
+    }
+}
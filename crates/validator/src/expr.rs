@@ -1,5 +1,5 @@
 use parser::{
-    ast::Symbol,
+    ast::{SourceSpan, Symbol},
     binary_op,
     shared_ast::{StringEnum, Type},
 };
@@ -8,81 +8,101 @@ use crate::{
     Validator,
     ast::{self, Ast},
     errors::ValidatorError,
-    helper::get_type,
+    format::check_format,
+    helper::{check_literal_range, get_type, literal_type, typed_literal},
 };
 type ParserExpr = parser::ast::Expr;
+type ParserExprKind = parser::ast::ExprKind;
 type ValidatorExpr = ast::Expr;
+type ValidatorExprKind = ast::ExprKind;
 
 pub fn validate_expr(
     expr: ParserExpr,
     ctx: &mut Validator,
 ) -> Result<ValidatorExpr, ValidatorError> {
     let return_type = get_type(&expr, ctx)?;
-    match expr {
-        ParserExpr::String(s) => Ok(ValidatorExpr::String(s.to_string())),
-        ParserExpr::Number(n) => Ok(ValidatorExpr::Number(n)),
-        ParserExpr::Float(f) => Ok(ValidatorExpr::Float(f)),
-        ParserExpr::Bool(b) => Ok(ValidatorExpr::Bool(b)),
-        ParserExpr::Char(c) => Ok(ValidatorExpr::Char(c)),
-        ParserExpr::Void => Ok(ValidatorExpr::Void),
-        ParserExpr::TemplateString(chunks) => {
+    // Başqa tip almamış sonluqsuz literal `int` olaraq qalır.
+    check_literal_range(&expr, &Type::Integer)?;
+    let span = expr.span;
+    let kind = match expr.kind {
+        ParserExprKind::String(s) => ValidatorExprKind::String(s.to_string()),
+        ParserExprKind::Number(n) => ValidatorExprKind::Number(n),
+        ParserExprKind::TypedNumber { value, typ } => ValidatorExprKind::TypedNumber { value, typ },
+        ParserExprKind::Float(f) => ValidatorExprKind::Float(f),
+        ParserExprKind::Bool(b) => ValidatorExprKind::Bool(b),
+        ParserExprKind::Char(c) => ValidatorExprKind::Char(c),
+        ParserExprKind::Void => ValidatorExprKind::Void,
+        ParserExprKind::TemplateString(chunks) => {
             let validated: Result<Vec<crate::ast::TemplateChunk>, ValidatorError> = chunks
                 .into_iter()
                 .map(|chunk| match chunk {
                     parser::ast::TemplateChunk::Literal(l) => {
                         Ok(crate::ast::TemplateChunk::Literal(l))
                     }
-                    parser::ast::TemplateChunk::Expr(e) => Ok(crate::ast::TemplateChunk::Expr(
-                        Box::new(validate_expr(*e, ctx)?),
-                    )),
+                    parser::ast::TemplateChunk::Expr(e, spec) => {
+                        let typ = get_type(&e, ctx)?;
+                        check_format(&typ, &e.span, spec.as_ref())?;
+                        Ok(crate::ast::TemplateChunk::Expr(
+                            Box::new(validate_expr(*e, ctx)?),
+                            spec,
+                        ))
+                    }
                 })
                 .collect();
-            Ok(ValidatorExpr::TemplateString(validated?))
+            ValidatorExprKind::TemplateString(validated?)
         }
-        ParserExpr::List(items) => {
+        ParserExprKind::List(items) => {
             let validated: Result<Vec<ValidatorExpr>, ValidatorError> =
                 items.into_iter().map(|x| validate_expr(x, ctx)).collect();
-            Ok(ValidatorExpr::List(validated?))
+            ValidatorExprKind::List(validated?)
         }
-        ParserExpr::Return(e) => {
+        ParserExprKind::Return(e) => {
             let validated = validate_expr(*e, ctx)?;
-            Ok(ValidatorExpr::Return(Box::new(validated)))
+            ValidatorExprKind::Return(Box::new(validated))
         }
-        ParserExpr::VariableRef { name, symbol } => {
-            let s = ctx.lookup_variable_mut_with_err(name.as_ref())?;
+        ParserExprKind::VariableRef { name, symbol } => {
+            let s = ctx.lookup_variable_mut_with_err(name.as_ref(), &span)?;
             s.is_used = true;
-            Ok(ValidatorExpr::VariableRef {
+            ValidatorExprKind::VariableRef {
                 name: name.to_string(),
                 symbol: s.clone(),
-            })
+            }
         }
-        ParserExpr::BinaryOp { left, right, op } => {
-            let left = validate_expr(*left, ctx)?;
-            let right = validate_expr(*right, ctx)?;
-            Ok(ValidatorExpr::BinaryOp {
+        ParserExprKind::BinaryOp { left, right, op } => {
+            let left_literal = literal_type(&left, &get_type(&right, ctx)?);
+            let right_literal = literal_type(&right, &get_type(&left, ctx)?);
+            let left = validate_expr(typed_literal(*left, left_literal), ctx)?;
+            let right = validate_expr(typed_literal(*right, right_literal), ctx)?;
+            ValidatorExprKind::BinaryOp {
                 left: Box::new(left),
                 right: Box::new(right),
                 op,
                 return_type,
-            })
+            }
         }
-        ParserExpr::Call { target, name, args } => {
+        ParserExprKind::Call { target, name, args } => {
             let target = target
                 .map(|t| validate_expr(*t, ctx))
                 .transpose()?
                 .map(Box::new);
 
-            let func_name = match *name {
-                ParserExpr::VariableRef { name: ref nam, .. } => {
-                    ctx.functions
-                        .get(nam.as_ref())
-                        .ok_or_else(|| ValidatorError::FunctionNotFound(nam.to_string()))?;
+            let func_name = match name.kind {
+                ParserExprKind::VariableRef { name: ref nam, .. } => {
+                    ctx.functions.get(nam.as_ref()).ok_or_else(|| {
+                        ValidatorError::FunctionNotFound(
+                            name.span.clone(),
+                            nam.to_string(),
+                            ctx.similar_function(nam.as_ref()),
+                        )
+                    })?;
                     nam.to_string()
                 }
                 _ => {
-                    return Err(ValidatorError::FunctionNotFound(format!(
-                        "{name:?} bu bir funksiya deyil"
-                    )));
+                    return Err(ValidatorError::FunctionNotFound(
+                        name.span.clone(),
+                        format!("{name:?} bu bir funksiya deyil"),
+                        None,
+                    ));
                 }
             };
 
@@ -91,6 +111,7 @@ pub fn validate_expr(
 
             if args.len() != params.len() {
                 return Err(ValidatorError::InvalidArgumentCount {
+                    span,
                     name: func_name.clone(),
                     expected: params.len(),
                     found: args.len(),
@@ -101,13 +122,16 @@ pub fn validate_expr(
                 let arg_type = get_type(arg, ctx)?;
                 let expected = &param.typ;
                 match (expected, &arg_type) {
-                    (Type::Any, _) | (_, Type::Any) => {}
+                    (Type::Any, _) | (_, Type::Any | Type::Error) => {}
+                    // `len` kimi funksiyalar istənilən siyahını qəbul edir.
+                    (Type::Array(element), Type::Array(_)) if **element == Type::Any => {}
                     (
                         Type::String(StringEnum::LiteralConstString),
                         Type::String(StringEnum::LiteralString),
                     ) => {}
                     (exp, found) if exp != found => {
                         return Err(ValidatorError::InvalidArgumentType {
+                            span: arg.span.clone(),
                             name: func_name.clone(),
                             expected: exp.to_string(),
                             found: found.to_string(),
@@ -117,61 +141,53 @@ pub fn validate_expr(
                 }
             }
 
-            let new_name = ValidatorExpr::VariableRef {
-                name: func_name,
-                symbol: Symbol {
-                    typ: Type::Function,
-                    is_mutable: false,
-                    is_used: false,
-                    is_changed: false,
+            let new_name = ValidatorExpr::new(
+                ValidatorExprKind::VariableRef {
+                    name: func_name,
+                    symbol: Symbol {
+                        typ: Type::Function,
+                        is_mutable: false,
+                        is_used: false,
+                        is_changed: false,
+                        span: SourceSpan::default(),
+                    },
                 },
-            };
+                name.span,
+            );
 
             let validated_args = args
                 .into_iter()
                 .map(|arg| validate_expr(arg, ctx))
                 .collect::<Result<Vec<_>, _>>()?;
 
-            Ok(ValidatorExpr::Call {
+            ValidatorExprKind::Call {
                 target,
                 name: Box::new(new_name),
                 args: validated_args,
                 returned_type: return_type,
-            })
+            }
         }
-        ParserExpr::UnaryOp { op, expr } => {
+        ParserExprKind::UnaryOp { op, expr } => {
             let expr = validate_expr(*expr, ctx)?;
-            let return_type = match &op {
-                parser::ast::Operation::Subtract => Type::Integer,
-                parser::ast::Operation::Not => Type::Bool,
-                _ => Type::Any,
-            };
-            Ok(ValidatorExpr::BinaryOp {
-                left: Box::new(ValidatorExpr::Void),
+            ValidatorExprKind::BinaryOp {
+                left: Box::new(ValidatorExprKind::Void.into()),
                 right: Box::new(expr),
                 op,
                 return_type,
-            })
-        }
-        ParserExpr::Index {
-            target,
-            index,
-            target_type,
-        } => {
-            let target = validate_expr(*target, ctx)?;
-            let index = validate_expr(*index, ctx)?;
-            Ok(ValidatorExpr::BinaryOp {
-                left: Box::new(target),
-                right: Box::new(index),
-                op: parser::ast::Operation::Equal,
-                return_type: target_type,
-            })
+            }
         }
-        ParserExpr::Break => Ok(ValidatorExpr::Break),
-        ParserExpr::Continue => Ok(ValidatorExpr::Continue),
-        ParserExpr::DynamicString(_)
-        | ParserExpr::Time(_)
-        | ParserExpr::Comment(_)
-        | ParserExpr::StructInit { .. } => Ok(ValidatorExpr::Void),
-    }
+        ParserExprKind::Index { target, index, .. } => ValidatorExprKind::Index {
+            target: Box::new(validate_expr(*target, ctx)?),
+            index: Box::new(validate_expr(*index, ctx)?),
+            element_type: return_type,
+        },
+        ParserExprKind::Break => ValidatorExprKind::Break,
+        ParserExprKind::Continue => ValidatorExprKind::Continue,
+        ParserExprKind::DynamicString(_)
+        | ParserExprKind::Time(_)
+        | ParserExprKind::Comment(_)
+        | ParserExprKind::StructInit { .. }
+        | ParserExprKind::Member { .. } => ValidatorExprKind::Void,
+    };
+    Ok(ValidatorExpr::new(kind, span))
 }
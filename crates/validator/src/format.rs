@@ -0,0 +1,47 @@
+use parser::{
+    ast::{FormatKind, FormatSpec, SourceSpan},
+    shared_ast::Type,
+};
+
+use crate::errors::ValidatorError;
+
+/// Şablondakı `${...}` dəyərinin göstərilə bildiyini və formatın onun tipinə
+/// uyğun olduğunu yoxlayır: dəqiqlik yalnız onluq ədədlərə, `0` ədədlərə, say
+/// sistemi (`x`, `X`, `o`, `b`) isə tam ədədlərə aiddir.
+pub fn check_format(
+    typ: &Type,
+    span: &SourceSpan,
+    spec: Option<&FormatSpec>,
+) -> Result<(), ValidatorError> {
+    let is_integer = matches!(
+        typ,
+        Type::Integer
+            | Type::Natural
+            | Type::BigInteger
+            | Type::LowInteger
+            | Type::ZigInteger
+            | Type::ZigNatural
+    );
+    let is_float = matches!(typ, Type::Float | Type::ZigFloat);
+    match typ {
+        Type::Error => return Ok(()),
+        _ if is_integer || is_float => {}
+        Type::Bool | Type::Char | Type::String(_) => {}
+        other => return Err(ValidatorError::NotFormattable(span.clone(), other.clone())),
+    }
+    let Some(spec) = spec else {
+        return Ok(());
+    };
+    let fits = (spec.precision.is_none() || is_float)
+        && (!spec.zero || is_integer || is_float)
+        && (spec.kind == FormatKind::Display || is_integer);
+    if fits {
+        Ok(())
+    } else {
+        Err(ValidatorError::FormatSpecMismatch(
+            spec.span.clone(),
+            spec.text.clone(),
+            typ.clone(),
+        ))
+    }
+}
@@ -2,29 +2,48 @@ use std::rc::Rc;
 
 use parser::{
     ast::{
-        Expr::{self, VariableRef},
-        Operation, Statement,
+        Expr,
+        ExprKind::{self, VariableRef},
+        Operation, SourceSpan, Statement,
     },
     shared_ast::{StringEnum, Type},
 };
 
 use crate::{Validator, ast::Ast, errors::ValidatorError, validate::validate_statement};
 pub fn get_type(value: &Expr, ctx: &Validator) -> Result<Type, ValidatorError> {
-    match value {
-        Expr::Number(_) => Ok(Type::Integer),
-        Expr::TemplateString(_) => Ok(Type::String(StringEnum::DynamicString)),
-        Expr::UnaryOp { op, expr } => {
-            get_type(expr, ctx)?;
+    let span = &value.span;
+    match &value.kind {
+        ExprKind::Number(_) => Ok(Type::Integer),
+        ExprKind::TypedNumber { typ, .. } => Ok(typ.clone()),
+        ExprKind::TemplateString(_) => Ok(Type::String(StringEnum::DynamicString)),
+        ExprKind::UnaryOp { op, expr } => {
+            let typ = get_type(expr, ctx)?;
             match *op {
-                Operation::Subtract => Ok(Type::Integer),
+                Operation::Subtract => match typ {
+                    Type::Integer
+                    | Type::BigInteger
+                    | Type::LowInteger
+                    | Type::Float
+                    | Type::Any
+                    | Type::Error => Ok(typ),
+                    found => Err(ValidatorError::TypeMismatch {
+                        span: expr.span.clone(),
+                        expected: Type::Integer,
+                        found,
+                    }),
+                },
                 Operation::Not => Ok(Type::Bool),
-                _ => Err(ValidatorError::UnknownType(format!("unary op {op:?}"))),
+                _ => Err(ValidatorError::UnknownType(
+                    span.clone(),
+                    format!("unary op {op:?}"),
+                    None,
+                )),
             }
         }
-        Expr::Bool(_) => Ok(Type::Bool),
-        Expr::Float(_) => Ok(Type::Float),
-        Expr::String(_) => Ok(Type::String(StringEnum::LiteralString)),
-        Expr::List(items) => {
+        ExprKind::Bool(_) => Ok(Type::Bool),
+        ExprKind::Float(_) => Ok(Type::Float),
+        ExprKind::String(_) => Ok(Type::String(StringEnum::LiteralString)),
+        ExprKind::List(items) => {
             if items.is_empty() {
                 return Ok(Type::Array(Box::new(Type::Any)));
             }
@@ -33,6 +52,7 @@ pub fn get_type(value: &Expr, ctx: &Validator) -> Result<Type, ValidatorError> {
                 let t = get_type(item, ctx)?;
                 if t != item_type {
                     return Err(ValidatorError::TypeMismatch {
+                        span: item.span.clone(),
                         expected: item_type.clone(),
                         found: t,
                     });
@@ -40,12 +60,33 @@ pub fn get_type(value: &Expr, ctx: &Validator) -> Result<Type, ValidatorError> {
             }
             Ok(Type::Array(Box::new(item_type)))
         }
-        Expr::Index {
-            target: _,
-            index: _,
-            target_type,
-        } => Ok(target_type.clone()),
-        Expr::VariableRef { name, symbol } => {
+        ExprKind::Index { target, index, .. } => {
+            let index_type = get_type(index, ctx)?;
+            if index_type.integer_range().is_none()
+                && !matches!(index_type, Type::Any | Type::Error)
+            {
+                return Err(ValidatorError::TypeMismatch {
+                    span: index.span.clone(),
+                    expected: Type::Integer,
+                    found: index_type,
+                });
+            }
+            match get_type(target, ctx)? {
+                Type::Array(element) => Ok(*element),
+                typ @ (Type::Any | Type::Error) => Ok(typ),
+                found => Err(ValidatorError::TypeMismatch {
+                    span: target.span.clone(),
+                    expected: Type::Array(Box::new(Type::Any)),
+                    found,
+                }),
+            }
+        }
+        ExprKind::Member { target, name } => Err(ValidatorError::UnknownField(
+            span.clone(),
+            get_type(target, ctx)?,
+            name.to_string(),
+        )),
+        ExprKind::VariableRef { name, symbol } => {
             if let Some(s) = symbol {
                 return Ok(s.typ.clone());
             }
@@ -55,23 +96,40 @@ pub fn get_type(value: &Expr, ctx: &Validator) -> Result<Type, ValidatorError> {
             if ctx.functions.contains_key(name.as_ref()) {
                 return Ok(Type::Function);
             }
-            Err(ValidatorError::UndefinedVariable(name.to_string()))
+            Err(ValidatorError::UndefinedVariable(
+                span.clone(),
+                name.to_string(),
+                ctx.similar_variable(name.as_ref()),
+            ))
         }
-        Expr::StructInit { name, .. } => Err(ValidatorError::UnknownStruct(name.to_string())),
-        Expr::Return(e) => get_type(e, ctx),
-        Expr::Call { name, .. } => match &**name {
+        ExprKind::StructInit { name, .. } => Err(ValidatorError::UnknownType(
+            span.clone(),
+            name.to_string(),
+            None,
+        )),
+        ExprKind::Return(e) => get_type(e, ctx),
+        ExprKind::Call { name, .. } => match &name.kind {
             VariableRef { name, symbol } => {
                 if let Some(func) = ctx.functions.get(name.as_ref()) {
                     Ok(func.return_type.clone())
                 } else {
-                    Err(ValidatorError::FunctionNotFound(name.to_string()))
+                    Err(ValidatorError::FunctionNotFound(
+                        span.clone(),
+                        name.to_string(),
+                        ctx.similar_function(name.as_ref()),
+                    ))
                 }
             }
-            _ => Err(ValidatorError::InvalidFunctionCall(format!(" {name:?}"))),
+            _ => Err(ValidatorError::InvalidFunctionCall(
+                span.clone(),
+                format!(" {name:?}"),
+            )),
         },
-        Expr::BinaryOp { left, right, op } => {
+        ExprKind::BinaryOp { left, right, op } => {
             let left_type = get_type(left, ctx)?;
             let right_type = get_type(right, ctx)?;
+            let left_type = literal_type(left, &right_type).unwrap_or(left_type);
+            let right_type = literal_type(right, &left_type).unwrap_or(right_type);
 
             match *op {
                 // ── Müqayisə əməliyyatları ────────────────────────────────────────
@@ -84,8 +142,8 @@ pub fn get_type(value: &Expr, ctx: &Validator) -> Result<Type, ValidatorError> {
 
                 // ── Məntiqi əməliyyatlar ──────────────────────────────────────────
                 Operation::And | Operation::Or => {
-                    expect_type(Type::Bool, &left_type)?;
-                    expect_type(Type::Bool, &right_type)?;
+                    expect_type(Type::Bool, &left_type, &left.span)?;
+                    expect_type(Type::Bool, &right_type, &right.span)?;
                     Ok(Type::Bool)
                 }
 
@@ -94,44 +152,51 @@ pub fn get_type(value: &Expr, ctx: &Validator) -> Result<Type, ValidatorError> {
                 | Operation::Subtract
                 | Operation::Multiply
                 | Operation::Divide
-                | Operation::Modulo => resolve_arithmetic_type(&left_type, &right_type, *op),
+                | Operation::Modulo => resolve_arithmetic_type(&left_type, &right_type, *op, span),
 
-                _ => Err(ValidatorError::UnknownType(format!(
-                    "unknown binary op {op:?}"
-                ))),
+                _ => Err(ValidatorError::UnknownType(
+                    span.clone(),
+                    format!("unknown binary op {op:?}"),
+                    None,
+                )),
             }
         }
-        Expr::Void => Ok(Type::Void),
-        Expr::Char(_) => Ok(Type::Char),
-        Expr::DynamicString(_) => Ok(Type::String(StringEnum::DynamicString)),
-        Expr::Time(_) => Ok(Type::Void),
-        Expr::Comment(_) => Ok(Type::Void),
-        Expr::Break | Expr::Continue => Ok(Type::Void),
-        _ => Err(ValidatorError::UnknownType(format!(
-            "unknown expr {value:?}"
-        ))),
+        ExprKind::Void => Ok(Type::Void),
+        ExprKind::Char(_) => Ok(Type::Char),
+        ExprKind::DynamicString(_) => Ok(Type::String(StringEnum::DynamicString)),
+        ExprKind::Time(_) => Ok(Type::Void),
+        ExprKind::Comment(_) => Ok(Type::Void),
+        ExprKind::Break | ExprKind::Continue => Ok(Type::Void),
+        _ => Err(ValidatorError::UnknownType(
+            span.clone(),
+            format!("unknown expr {value:?}"),
+            None,
+        )),
     }
 }
 
-pub fn validate_body<'a>(
-    body: Vec<Statement>,
-    ctx: &mut Validator,
-) -> Result<Vec<Ast>, ValidatorError> {
+/// Gövdəni yoxlayır. Uğursuz ifadənin xətası `ctx`-ə yazılır və növbəti ifadə ilə davam edilir.
+pub fn validate_body(body: Vec<Statement>, ctx: &mut Validator) -> Vec<Ast> {
     let mut result = Vec::new();
-    for expr in body {
-        result.push(validate_statement(expr, ctx)?);
+    for stmt in body {
+        ctx.register_allow(&stmt);
+        match validate_statement(stmt, ctx) {
+            Ok(ast) => result.push(ast),
+            Err(error) => ctx.report(error),
+        }
     }
-    Ok(result)
+    result
 }
-pub fn type_checking(left: Type, right: Type) -> Result<(), ValidatorError> {
+pub fn type_checking(left: Type, right: Type, span: &SourceSpan) -> Result<(), ValidatorError> {
     match (left, right) {
-        (Type::Any, _) => Ok(()),
-        (_, Type::Any) => Ok(()),
+        (Type::Any | Type::Error, _) => Ok(()),
+        (_, Type::Any | Type::Error) => Ok(()),
         (Type::String(StringEnum::LiteralConstString), Type::String(StringEnum::LiteralString)) => {
             Ok(())
         }
 
         (expected, other) if expected != other => Err(ValidatorError::AssignmentTypeMismatch {
+            span: span.clone(),
             name: other.to_string(),
             expected: expected.to_string(),
             found: other.to_string(),
@@ -143,9 +208,10 @@ pub fn reconcile_type(
     typ: Rc<Type>,
     inferred: &mut Type,
     name: &str,
+    span: &SourceSpan,
 ) -> Result<(), ValidatorError> {
     match (&*typ, &inferred) {
-        (Type::Any, _) => Ok(()),
+        (Type::Any, _) | (_, Type::Error) => Ok(()),
         (other, Type::Any) => {
             *inferred = other.clone();
             Ok(())
@@ -154,13 +220,23 @@ pub fn reconcile_type(
             *inferred = Type::String(StringEnum::LiteralConstString);
             Ok(())
         }
+        // Mühitdən gələn yazı kimi özümüzə məxsus olmayan dəyər elə belə də qalır.
+        (Type::String(_), Type::String(StringEnum::LiteralConstString)) => Ok(()),
         (Type::Natural, Type::Integer) => {
-            *inferred = Type::Integer;
+            *inferred = Type::Natural;
+            Ok(())
+        }
+        // Siyahılar elementlərinə görə uzlaşdırılır: `list<str> a = args()`.
+        (Type::Array(expected), Type::Array(found)) => {
+            let mut element = (**found).clone();
+            reconcile_type(Rc::new((**expected).clone()), &mut element, name, span)?;
+            *inferred = Type::Array(Box::new(element));
             Ok(())
         }
 
         (expected, other) if *expected == **other => Ok(()),
         other => Err(ValidatorError::DeclTypeMismatch {
+            span: span.clone(),
             name: name.to_string(),
             expected: other.0.to_string(),
             found: other.1.to_string(),
@@ -171,8 +247,12 @@ fn resolve_arithmetic_type(
     left: &Type,
     right: &Type,
     op: Operation,
+    span: &SourceSpan,
 ) -> Result<Type, ValidatorError> {
     match (left, right) {
+        // Əvvəlki xəta artıq bildirilib
+        (Type::Error, _) | (_, Type::Error) => Ok(Type::Error),
+
         // String birləşməsi yalnız Add üçün
         (Type::String(_), Type::String(_)) if op == Operation::Add => {
             Ok(Type::String(StringEnum::DynamicString))
@@ -180,6 +260,7 @@ fn resolve_arithmetic_type(
 
         // String-ə digər riyazi əməliyyatlar qadağandır
         (Type::String(_), _) | (_, Type::String(_)) => Err(ValidatorError::InvalidOperation {
+            span: span.clone(),
             op,
             left: left.clone(),
             right: right.clone(),
@@ -188,6 +269,8 @@ fn resolve_arithmetic_type(
         // Eyni tiplər
         (Type::Integer, Type::Integer) => Ok(Type::Integer),
         (Type::Natural, Type::Natural) => Ok(Type::Natural),
+        (Type::BigInteger, Type::BigInteger) => Ok(Type::BigInteger),
+        (Type::LowInteger, Type::LowInteger) => Ok(Type::LowInteger),
         (Type::Float, Type::Float) => Ok(Type::Float),
 
         // Float + Integer qarışığı → Float
@@ -195,17 +278,58 @@ fn resolve_arithmetic_type(
 
         // Hər şey digər hal — tip uyğunsuzluğu
         (l, r) => Err(ValidatorError::TypeMismatch {
+            span: span.clone(),
             expected: l.clone(),
             found: r.clone(),
         }),
     }
 }
 
+/// Sonluqsuz ədəd literalı digər tərəfin tam ədəd tipini alır, əgər o tipə sığırsa:
+/// `natural n` üçün `n - 1` `natural` olaraq qalır.
+pub(crate) fn literal_type(literal: &Expr, other: &Type) -> Option<Type> {
+    let ExprKind::Number(value) = literal.kind else {
+        return None;
+    };
+    if *other == Type::Integer {
+        return None;
+    }
+    let (min, max) = other.integer_range()?;
+    (min..=max).contains(&value).then(|| other.clone())
+}
+
+/// Sonluqsuz ədəd literalının `typ` tam ədəd tipinə sığdığını yoxlayır.
+pub(crate) fn check_literal_range(literal: &Expr, typ: &Type) -> Result<(), ValidatorError> {
+    let (ExprKind::Number(value), Some((min, max))) = (&literal.kind, typ.integer_range()) else {
+        return Ok(());
+    };
+    if (min..=max).contains(value) {
+        Ok(())
+    } else {
+        Err(ValidatorError::NumberOutOfRange(
+            literal.span.clone(),
+            *value,
+            typ.clone(),
+        ))
+    }
+}
+
+/// Digər tərəfin tipini almış ədəd literalı həmin tipdə yazılır.
+pub(crate) fn typed_literal(literal: Expr, typ: Option<Type>) -> Expr {
+    match (literal.kind, typ) {
+        (ExprKind::Number(value), Some(typ)) => {
+            Expr::new(ExprKind::TypedNumber { value, typ }, literal.span)
+        }
+        (kind, _) => Expr::new(kind, literal.span),
+    }
+}
+
 /// Tipin gözlənilən tipə uyğun olmasını yoxlayır.
 #[inline]
-fn expect_type(expected: Type, found: &Type) -> Result<(), ValidatorError> {
-    if *found != expected {
+fn expect_type(expected: Type, found: &Type, span: &SourceSpan) -> Result<(), ValidatorError> {
+    if *found != expected && *found != Type::Error {
         Err(ValidatorError::TypeMismatch {
+            span: span.clone(),
             expected,
             found: found.clone(),
         })
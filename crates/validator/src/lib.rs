@@ -1,19 +1,27 @@
 use std::{collections::HashMap, vec};
 pub mod ast;
+pub mod builtins;
 pub mod decl;
 pub mod errors;
 pub mod expr;
+mod format;
 mod helper;
+pub mod lint;
+#[cfg(test)]
 mod tests;
 pub mod validate;
 use crate::{
     ast::{ExternalFunctionDef, Function, Program},
+    builtins::{builtin_functions, is_builtin},
     errors::ValidatorError,
+    helper::validate_body,
+    lint::{Level, Lint, LintLevels},
     validate::validate_statement,
 };
 use parser::{
-    ast::{Expr, FunctionDef, Parameter, Statement, Symbol},
+    ast::{Parameter, SourceSpan, Statement, StatementKind, Symbol},
     shared_ast::Type,
+    suggest::similar_name,
 };
 
 #[derive(Debug, PartialEq)]
@@ -35,17 +43,30 @@ pub struct Validator {
     pub functions: HashMap<String, FunctionInfo>,
     pub variables: Vec<HashMap<String, Symbol>>,
     pub link_files: Vec<String>,
+    /// Yoxlama zamanı toplanmış xətalar; yoxlama ilk xətada dayanmır.
+    pub errors: Vec<ValidatorError>,
+    /// Proqramı dayandırmayan xəbərdarlıqlar.
+    pub warnings: Vec<ValidatorError>,
+    pub lints: LintLevels,
+    /// `@allow(...)` ilə söndürülmüş xəbərdarlıqlar və aid olduqları ifadənin yeri.
+    allowed: Vec<(Lint, SourceSpan)>,
 }
 
 impl Validator {
+    pub fn with_lints(lints: LintLevels) -> Self {
+        Self {
+            lints,
+            ..Self::default()
+        }
+    }
     pub fn function_decl(&mut self, ast: &Vec<Statement>) -> &mut Validator {
         for stmt in ast {
-            match stmt {
-                Statement::FunctionDef {
+            match &stmt.kind {
+                StatementKind::FunctionDef {
                     name,
                     return_typ,
                     params,
-                    body,
+                    ..
                 } => {
                     self.functions.insert(
                         name.to_string(),
@@ -55,11 +76,12 @@ impl Validator {
                         },
                     );
                 }
-                Statement::ExternalFunctionDef {
+                StatementKind::ExternalFunctionDef {
                     name,
                     return_typ,
                     params,
                     library,
+                    ..
                 } => {
                     self.link_files.push(library.to_string());
                     self.functions.insert(
@@ -78,13 +100,20 @@ impl Validator {
     pub fn lookup_variable_mut_with_err(
         &mut self,
         var_name: &str,
+        span: &SourceSpan,
     ) -> Result<&mut Symbol, ValidatorError> {
-        for stack in self.variables.iter_mut().rev() {
-            if let Some(symbol) = stack.get_mut(var_name) {
-                return Ok(symbol);
-            }
+        let scope = self
+            .variables
+            .iter()
+            .rposition(|stack| stack.contains_key(var_name));
+        if let Some(scope) = scope {
+            return Ok(self.variables[scope].get_mut(var_name).unwrap());
         }
-        Err(ValidatorError::UndefinedVariable(var_name.to_string()))
+        Err(ValidatorError::UndefinedVariable(
+            span.clone(),
+            var_name.to_string(),
+            self.similar_variable(var_name),
+        ))
     }
     pub fn lookup_variable(&self, var_name: &str) -> Option<&Symbol> {
         for stack in self.variables.iter().rev() {
@@ -95,12 +124,85 @@ impl Validator {
         None
     }
 
+    /// Görünən dəyişənlərdən `name`-ə bənzəyənin adı.
+    pub fn similar_variable(&self, name: &str) -> Option<String> {
+        let names = self.variables.iter().flat_map(|scope| scope.keys());
+        similar_name(name, names.map(String::as_str)).map(str::to_string)
+    }
+
+    /// Məlum funksiyalardan `name`-ə bənzəyənin adı.
+    pub fn similar_function(&self, name: &str) -> Option<String> {
+        similar_name(name, self.functions.keys().map(String::as_str)).map(str::to_string)
+    }
+
+    pub fn report(&mut self, error: ValidatorError) {
+        self.errors.push(error);
+    }
+
+    /// Xəbərdarlığı səviyyəsinə görə `warnings`-ə və ya `errors`-a yazır.
+    pub fn lint(&mut self, error: ValidatorError) {
+        let Some(lint) = error.lint() else {
+            return self.report(error);
+        };
+        let allowed = self.allowed.iter().any(|(allowed, span)| {
+            *allowed == lint && error.span().is_some_and(|inner| span.contains(inner))
+        });
+        if allowed {
+            return;
+        }
+        match self.lints.level(lint) {
+            Level::Allow => {}
+            Level::Warn => self.warnings.push(error),
+            Level::Deny => self.errors.push(error),
+        }
+    }
+
+    /// İfadənin `@allow(...)` adlarını qeyd edir.
+    pub fn register_allow(&mut self, stmt: &Statement) {
+        for name in &stmt.allow {
+            match Lint::from_name(name) {
+                Some(lint) => self.allowed.push((lint, stmt.span.clone())),
+                None => self.report(ValidatorError::UnknownLint(
+                    stmt.span.clone(),
+                    name.to_string(),
+                )),
+            }
+        }
+    }
+
     pub fn declare_variable(&mut self, var_name: String, symbol: Symbol) {
         if let Some(stack) = self.variables.last_mut() {
             stack.insert(var_name, symbol);
         }
     }
 
+    /// Son görünmə sahəsini bağlayır və onun istifadə olunmayan və dəyişməyən
+    /// dəyişənlərini bildirir. Uğursuz ifadələrdəki istifadələr qeydə alınmır, ona görə
+    /// sahə açılandan (`errors_before`) bəri xəta olubsa, bu yoxlamalar buraxılır.
+    fn pop_scope(&mut self, errors_before: usize) {
+        let Some(scope) = self.variables.pop() else {
+            return;
+        };
+        if self.errors.len() != errors_before {
+            return;
+        }
+        let mut unused = Vec::new();
+        for (name, symbol) in scope {
+            if !symbol.is_used {
+                unused.push(ValidatorError::NotUsedVariable(symbol.span, name));
+            } else if symbol.is_mutable && !symbol.is_changed {
+                unused.push(ValidatorError::NeverChangedMuttableVariable(
+                    symbol.span,
+                    name,
+                ));
+            }
+        }
+        unused.sort_by_key(|error| error.span().map(|span| span.start));
+        for error in unused {
+            self.lint(error);
+        }
+    }
+
     pub fn validate(mut self, ast: Vec<Statement>) -> Result<(Validator, Program), ValidatorError> {
         let mut program = Program {
             functions: vec![],
@@ -108,14 +210,45 @@ impl Validator {
             external_functions: vec![],
         };
         self.variables.push(HashMap::new());
+        let mut defined: HashMap<&str, &SourceSpan> = HashMap::new();
+        for stmt in &ast {
+            if let StatementKind::FunctionDef { name, .. }
+            | StatementKind::ExternalFunctionDef { name, .. } = &stmt.kind
+            {
+                // `main` yuxarı səviyyəli ifadələrdən yaradılır, ona görə bu ad istifadəçiyə verilmir.
+                if name.as_ref() == "main" {
+                    self.report(ValidatorError::MainFunctionDefined(stmt.span.clone()));
+                } else if is_builtin(name) {
+                    self.report(ValidatorError::FunctionAlreadyDefined(
+                        stmt.span.clone(),
+                        name.to_string(),
+                        None,
+                    ));
+                } else if let Some(first) = defined.get(name.as_ref()) {
+                    self.report(ValidatorError::FunctionAlreadyDefined(
+                        stmt.span.clone(),
+                        name.to_string(),
+                        Some((*first).clone()),
+                    ));
+                } else {
+                    defined.insert(name.as_ref(), &stmt.span);
+                }
+            }
+        }
+        for (name, info) in builtin_functions() {
+            self.functions.insert(name.to_string(), info);
+        }
         self.function_decl(&ast);
         for stmt in ast {
-            match stmt {
-                Statement::ExternalFunctionDef {
+            self.register_allow(&stmt);
+            let span = stmt.span.clone();
+            match stmt.kind {
+                StatementKind::ExternalFunctionDef {
                     name,
                     return_typ,
                     params,
                     library,
+                    ..
                 } => {
                     self.functions.insert(
                         name.to_string(),
@@ -129,15 +262,17 @@ impl Validator {
                         params,
                         return_typ,
                         library: library.to_string(),
+                        span,
                     });
                 }
-                Statement::FunctionDef {
+                StatementKind::FunctionDef {
                     name,
                     return_typ,
                     params,
                     body,
+                    ..
                 } => {
-                    let mut validated_body = Vec::new();
+                    let errors_before = self.errors.len();
                     self.variables.push(HashMap::new());
                     for param in &params {
                         self.declare_variable(
@@ -147,39 +282,32 @@ impl Validator {
                                 is_mutable: param.is_pointer,
                                 is_used: false,
                                 is_changed: false,
+                                span: param.span.clone(),
                             },
                         );
                     }
-                    for s in body {
-                        validated_body.push(validate_statement(s, &mut self)?);
-                    }
-                    self.variables.pop();
+                    let validated_body = validate_body(body, &mut self);
+                    self.pop_scope(errors_before);
                     program.functions.push(Function {
                         name: name.to_string(),
                         body: validated_body,
                         params,
                         return_typ,
+                        span,
                     });
                 }
-                stmt => {
-                    program
-                        .expressions
-                        .push(validate_statement(stmt, &mut self)?);
-                }
+                kind => match validate_statement(Statement::new(kind, span), &mut self) {
+                    Ok(ast) => program.expressions.push(ast),
+                    Err(error) => self.report(error),
+                },
             }
         }
 
-        if let Some(scope) = self.variables.last() {
-            for (name, symbol) in scope {
-                if !symbol.is_used {
-                    return Err(ValidatorError::NotUsedVariable(name.clone()));
-                }
-                if symbol.is_mutable && !symbol.is_changed {
-                    return Err(ValidatorError::NeverChangedMuttableVariable(name.clone()));
-                }
-            }
-        }
+        self.pop_scope(0);
 
+        if !self.errors.is_empty() {
+            return Err(ValidatorError::collect(std::mem::take(&mut self.errors)));
+        }
         Ok((self, program))
     }
 }
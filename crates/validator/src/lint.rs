@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+/// Proqramı dayandırmayan, standart olaraq xəbərdarlıq kimi göstərilən yoxlamalar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// Elan edilib heç istifadə olunmamış dəyər.
+    Unused,
+    /// `var` ilə elan edilib heç dəyişdirilməmiş dəyər.
+    UnusedMut,
+}
+
+impl Lint {
+    pub const ALL: [Lint; 2] = [Lint::Unused, Lint::UnusedMut];
+
+    /// `-W`, `-D`, `-A` və `@allow(...)` içində işlədilən ad.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Lint::Unused => "unused",
+            Lint::UnusedMut => "unused_mut",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Lint> {
+        Lint::ALL.into_iter().find(|lint| lint.name() == name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Allow,
+    Warn,
+    Deny,
+}
+
+/// Hər xəbərdarlığın səviyyəsi. Təyin edilməyənlər `Warn` sayılır.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LintLevels {
+    levels: HashMap<Lint, Level>,
+}
+
+impl LintLevels {
+    pub fn set(&mut self, lint: Lint, level: Level) {
+        self.levels.insert(lint, level);
+    }
+
+    /// Adla səviyyə təyin edir; `warnings` bütün xəbərdarlıqları əhatə edir.
+    /// Naməlum ad üçün `false` qaytarır.
+    pub fn set_by_name(&mut self, name: &str, level: Level) -> bool {
+        if name == "warnings" {
+            for lint in Lint::ALL {
+                self.set(lint, level);
+            }
+            return true;
+        }
+        match Lint::from_name(name) {
+            Some(lint) => {
+                self.set(lint, level);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn level(&self, lint: Lint) -> Level {
+        self.levels.get(&lint).copied().unwrap_or(Level::Warn)
+    }
+}
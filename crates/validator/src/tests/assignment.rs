@@ -1,18 +1,17 @@
 use std::collections::HashMap;
 
-use crate::{validate::validate_statement, Validator, errors::ValidatorError};
+use crate::{Validator, errors::ValidatorError, validate::validate_statement};
 use parser::{
-    ast::{Atom, Expr, Statement, Symbol},
+    ast::{Atom, Expr, ExprKind, SourceSpan, Statement, StatementKind, Symbol},
     shared_ast::Type,
 };
-use std::assert_matches;
 // ─── Helpers ────────────────────────────────────────────────────────────────
 
 fn assign(name: &str, value: Expr) -> Statement {
-    Statement::Assignment {
+    Statement::from(StatementKind::Assignment {
         name: Atom::from(name),
         value: Box::new(value),
-    }
+    })
 }
 
 // ─── Tests ──────────────────────────────────────────────────────────────────
@@ -28,10 +27,11 @@ fn test_assignment_success() {
             is_mutable: true,
             is_used: false,
             is_changed: false,
+            span: SourceSpan::default(),
         },
     );
 
-    let result = validate_statement(assign("x", Expr::Number(2)), &mut validator);
+    let result = validate_statement(assign("x", Expr::from(ExprKind::Number(2))), &mut validator);
     assert!(result.is_ok(), "valid assignment should not fail");
 
     let symbol = validator
@@ -58,11 +58,15 @@ fn test_assignment_to_immutable() {
             is_mutable: false,
             is_used: false,
             is_changed: false,
+            span: SourceSpan::default(),
         },
     );
 
-    let result = validate_statement(assign("x", Expr::Number(2)), &mut validator);
-    assert_matches!(result, Err(ValidatorError::AssignmentToImmutableVariable(_)));
+    let result = validate_statement(assign("x", Expr::from(ExprKind::Number(2))), &mut validator);
+    assert!(matches!(
+        result,
+        Err(ValidatorError::AssignmentToImmutableVariable(_, _))
+    ));
 }
 
 #[test]
@@ -76,14 +80,15 @@ fn test_assignment_type_mismatch() {
             is_mutable: true,
             is_used: false,
             is_changed: false,
+            span: SourceSpan::default(),
         },
     );
 
     let result = validate_statement(
-        assign("x", Expr::String(Atom::from("salam"))),
+        assign("x", Expr::from(ExprKind::String(Atom::from("salam")))),
         &mut validator,
     );
-    assert_matches!(result, Err(ValidatorError::AssignmentTypeMismatch { .. }));
+    assert!(matches!(result, Err(ValidatorError::AssignmentTypeMismatch { .. })));
 }
 
 #[test]
@@ -91,6 +96,24 @@ fn test_assignment_undefined_variable() {
     let mut validator = Validator::default();
     validator.variables.push(HashMap::new());
 
-    let result = validate_statement(assign("x", Expr::Number(1)), &mut validator);
-    assert_matches!(result, Err(ValidatorError::UndefinedVariable(_)));
+    let result = validate_statement(assign("x", Expr::from(ExprKind::Number(1))), &mut validator);
+    assert!(matches!(result, Err(ValidatorError::UndefinedVariable(..))));
+}
+
+#[test]
+fn test_undefined_variable_error_points_at_use() {
+    let source = "const int a = 1\nexit(b)\n";
+    let ast = parser::parse_file("span.az", source).expect("parse edilmədi");
+
+    let err = Validator::default()
+        .validate(ast)
+        .expect_err("undefined variable should fail");
+    let ValidatorError::UndefinedVariable(span, name, _) = &err else {
+        panic!("UndefinedVariable gözlənilirdi, {err:?} alındı");
+    };
+    assert_eq!(name, "b");
+    assert_eq!(&source[span.start as usize..span.end as usize], "b");
+    let location = span.location().expect("fayl qeydə alınmalıdır");
+    assert_eq!((location.line, location.column), (2, 6));
+    assert!(err.to_string().starts_with("Sətir 2, sütun 6"));
 }
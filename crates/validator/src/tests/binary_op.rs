@@ -1,16 +1,15 @@
-#[cfg(test)]
 use crate::Validator;
 use parser::{
-    ast::{Atom, Expr, Operation, Statement},
+    ast::{Atom, Expr, ExprKind, Operation, Statement, StatementKind},
     shared_ast::{StringEnum, Type},
 };
 #[test]
 fn test_binary_op_add_integers() {
-    let stmt = Statement::Expr(Expr::BinaryOp {
-        left: Box::new(Expr::Number(2)),
-        right: Box::new(Expr::Number(3)),
+    let stmt = Statement::from(StatementKind::Expr(Expr::from(ExprKind::BinaryOp {
+        left: Box::new(Expr::from(ExprKind::Number(2))),
+        right: Box::new(Expr::from(ExprKind::Number(3))),
         op: Operation::Add,
-    });
+    })));
     let (_validator, program) = Validator::default()
         .validate(vec![stmt])
         .expect("valid program should not fail");
@@ -18,21 +17,23 @@ fn test_binary_op_add_integers() {
     assert_eq!(program.expressions.len(), 1);
     assert_eq!(
         program.expressions[0],
-        crate::ast::Ast::Expr(crate::ast::Expr::BinaryOp {
-            left: Box::new(crate::ast::Expr::Number(2)),
-            right: Box::new(crate::ast::Expr::Number(3)),
-            op: Operation::Add,
-            return_type: Type::Integer,
-        })
+        crate::ast::Ast::from(crate::ast::AstKind::Expr(crate::ast::Expr::from(
+            crate::ast::ExprKind::BinaryOp {
+                left: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(2))),
+                right: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(3))),
+                op: Operation::Add,
+                return_type: Type::Integer,
+            }
+        )))
     );
 }
 #[test]
 fn test_binary_op_add_strings() {
-    let stmt = Statement::Expr(Expr::BinaryOp {
-        left: Box::new(Expr::String(Atom::from("Salam"))),
-        right: Box::new(Expr::String(Atom::from("Yupiter"))),
+    let stmt = Statement::from(StatementKind::Expr(Expr::from(ExprKind::BinaryOp {
+        left: Box::new(Expr::from(ExprKind::String(Atom::from("Salam")))),
+        right: Box::new(Expr::from(ExprKind::String(Atom::from("Yupiter")))),
         op: Operation::Add,
-    });
+    })));
     let (_validator, program) = Validator::default()
         .validate(vec![stmt])
         .expect("valid program should not fail");
@@ -40,253 +41,392 @@ fn test_binary_op_add_strings() {
     assert_eq!(program.expressions.len(), 1);
     assert_eq!(
         program.expressions[0],
-        crate::ast::Ast::Expr(crate::ast::Expr::BinaryOp {
-            left: Box::new(crate::ast::Expr::String(String::from("Salam"))),
-            right: Box::new(crate::ast::Expr::String(String::from("Yupiter"))),
-            op: Operation::Add,
-            return_type: Type::String(StringEnum::DynamicString),
-        })
+        crate::ast::Ast::from(crate::ast::AstKind::Expr(crate::ast::Expr::from(
+            crate::ast::ExprKind::BinaryOp {
+                left: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::String(
+                    String::from("Salam")
+                ))),
+                right: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::String(
+                    String::from("Yupiter")
+                ))),
+                op: Operation::Add,
+                return_type: Type::String(StringEnum::DynamicString),
+            }
+        )))
     );
 }
 
 #[test]
 fn test_binary_op_subtract_integers() {
-    let stmt = Statement::Expr(Expr::BinaryOp {
-        left: Box::new(Expr::Number(10)),
-        right: Box::new(Expr::Number(4)),
+    let stmt = Statement::from(StatementKind::Expr(Expr::from(ExprKind::BinaryOp {
+        left: Box::new(Expr::from(ExprKind::Number(10))),
+        right: Box::new(Expr::from(ExprKind::Number(4))),
         op: Operation::Subtract,
-    });
+    })));
     let (_validator, program) = Validator::default()
         .validate(vec![stmt])
         .expect("valid program should not fail");
 
     assert_eq!(
         program.expressions[0],
-        crate::ast::Ast::Expr(crate::ast::Expr::BinaryOp {
-            left: Box::new(crate::ast::Expr::Number(10)),
-            right: Box::new(crate::ast::Expr::Number(4)),
-            op: Operation::Subtract,
-            return_type: Type::Integer,
-        })
+        crate::ast::Ast::from(crate::ast::AstKind::Expr(crate::ast::Expr::from(
+            crate::ast::ExprKind::BinaryOp {
+                left: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(10))),
+                right: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(4))),
+                op: Operation::Subtract,
+                return_type: Type::Integer,
+            }
+        )))
     );
 }
 
 #[test]
 fn test_binary_op_multiply_floats() {
-    let stmt = Statement::Expr(Expr::BinaryOp {
-        left: Box::new(Expr::Float(2.5)),
-        right: Box::new(Expr::Float(3.0)),
+    let stmt = Statement::from(StatementKind::Expr(Expr::from(ExprKind::BinaryOp {
+        left: Box::new(Expr::from(ExprKind::Float(2.5))),
+        right: Box::new(Expr::from(ExprKind::Float(3.0))),
         op: Operation::Multiply,
-    });
+    })));
     let (_validator, program) = Validator::default()
         .validate(vec![stmt])
         .expect("valid program should not fail");
 
     assert_eq!(
         program.expressions[0],
-        crate::ast::Ast::Expr(crate::ast::Expr::BinaryOp {
-            left: Box::new(crate::ast::Expr::Float(2.5)),
-            right: Box::new(crate::ast::Expr::Float(3.0)),
-            op: Operation::Multiply,
-            return_type: Type::Float,
-        })
+        crate::ast::Ast::from(crate::ast::AstKind::Expr(crate::ast::Expr::from(
+            crate::ast::ExprKind::BinaryOp {
+                left: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Float(2.5))),
+                right: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Float(3.0))),
+                op: Operation::Multiply,
+                return_type: Type::Float,
+            }
+        )))
     );
 }
 
 #[test]
 fn test_binary_op_mixed_int_float() {
-    let stmt = Statement::Expr(Expr::BinaryOp {
-        left: Box::new(Expr::Number(5)),
-        right: Box::new(Expr::Float(2.0)),
+    let stmt = Statement::from(StatementKind::Expr(Expr::from(ExprKind::BinaryOp {
+        left: Box::new(Expr::from(ExprKind::Number(5))),
+        right: Box::new(Expr::from(ExprKind::Float(2.0))),
         op: Operation::Add,
-    });
+    })));
     let (_validator, program) = Validator::default()
         .validate(vec![stmt])
         .expect("valid program should not fail");
 
     assert_eq!(
         program.expressions[0],
-        crate::ast::Ast::Expr(crate::ast::Expr::BinaryOp {
-            left: Box::new(crate::ast::Expr::Number(5)),
-            right: Box::new(crate::ast::Expr::Float(2.0)),
-            op: Operation::Add,
-            return_type: Type::Float,
-        })
+        crate::ast::Ast::from(crate::ast::AstKind::Expr(crate::ast::Expr::from(
+            crate::ast::ExprKind::BinaryOp {
+                left: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(5))),
+                right: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Float(2.0))),
+                op: Operation::Add,
+                return_type: Type::Float,
+            }
+        )))
     );
 }
 
 #[test]
 fn test_binary_op_divide_integers() {
-    let stmt = Statement::Expr(Expr::BinaryOp {
-        left: Box::new(Expr::Number(10)),
-        right: Box::new(Expr::Number(3)),
+    let stmt = Statement::from(StatementKind::Expr(Expr::from(ExprKind::BinaryOp {
+        left: Box::new(Expr::from(ExprKind::Number(10))),
+        right: Box::new(Expr::from(ExprKind::Number(3))),
         op: Operation::Divide,
-    });
+    })));
     let (_validator, program) = Validator::default()
         .validate(vec![stmt])
         .expect("valid program should not fail");
 
     assert_eq!(
         program.expressions[0],
-        crate::ast::Ast::Expr(crate::ast::Expr::BinaryOp {
-            left: Box::new(crate::ast::Expr::Number(10)),
-            right: Box::new(crate::ast::Expr::Number(3)),
-            op: Operation::Divide,
-            return_type: Type::Integer,
-        })
+        crate::ast::Ast::from(crate::ast::AstKind::Expr(crate::ast::Expr::from(
+            crate::ast::ExprKind::BinaryOp {
+                left: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(10))),
+                right: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(3))),
+                op: Operation::Divide,
+                return_type: Type::Integer,
+            }
+        )))
     );
 }
 
 #[test]
 fn test_binary_op_equal_comparison() {
-    let stmt = Statement::Expr(Expr::BinaryOp {
-        left: Box::new(Expr::Number(5)),
-        right: Box::new(Expr::Number(5)),
+    let stmt = Statement::from(StatementKind::Expr(Expr::from(ExprKind::BinaryOp {
+        left: Box::new(Expr::from(ExprKind::Number(5))),
+        right: Box::new(Expr::from(ExprKind::Number(5))),
         op: Operation::Equal,
-    });
+    })));
     let (_validator, program) = Validator::default()
         .validate(vec![stmt])
         .expect("valid program should not fail");
 
     assert_eq!(
         program.expressions[0],
-        crate::ast::Ast::Expr(crate::ast::Expr::BinaryOp {
-            left: Box::new(crate::ast::Expr::Number(5)),
-            right: Box::new(crate::ast::Expr::Number(5)),
-            op: Operation::Equal,
-            return_type: Type::Bool,
-        })
+        crate::ast::Ast::from(crate::ast::AstKind::Expr(crate::ast::Expr::from(
+            crate::ast::ExprKind::BinaryOp {
+                left: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(5))),
+                right: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(5))),
+                op: Operation::Equal,
+                return_type: Type::Bool,
+            }
+        )))
     );
 }
 
 #[test]
 fn test_binary_op_not_equal_comparison() {
-    let stmt = Statement::Expr(Expr::BinaryOp {
-        left: Box::new(Expr::Number(5)),
-        right: Box::new(Expr::Number(3)),
+    let stmt = Statement::from(StatementKind::Expr(Expr::from(ExprKind::BinaryOp {
+        left: Box::new(Expr::from(ExprKind::Number(5))),
+        right: Box::new(Expr::from(ExprKind::Number(3))),
         op: Operation::NotEqual,
-    });
+    })));
     let (_validator, program) = Validator::default()
         .validate(vec![stmt])
         .expect("valid program should not fail");
 
     assert_eq!(
         program.expressions[0],
-        crate::ast::Ast::Expr(crate::ast::Expr::BinaryOp {
-            left: Box::new(crate::ast::Expr::Number(5)),
-            right: Box::new(crate::ast::Expr::Number(3)),
-            op: Operation::NotEqual,
-            return_type: Type::Bool,
-        })
+        crate::ast::Ast::from(crate::ast::AstKind::Expr(crate::ast::Expr::from(
+            crate::ast::ExprKind::BinaryOp {
+                left: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(5))),
+                right: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(3))),
+                op: Operation::NotEqual,
+                return_type: Type::Bool,
+            }
+        )))
     );
 }
 
 #[test]
 fn test_binary_op_greater_comparison() {
-    let stmt = Statement::Expr(Expr::BinaryOp {
-        left: Box::new(Expr::Number(10)),
-        right: Box::new(Expr::Number(5)),
+    let stmt = Statement::from(StatementKind::Expr(Expr::from(ExprKind::BinaryOp {
+        left: Box::new(Expr::from(ExprKind::Number(10))),
+        right: Box::new(Expr::from(ExprKind::Number(5))),
         op: Operation::Greater,
-    });
+    })));
     let (_validator, program) = Validator::default()
         .validate(vec![stmt])
         .expect("valid program should not fail");
 
     assert_eq!(
         program.expressions[0],
-        crate::ast::Ast::Expr(crate::ast::Expr::BinaryOp {
-            left: Box::new(crate::ast::Expr::Number(10)),
-            right: Box::new(crate::ast::Expr::Number(5)),
-            op: Operation::Greater,
-            return_type: Type::Bool,
-        })
+        crate::ast::Ast::from(crate::ast::AstKind::Expr(crate::ast::Expr::from(
+            crate::ast::ExprKind::BinaryOp {
+                left: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(10))),
+                right: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(5))),
+                op: Operation::Greater,
+                return_type: Type::Bool,
+            }
+        )))
     );
 }
 
 #[test]
 fn test_binary_op_less_equal_comparison() {
-    let stmt = Statement::Expr(Expr::BinaryOp {
-        left: Box::new(Expr::Number(3)),
-        right: Box::new(Expr::Number(3)),
+    let stmt = Statement::from(StatementKind::Expr(Expr::from(ExprKind::BinaryOp {
+        left: Box::new(Expr::from(ExprKind::Number(3))),
+        right: Box::new(Expr::from(ExprKind::Number(3))),
         op: Operation::LessEqual,
-    });
+    })));
     let (_validator, program) = Validator::default()
         .validate(vec![stmt])
         .expect("valid program should not fail");
 
     assert_eq!(
         program.expressions[0],
-        crate::ast::Ast::Expr(crate::ast::Expr::BinaryOp {
-            left: Box::new(crate::ast::Expr::Number(3)),
-            right: Box::new(crate::ast::Expr::Number(3)),
-            op: Operation::LessEqual,
-            return_type: Type::Bool,
-        })
+        crate::ast::Ast::from(crate::ast::AstKind::Expr(crate::ast::Expr::from(
+            crate::ast::ExprKind::BinaryOp {
+                left: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(3))),
+                right: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(3))),
+                op: Operation::LessEqual,
+                return_type: Type::Bool,
+            }
+        )))
     );
 }
 
 #[test]
 fn test_binary_op_logical_and() {
-    let stmt = Statement::Expr(Expr::BinaryOp {
-        left: Box::new(Expr::Bool(true)),
-        right: Box::new(Expr::Bool(false)),
+    let stmt = Statement::from(StatementKind::Expr(Expr::from(ExprKind::BinaryOp {
+        left: Box::new(Expr::from(ExprKind::Bool(true))),
+        right: Box::new(Expr::from(ExprKind::Bool(false))),
         op: Operation::And,
-    });
+    })));
     let (_validator, program) = Validator::default()
         .validate(vec![stmt])
         .expect("valid program should not fail");
 
     assert_eq!(
         program.expressions[0],
-        crate::ast::Ast::Expr(crate::ast::Expr::BinaryOp {
-            left: Box::new(crate::ast::Expr::Bool(true)),
-            right: Box::new(crate::ast::Expr::Bool(false)),
-            op: Operation::And,
-            return_type: Type::Bool,
-        })
+        crate::ast::Ast::from(crate::ast::AstKind::Expr(crate::ast::Expr::from(
+            crate::ast::ExprKind::BinaryOp {
+                left: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Bool(true))),
+                right: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Bool(false))),
+                op: Operation::And,
+                return_type: Type::Bool,
+            }
+        )))
     );
 }
 
 #[test]
 fn test_binary_op_logical_or() {
-    let stmt = Statement::Expr(Expr::BinaryOp {
-        left: Box::new(Expr::Bool(true)),
-        right: Box::new(Expr::Bool(false)),
+    let stmt = Statement::from(StatementKind::Expr(Expr::from(ExprKind::BinaryOp {
+        left: Box::new(Expr::from(ExprKind::Bool(true))),
+        right: Box::new(Expr::from(ExprKind::Bool(false))),
         op: Operation::Or,
-    });
+    })));
     let (_validator, program) = Validator::default()
         .validate(vec![stmt])
         .expect("valid program should not fail");
 
     assert_eq!(
         program.expressions[0],
-        crate::ast::Ast::Expr(crate::ast::Expr::BinaryOp {
-            left: Box::new(crate::ast::Expr::Bool(true)),
-            right: Box::new(crate::ast::Expr::Bool(false)),
-            op: Operation::Or,
-            return_type: Type::Bool,
-        })
+        crate::ast::Ast::from(crate::ast::AstKind::Expr(crate::ast::Expr::from(
+            crate::ast::ExprKind::BinaryOp {
+                left: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Bool(true))),
+                right: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Bool(false))),
+                op: Operation::Or,
+                return_type: Type::Bool,
+            }
+        )))
     );
 }
 
 #[test]
 fn test_binary_op_modulo() {
-    let stmt = Statement::Expr(Expr::BinaryOp {
-        left: Box::new(Expr::Number(10)),
-        right: Box::new(Expr::Number(3)),
+    let stmt = Statement::from(StatementKind::Expr(Expr::from(ExprKind::BinaryOp {
+        left: Box::new(Expr::from(ExprKind::Number(10))),
+        right: Box::new(Expr::from(ExprKind::Number(3))),
         op: Operation::Modulo,
-    });
+    })));
     let (_validator, program) = Validator::default()
         .validate(vec![stmt])
         .expect("valid program should not fail");
 
     assert_eq!(
         program.expressions[0],
-        crate::ast::Ast::Expr(crate::ast::Expr::BinaryOp {
-            left: Box::new(crate::ast::Expr::Number(10)),
-            right: Box::new(crate::ast::Expr::Number(3)),
-            op: Operation::Modulo,
-            return_type: Type::Integer,
-        })
+        crate::ast::Ast::from(crate::ast::AstKind::Expr(crate::ast::Expr::from(
+            crate::ast::ExprKind::BinaryOp {
+                left: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(10))),
+                right: Box::new(crate::ast::Expr::from(crate::ast::ExprKind::Number(3))),
+                op: Operation::Modulo,
+                return_type: Type::Integer,
+            }
+        )))
     );
 }
+
+#[test]
+fn test_unary_minus_keeps_operand_type() {
+    let ast = parser::parse_file(
+        "neg.az",
+        "const float f = 2.5\nconst float g = -(f + 1.0)\nconst bigint b = 1bigint\nconst bigint c = -b\n",
+    )
+    .expect("parse edilmədi");
+    assert!(Validator::default().validate(ast).is_ok());
+}
+
+#[test]
+fn test_unary_minus_needs_a_number() {
+    let ast = parser::parse_file("neg.az", "const bool b = true\nconst bool c = -b\n")
+        .expect("parse edilmədi");
+    let error = Validator::default().validate(ast).unwrap_err();
+    assert!(matches!(
+        error,
+        crate::errors::ValidatorError::TypeMismatch {
+            expected: Type::Integer,
+            found: Type::Bool,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_member_access_has_no_fields_yet() {
+    let ast = parser::parse_file("member.az", "const int say = 1\nconst int x = say.dəyər\n")
+        .expect("parse edilmədi");
+    let error = Validator::default().validate(ast).unwrap_err();
+    assert!(matches!(
+        error,
+        crate::errors::ValidatorError::UnknownField(_, Type::Integer, ref name) if name == "dəyər"
+    ));
+}
+
+#[test]
+fn test_untyped_literal_takes_the_other_integer_type() {
+    for source in [
+        "var natural n = 5\nn = n - 1\n",
+        "const bigint b = 1bigint\nconst bigint c = b + 1\n",
+        "const tinyint t = 3tinyint\nconst tinyint u = t * 2\n",
+        "const bigint b = 1bigint\nconst bigint c = 5000000000 - b\n",
+    ] {
+        let ast = parser::parse_file("literal.az", source).expect("parse edilmədi");
+        let result = Validator::default().validate(ast);
+        assert!(result.is_ok(), "{source}: {:?}", result.err());
+    }
+}
+
+#[test]
+fn test_exit_code_must_be_int() {
+    let ast = parser::parse_file("exit.az", "const bigint b = 1bigint\nexit(b + 1)\n")
+        .expect("parse edilmədi");
+    let error = Validator::default().validate(ast).unwrap_err();
+    assert!(matches!(
+        error,
+        crate::errors::ValidatorError::AssignmentTypeMismatch { ref expected, ref found, .. }
+            if *expected == Type::Integer.to_string() && *found == Type::BigInteger.to_string()
+    ));
+}
+
+#[test]
+fn test_literal_outside_the_other_type_is_a_mismatch() {
+    let ast = parser::parse_file(
+        "literal.az",
+        "const tinyint t = 3tinyint\nconst tinyint u = t * 300\n",
+    )
+    .expect("parse edilmədi");
+    let error = Validator::default().validate(ast).unwrap_err();
+    assert!(matches!(
+        error,
+        crate::errors::ValidatorError::TypeMismatch {
+            expected: Type::LowInteger,
+            found: Type::Integer,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_untyped_literal_is_checked_against_its_type() {
+    let error = |source: &str| {
+        let ast = parser::parse_file("range.az", source).expect("parse edilmədi");
+        Validator::default().validate(ast).unwrap_err()
+    };
+    assert!(matches!(
+        error("const int x = 5000000000\n"),
+        crate::errors::ValidatorError::NumberOutOfRange(_, 5000000000, Type::Integer)
+    ));
+    assert!(matches!(
+        error("const natural n = -1\n"),
+        crate::errors::ValidatorError::NumberOutOfRange(_, -1, Type::Natural)
+    ));
+    assert!(matches!(
+        error("exit(5000000000)\n"),
+        crate::errors::ValidatorError::NumberOutOfRange(_, 5000000000, Type::Integer)
+    ));
+
+    for source in [
+        "const bigint b = 5000000000\n",
+        "const tinyint t = -128\n",
+        "const tinyint t = -128tinyint\n",
+        "const bigint b = -9223372036854775808\n",
+    ] {
+        let ast = parser::parse_file("range.az", source).expect("parse edilmədi");
+        let result = Validator::default().validate(ast);
+        assert!(result.is_ok(), "{source}: {:?}", result.err());
+    }
+}
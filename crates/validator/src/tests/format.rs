@@ -0,0 +1,51 @@
+use parser::shared_ast::Type;
+
+use crate::{Validator, errors::ValidatorError};
+
+fn validate(source: &str) -> Result<(), ValidatorError> {
+    let ast = parser::parse_file("format.az", source).expect("parse edilmədi");
+    Validator::default().validate(ast).map(|_| ())
+}
+
+#[test]
+fn test_format_specs_that_fit_their_types() {
+    let source = "const float price = 2.5\nconst int n = 42\nconst str name = \"Əli\"\n\
+                  const str s = `${price:.2} ${n:>5} ${n:x} ${n:08b} ${name:*^9} ${price:010.3}`\n";
+    assert_eq!(validate(source), Ok(()));
+}
+
+#[test]
+fn test_precision_needs_a_float() {
+    let error = validate("const int n = 1\nconst str s = `${n:.2}`\n").unwrap_err();
+    assert!(matches!(
+        error,
+        ValidatorError::FormatSpecMismatch(_, ref spec, Type::Integer) if spec == ".2"
+    ));
+}
+
+#[test]
+fn test_radix_needs_an_integer() {
+    let error = validate("const float f = 1.0\nconst str s = `${f:x}`\n").unwrap_err();
+    assert!(matches!(error, ValidatorError::FormatSpecMismatch(..)));
+    let error = validate("const str t = \"a\"\nconst str s = `${t:05}`\n").unwrap_err();
+    assert!(matches!(error, ValidatorError::FormatSpecMismatch(..)));
+}
+
+#[test]
+fn test_lists_are_not_formattable() {
+    let error = validate("const list<int> xs = [1]\nconst str s = `${xs}`\n").unwrap_err();
+    assert!(matches!(
+        error,
+        ValidatorError::NotFormattable(_, Type::Array(_))
+    ));
+}
+
+#[test]
+fn test_nested_template_is_validated() {
+    assert_eq!(
+        validate("const int n = 1\nconst str s = `a ${`b ${n:>3}`:<10} c`\n"),
+        Ok(())
+    );
+    let error = validate("const int n = 1\nconst str s = `a ${`b ${n:.1}`}`\n").unwrap_err();
+    assert!(matches!(error, ValidatorError::FormatSpecMismatch(..)));
+}
@@ -1,9 +1,12 @@
-use crate::{Validator, ast::Ast, ast::Expr as ValidatorExpr, errors::ValidatorError};
+use crate::{
+    Validator,
+    ast::{AstKind, Expr as ValidatorExpr, ExprKind as ValidatorExprKind},
+    errors::ValidatorError,
+};
 use parser::{
-    ast::{Atom, Expr, Parameter, Statement},
+    ast::{Atom, Expr, ExprKind, Parameter, SourceSpan, Statement, StatementKind},
     shared_ast::Type,
 };
-use std::assert_matches;
 
 fn make_func(
     name: &str,
@@ -11,26 +14,28 @@ fn make_func(
     params: Vec<Parameter>,
     body: Vec<Statement>,
 ) -> Statement {
-    Statement::FunctionDef {
+    Statement::from(StatementKind::FunctionDef {
         name: Atom::from(name),
         return_typ,
+        return_type_span: SourceSpan::default(),
         params,
         body,
-    }
+        doc: None,
+    })
 }
 
 #[test]
 fn test_function_call_success() {
     let stmts = vec![
         make_func("foo", Type::Integer, vec![], vec![]),
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("foo"),
                 symbol: None,
-            }),
+            })),
             args: vec![],
-        }),
+        }))),
     ];
     let result = Validator::default().validate(stmts);
     assert!(result.is_ok());
@@ -47,23 +52,30 @@ fn test_function_call_with_args() {
                     name: Atom::from("a"),
                     typ: Type::Integer,
                     is_pointer: false,
+                    span: SourceSpan::default(),
+                    type_span: SourceSpan::default(),
                 },
                 Parameter {
                     name: Atom::from("b"),
                     typ: Type::Integer,
                     is_pointer: false,
+                    span: SourceSpan::default(),
+                    type_span: SourceSpan::default(),
                 },
             ],
             vec![],
         ),
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("add"),
                 symbol: None,
-            }),
-            args: vec![Expr::Number(1), Expr::Number(2)],
-        }),
+            })),
+            args: vec![
+                Expr::from(ExprKind::Number(1)),
+                Expr::from(ExprKind::Number(2)),
+            ],
+        }))),
     ];
     let result = Validator::default().validate(stmts);
     assert!(result.is_ok());
@@ -71,47 +83,57 @@ fn test_function_call_with_args() {
 
 #[test]
 fn test_function_call_not_found() {
-    let stmts = vec![Statement::Expr(Expr::Call {
-        target: None,
-        name: Box::new(Expr::VariableRef {
-            name: Atom::from("nonexistent"),
-            symbol: None,
-        }),
-        args: vec![],
-    })];
+    let stmts = vec![Statement::from(StatementKind::Expr(Expr::from(
+        ExprKind::Call {
+            target: None,
+            name: Box::new(Expr::from(ExprKind::VariableRef {
+                name: Atom::from("nonexistent"),
+                symbol: None,
+            })),
+            args: vec![],
+        },
+    )))];
     let result = Validator::default().validate(stmts);
-    assert_matches!(result, Err(ValidatorError::FunctionNotFound(_)));
+    assert!(matches!(result, Err(ValidatorError::FunctionNotFound(..))));
 }
 
 #[test]
 fn test_function_call_invalid_name() {
-    let stmts = vec![Statement::Expr(Expr::Call {
-        target: None,
-        name: Box::new(Expr::Number(42)),
-        args: vec![],
-    })];
+    let stmts = vec![Statement::from(StatementKind::Expr(Expr::from(
+        ExprKind::Call {
+            target: None,
+            name: Box::new(Expr::from(ExprKind::Number(42))),
+            args: vec![],
+        },
+    )))];
     let result = Validator::default().validate(stmts);
-    assert_matches!(result, Err(ValidatorError::InvalidFunctionCall(_)));
+    assert!(matches!(
+        result,
+        Err(ValidatorError::InvalidFunctionCall(_, _))
+    ));
 }
 
 #[test]
 fn test_function_call_return_type_in_result() {
     let stmts = vec![
         make_func("foo", Type::Integer, vec![], vec![]),
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("foo"),
                 symbol: None,
-            }),
+            })),
             args: vec![],
-        }),
+        }))),
     ];
     let (_validator, program) = Validator::default()
         .validate(stmts)
         .expect("should validate");
-    let returned_type = match &program.expressions[0] {
-        Ast::Expr(ValidatorExpr::Call { returned_type, .. }) => returned_type,
+    let returned_type = match &program.expressions[0].kind {
+        AstKind::Expr(ValidatorExpr {
+            kind: ValidatorExprKind::Call { returned_type, .. },
+            ..
+        }) => returned_type,
         other => panic!("expected Call, got {other:?}"),
     };
     assert_eq!(*returned_type, Type::Integer);
@@ -121,20 +143,23 @@ fn test_function_call_return_type_in_result() {
 fn test_function_call_return_type_bool() {
     let stmts = vec![
         make_func("is_valid", Type::Bool, vec![], vec![]),
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("is_valid"),
                 symbol: None,
-            }),
+            })),
             args: vec![],
-        }),
+        }))),
     ];
     let (_validator, program) = Validator::default()
         .validate(stmts)
         .expect("should validate");
-    let returned_type = match &program.expressions[0] {
-        Ast::Expr(ValidatorExpr::Call { returned_type, .. }) => returned_type,
+    let returned_type = match &program.expressions[0].kind {
+        AstKind::Expr(ValidatorExpr {
+            kind: ValidatorExprKind::Call { returned_type, .. },
+            ..
+        }) => returned_type,
         other => panic!("expected Call, got {other:?}"),
     };
     assert_eq!(*returned_type, Type::Bool);
@@ -151,26 +176,33 @@ fn test_function_call_wrong_arg_count_too_few() {
                     name: Atom::from("a"),
                     typ: Type::Integer,
                     is_pointer: false,
+                    span: SourceSpan::default(),
+                    type_span: SourceSpan::default(),
                 },
                 Parameter {
                     name: Atom::from("b"),
                     typ: Type::Integer,
                     is_pointer: false,
+                    span: SourceSpan::default(),
+                    type_span: SourceSpan::default(),
                 },
             ],
             vec![],
         ),
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("add"),
                 symbol: None,
-            }),
-            args: vec![Expr::Number(1)],
-        }),
+            })),
+            args: vec![Expr::from(ExprKind::Number(1))],
+        }))),
     ];
     let result = Validator::default().validate(stmts);
-    assert_matches!(result, Err(ValidatorError::InvalidArgumentCount { .. }));
+    assert!(matches!(
+        result,
+        Err(ValidatorError::InvalidArgumentCount { .. })
+    ));
 }
 
 #[test]
@@ -183,20 +215,29 @@ fn test_function_call_wrong_arg_count_too_many() {
                 name: Atom::from("a"),
                 typ: Type::Integer,
                 is_pointer: false,
+                span: SourceSpan::default(),
+                type_span: SourceSpan::default(),
             }],
             vec![],
         ),
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("foo"),
                 symbol: None,
-            }),
-            args: vec![Expr::Number(1), Expr::Number(2), Expr::Number(3)],
-        }),
+            })),
+            args: vec![
+                Expr::from(ExprKind::Number(1)),
+                Expr::from(ExprKind::Number(2)),
+                Expr::from(ExprKind::Number(3)),
+            ],
+        }))),
     ];
     let result = Validator::default().validate(stmts);
-    assert_matches!(result, Err(ValidatorError::InvalidArgumentCount { .. }));
+    assert!(matches!(
+        result,
+        Err(ValidatorError::InvalidArgumentCount { .. })
+    ));
 }
 
 #[test]
@@ -209,20 +250,25 @@ fn test_function_call_wrong_arg_type() {
                 name: Atom::from("a"),
                 typ: Type::Integer,
                 is_pointer: false,
+                span: SourceSpan::default(),
+                type_span: SourceSpan::default(),
             }],
             vec![],
         ),
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("foo"),
                 symbol: None,
-            }),
-            args: vec![Expr::String(Atom::from("hello"))],
-        }),
+            })),
+            args: vec![Expr::from(ExprKind::String(Atom::from("hello")))],
+        }))),
     ];
     let result = Validator::default().validate(stmts);
-    assert_matches!(result, Err(ValidatorError::InvalidArgumentType { .. }));
+    assert!(matches!(
+        result,
+        Err(ValidatorError::InvalidArgumentType { .. })
+    ));
 }
 
 #[test]
@@ -236,26 +282,36 @@ fn test_function_call_multiple_args_type_check() {
                     name: Atom::from("a"),
                     typ: Type::Integer,
                     is_pointer: false,
+                    span: SourceSpan::default(),
+                    type_span: SourceSpan::default(),
                 },
                 Parameter {
                     name: Atom::from("b"),
                     typ: Type::Integer,
                     is_pointer: false,
+                    span: SourceSpan::default(),
+                    type_span: SourceSpan::default(),
                 },
             ],
             vec![],
         ),
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("add"),
                 symbol: None,
-            }),
-            args: vec![Expr::Number(1), Expr::String(Atom::from("wrong"))],
-        }),
+            })),
+            args: vec![
+                Expr::from(ExprKind::Number(1)),
+                Expr::from(ExprKind::String(Atom::from("wrong"))),
+            ],
+        }))),
     ];
     let result = Validator::default().validate(stmts);
-    assert_matches!(result, Err(ValidatorError::InvalidArgumentType { .. }));
+    assert!(matches!(
+        result,
+        Err(ValidatorError::InvalidArgumentType { .. })
+    ));
 }
 
 fn make_external_func(
@@ -265,12 +321,14 @@ fn make_external_func(
     library: &str,
     symbol: &str,
 ) -> Statement {
-    Statement::ExternalFunctionDef {
+    Statement::from(StatementKind::ExternalFunctionDef {
         name: Atom::from(name),
         return_typ,
+        return_type_span: SourceSpan::default(),
         params,
         library: Atom::from(library),
-    }
+        doc: None,
+    })
 }
 
 #[test]
@@ -283,18 +341,20 @@ fn test_external_function_call_success() {
                 name: Atom::from("a"),
                 typ: Type::Integer,
                 is_pointer: false,
+                span: SourceSpan::default(),
+                type_span: SourceSpan::default(),
             }],
             "c",
             "add",
         ),
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("add"),
                 symbol: None,
-            }),
-            args: vec![Expr::Number(42)],
-        }),
+            })),
+            args: vec![Expr::from(ExprKind::Number(42))],
+        }))),
     ];
     let result = Validator::default().validate(stmts);
     assert!(result.is_ok());
@@ -311,27 +371,34 @@ fn test_external_function_call_wrong_arg_count() {
                     name: Atom::from("a"),
                     typ: Type::Integer,
                     is_pointer: false,
+                    span: SourceSpan::default(),
+                    type_span: SourceSpan::default(),
                 },
                 Parameter {
                     name: Atom::from("b"),
                     typ: Type::Integer,
                     is_pointer: false,
+                    span: SourceSpan::default(),
+                    type_span: SourceSpan::default(),
                 },
             ],
             "c",
             "foo",
         ),
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("foo"),
                 symbol: None,
-            }),
-            args: vec![Expr::Number(1)],
-        }),
+            })),
+            args: vec![Expr::from(ExprKind::Number(1))],
+        }))),
     ];
     let result = Validator::default().validate(stmts);
-    assert_matches!(result, Err(ValidatorError::InvalidArgumentCount { .. }));
+    assert!(matches!(
+        result,
+        Err(ValidatorError::InvalidArgumentCount { .. })
+    ));
 }
 
 #[test]
@@ -344,35 +411,40 @@ fn test_external_function_call_wrong_arg_type() {
                 name: Atom::from("x"),
                 typ: Type::Integer,
                 is_pointer: false,
+                span: SourceSpan::default(),
+                type_span: SourceSpan::default(),
             }],
             "c",
             "print_int",
         ),
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("print_int"),
                 symbol: None,
-            }),
-            args: vec![Expr::String(Atom::from("hello"))],
-        }),
+            })),
+            args: vec![Expr::from(ExprKind::String(Atom::from("hello")))],
+        }))),
     ];
     let result = Validator::default().validate(stmts);
-    assert_matches!(result, Err(ValidatorError::InvalidArgumentType { .. }));
+    assert!(matches!(
+        result,
+        Err(ValidatorError::InvalidArgumentType { .. })
+    ));
 }
 
 #[test]
 fn test_external_function_call_no_args() {
     let stmts = vec![
         make_external_func("get_time", Type::Integer, vec![], "c", "time"),
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("get_time"),
                 symbol: None,
-            }),
+            })),
             args: vec![],
-        }),
+        }))),
     ];
     let result = Validator::default().validate(stmts);
     assert!(result.is_ok());
@@ -381,32 +453,36 @@ fn test_external_function_call_no_args() {
 #[test]
 fn test_external_func_link_name_passed_through() {
     let stmts = vec![
-        Statement::ExternalFunctionDef {
+        Statement::from(StatementKind::ExternalFunctionDef {
             name: Atom::from("print"),
             return_typ: Type::Void,
+            return_type_span: SourceSpan::default(),
             params: vec![Parameter {
                 name: Atom::from("val"),
                 typ: Type::Any,
                 is_pointer: false,
+                span: SourceSpan::default(),
+                type_span: SourceSpan::default(),
             }],
             library: Atom::from("../build/printlib.so"),
-        },
-        Statement::Expr(Expr::Call {
+            doc: None,
+        }),
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("print"),
                 symbol: None,
-            }),
-            args: vec![Expr::Number(42)],
-        }),
+            })),
+            args: vec![Expr::from(ExprKind::Number(42))],
+        }))),
     ];
     let (_validator, program) = Validator::default()
         .validate(stmts)
         .expect("should validate with link_name");
     assert_eq!(program.external_functions.len(), 1);
     assert_eq!(
-        program.external_functions[0].link_name,
-        Some("printlib".to_string())
+        program.external_functions[0].library,
+        "../build/printlib.so"
     );
 }
 
@@ -420,21 +496,97 @@ fn test_external_func_link_name_none_when_omitted() {
                 name: Atom::from("val"),
                 typ: Type::Any,
                 is_pointer: false,
+                span: SourceSpan::default(),
+                type_span: SourceSpan::default(),
             }],
             "../build/printlib.so",
             "printValue",
         ),
-        Statement::Expr(Expr::Call {
+        Statement::from(StatementKind::Expr(Expr::from(ExprKind::Call {
             target: None,
-            name: Box::new(Expr::VariableRef {
+            name: Box::new(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("print"),
                 symbol: None,
-            }),
-            args: vec![Expr::Number(42)],
-        }),
+            })),
+            args: vec![Expr::from(ExprKind::Number(42))],
+        }))),
     ];
     let (_validator, program) = Validator::default()
         .validate(stmts)
         .expect("should validate without link_name");
     assert_eq!(program.external_functions.len(), 1);
 }
+
+#[test]
+fn test_env_takes_a_string_and_returns_a_borrowed_one() {
+    let ast = parser::parse_file("env.az", "env(5)\n").expect("parse edilmədi");
+    let error = Validator::default().validate(ast).unwrap_err();
+    assert!(matches!(
+        error,
+        ValidatorError::InvalidArgumentType { ref name, .. } if name == "env"
+    ));
+
+    let ast = parser::parse_file("env.az", "env(\"HOME\")\n").expect("parse edilmədi");
+    let (_, program) = Validator::default()
+        .validate(ast)
+        .expect("validate edilmədi");
+    let AstKind::Expr(ValidatorExpr {
+        kind: ValidatorExprKind::Call { returned_type, .. },
+        ..
+    }) = &program.expressions[0].kind
+    else {
+        panic!("çağırış gözlənilirdi");
+    };
+    assert_eq!(
+        *returned_type,
+        Type::String(parser::shared_ast::StringEnum::LiteralConstString)
+    );
+
+    let ast = parser::parse_file("env.az", "const str home = env(\"HOME\")\nexit(0)\n")
+        .expect("parse edilmədi");
+    assert!(Validator::default().validate(ast).is_ok());
+}
+
+#[test]
+fn test_args_returns_a_list_of_borrowed_strings() {
+    let source = "const list<str> argv = args()\nconst str first = argv[0]\nexit(len(argv))\n";
+    let ast = parser::parse_file("args.az", source).expect("parse edilmədi");
+    let (_, program) = Validator::default()
+        .validate(ast)
+        .expect("validate edilmədi");
+    let AstKind::Decl { value, .. } = &program.expressions[1].kind else {
+        panic!("elan gözlənilirdi");
+    };
+    assert!(matches!(
+        &value.kind,
+        ValidatorExprKind::Index { element_type, .. }
+            if *element_type == Type::String(parser::shared_ast::StringEnum::LiteralConstString)
+    ));
+}
+
+#[test]
+fn test_index_needs_a_list_and_an_integer() {
+    let error = |source| {
+        let ast = parser::parse_file("index.az", source).expect("parse edilmədi");
+        Validator::default().validate(ast).unwrap_err()
+    };
+    assert!(matches!(
+        error("const str s = args()[\"0\"]\n"),
+        ValidatorError::TypeMismatch {
+            expected: Type::Integer,
+            ..
+        }
+    ));
+    assert!(matches!(
+        error("const int n = 5\nconst int m = n[0]\n"),
+        ValidatorError::TypeMismatch {
+            expected: Type::Array(_),
+            found: Type::Integer,
+            ..
+        }
+    ));
+    assert!(matches!(
+        error("exit(len(5))\n"),
+        ValidatorError::InvalidArgumentType { ref name, .. } if name == "len"
+    ));
+}
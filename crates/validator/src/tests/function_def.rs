@@ -1,6 +1,6 @@
-use crate::Validator;
+use crate::{Validator, builtins::builtin_functions};
 use parser::{
-    ast::{Atom, Expr, Parameter, Statement},
+    ast::{Atom, Expr, ExprKind, Parameter, SourceSpan, Statement, StatementKind},
     shared_ast::Type,
 };
 use std::rc::Rc;
@@ -11,21 +11,25 @@ fn make_func(
     params: Vec<Parameter>,
     body: Vec<Statement>,
 ) -> Statement {
-    Statement::FunctionDef {
+    Statement::from(StatementKind::FunctionDef {
         name: Atom::from(name),
         return_typ,
+        return_type_span: SourceSpan::default(),
         params,
         body,
-    }
+        doc: None,
+    })
 }
 
 fn make_decl(name: &str, typ: Type, is_mutable: bool, value: Expr) -> Statement {
-    Statement::Decl {
+    Statement::from(StatementKind::Decl {
         name: Atom::from(name),
         typ: Rc::new(typ),
+        type_span: SourceSpan::default(),
         is_mutable,
         value: Box::new(value),
-    }
+        doc: None,
+    })
 }
 
 #[test]
@@ -99,6 +103,8 @@ fn test_function_decl_parameters_single() {
         name: Atom::from("x"),
         typ: Type::Integer,
         is_pointer: false,
+        span: SourceSpan::default(),
+        type_span: SourceSpan::default(),
     }];
     let stmt = make_func("f", Type::Integer, params, vec![]);
 
@@ -118,16 +124,22 @@ fn test_function_decl_parameters_multiple() {
             name: Atom::from("a"),
             typ: Type::Integer,
             is_pointer: false,
+            span: SourceSpan::default(),
+            type_span: SourceSpan::default(),
         },
         Parameter {
             name: Atom::from("b"),
             typ: Type::String(parser::shared_ast::StringEnum::DynamicString),
             is_pointer: false,
+            span: SourceSpan::default(),
+            type_span: SourceSpan::default(),
         },
         Parameter {
             name: Atom::from("c"),
             typ: Type::Bool,
             is_pointer: false,
+            span: SourceSpan::default(),
+            type_span: SourceSpan::default(),
         },
     ];
     let stmt = make_func("multi", Type::Void, params, vec![]);
@@ -151,6 +163,8 @@ fn test_function_decl_parameter_pointer_flag() {
         name: Atom::from("ptr"),
         typ: Type::Integer,
         is_pointer: true,
+        span: SourceSpan::default(),
+        type_span: SourceSpan::default(),
     }];
     let stmt = make_func("deref", Type::Integer, params, vec![]);
 
@@ -195,7 +209,7 @@ fn test_function_decl_ignores_decl_statements() {
     let mut validator = Validator::default();
     let stmts = vec![
         make_func("f", Type::Integer, vec![], vec![]),
-        make_decl("x", Type::Integer, false, Expr::Number(1)),
+        make_decl("x", Type::Integer, false, Expr::from(ExprKind::Number(1))),
     ];
 
     validator.function_decl(&stmts);
@@ -246,7 +260,7 @@ fn test_validate_with_function_def_and_return_expr() {
         "f",
         Type::Integer,
         vec![],
-        vec![Statement::Expr(Expr::Return(Box::new(Expr::Number(42))))],
+        vec![Statement::from(StatementKind::Expr(Expr::from(ExprKind::Return(Box::new(Expr::from(ExprKind::Number(42)))))))],
     )];
     let result = Validator::default().validate(stmts);
     assert!(result.is_ok());
@@ -272,7 +286,7 @@ fn test_validate_function_def_preserves_functions_in_result() {
 
     assert!(validator.functions.contains_key("foo"));
     assert!(validator.functions.contains_key("bar"));
-    assert_eq!(validator.functions.len(), 2);
+    assert_eq!(validator.functions.len(), 2 + builtin_functions().len());
     assert_eq!(
         validator.functions.get("foo").unwrap().return_type,
         Type::Integer
@@ -299,3 +313,43 @@ fn test_validate_function_def_with_complex_return_type() {
         Type::Array(Box::new(Type::Integer))
     );
 }
+
+#[test]
+fn test_user_defined_main_is_rejected() {
+    let stmt = make_func("main", Type::Void, vec![], vec![]);
+
+    let result = Validator::default().validate(vec![stmt]);
+
+    assert!(matches!(
+        result,
+        Err(crate::errors::ValidatorError::MainFunctionDefined(_))
+    ));
+}
+
+#[test]
+fn test_builtin_function_cannot_be_redefined() {
+    let stmt = make_func("args", Type::Void, vec![], vec![]);
+
+    let result = Validator::default().validate(vec![stmt]);
+
+    assert!(matches!(
+        result,
+        Err(crate::errors::ValidatorError::FunctionAlreadyDefined(_, name, None)) if name == "args"
+    ));
+}
+
+#[test]
+fn test_duplicate_function_points_at_first_definition() {
+    let source = "func f(): int\n    return 1\n\nfunc f(): int\n    return 2\n";
+    let ast = parser::parse_file("dup.az", source).expect("parse edilmədi");
+
+    let result = Validator::default().validate(ast);
+
+    let Err(crate::errors::ValidatorError::FunctionAlreadyDefined(span, name, Some(first))) = result
+    else {
+        panic!("FunctionAlreadyDefined gözlənilirdi, {result:?} alındı");
+    };
+    assert_eq!(name, "f");
+    assert_eq!(first.location().unwrap().line, 1);
+    assert_eq!(span.location().unwrap().line, 4);
+}
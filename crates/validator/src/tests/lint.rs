@@ -0,0 +1,98 @@
+use crate::{
+    Validator,
+    errors::ValidatorError,
+    lint::{Level, Lint, LintLevels},
+};
+
+fn validate(source: &str, lints: LintLevels) -> Result<Validator, ValidatorError> {
+    let ast = parser::parse_file("lint.az", source).expect("parse edilmədi");
+    Validator::with_lints(lints)
+        .validate(ast)
+        .map(|(validator, _)| validator)
+}
+
+#[test]
+fn test_unused_variables_are_warnings_by_default() {
+    let validator = validate(
+        "const int a = 1\nvar int b = 2\nexit(b)\n",
+        LintLevels::default(),
+    )
+    .expect("xəbərdarlıqlar tərtibi dayandırmamalıdır");
+
+    assert_eq!(validator.warnings.len(), 2);
+    assert!(matches!(
+        validator.warnings[0],
+        ValidatorError::NotUsedVariable(_, ref name) if name == "a"
+    ));
+    assert!(matches!(
+        validator.warnings[1],
+        ValidatorError::NeverChangedMuttableVariable(_, ref name) if name == "b"
+    ));
+}
+
+#[test]
+fn test_deny_turns_lint_into_error() {
+    let mut lints = LintLevels::default();
+    lints.set(Lint::Unused, Level::Deny);
+    let error = validate("const int a = 1\nvar int b = 2\nexit(b)\n", lints)
+        .expect_err("-D unused xəta verməlidir");
+
+    assert!(matches!(error, ValidatorError::NotUsedVariable(..)));
+}
+
+#[test]
+fn test_allow_level_silences_lint() {
+    let mut lints = LintLevels::default();
+    assert!(lints.set_by_name("warnings", Level::Allow));
+    assert!(!lints.set_by_name("unknown", Level::Allow));
+    let validator = validate("const int a = 1\n", lints).expect("yoxlama keçməlidir");
+
+    assert!(validator.warnings.is_empty());
+}
+
+#[test]
+fn test_allow_attribute_silences_only_its_statement() {
+    let source = "@allow(unused)\nconst int a = 1\nconst int b = 2\n";
+    let validator = validate(source, LintLevels::default()).expect("yoxlama keçməlidir");
+
+    assert_eq!(validator.warnings.len(), 1);
+    assert!(matches!(
+        validator.warnings[0],
+        ValidatorError::NotUsedVariable(_, ref name) if name == "b"
+    ));
+}
+
+#[test]
+fn test_unknown_lint_in_attribute_is_error() {
+    let error = validate("@allow(unusd)\nconst int a = 1\n", LintLevels::default())
+        .expect_err("naməlum ad qəbul edilməməlidir");
+
+    assert!(matches!(error, ValidatorError::UnknownLint(_, ref name) if name == "unusd"));
+}
+
+#[test]
+fn test_unused_local_in_function_is_warned() {
+    let source = "func f(const int x): int\n    const int a = 1\n    var int b = x\n    return b\nexit(f(1))\n";
+    let validator = validate(source, LintLevels::default()).expect("yoxlama keçməlidir");
+
+    assert_eq!(validator.warnings.len(), 2, "{:?}", validator.warnings);
+    assert!(matches!(
+        validator.warnings[0],
+        ValidatorError::NotUsedVariable(_, ref name) if name == "a"
+    ));
+    assert!(matches!(
+        validator.warnings[1],
+        ValidatorError::NeverChangedMuttableVariable(_, ref name) if name == "b"
+    ));
+}
+
+#[test]
+fn test_function_with_error_is_not_linted() {
+    let source = "func f(): int\n    const int a = 1\n    return c\nexit(f())\n";
+    let errors = validate(source, LintLevels::default())
+        .expect_err("naməlum dəyər xəta verməlidir")
+        .into_errors();
+
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(matches!(errors[0], ValidatorError::UndefinedVariable(..)));
+}
@@ -1,6 +1,10 @@
 mod assignment;
 mod binary_op;
+mod format;
 mod function_call;
 mod function_def;
+mod lint;
+mod recovery;
+mod suggest;
 mod variable_decl;
 mod while_loop;
@@ -0,0 +1,66 @@
+use crate::{Validator, errors::ValidatorError};
+
+fn errors(source: &str) -> Vec<ValidatorError> {
+    let ast = parser::parse_file("recovery.az", source).expect("parse edilmədi");
+    Validator::default()
+        .validate(ast)
+        .expect_err("xəta gözlənilirdi")
+        .into_errors()
+}
+
+#[test]
+fn test_reports_every_independent_error() {
+    let errors = errors("exit(b)\nexit(c)\n");
+    let names: Vec<_> = errors
+        .iter()
+        .map(|e| match e {
+            ValidatorError::UndefinedVariable(_, name, _) => name.as_str(),
+            other => panic!("UndefinedVariable gözlənilirdi, {other:?} alındı"),
+        })
+        .collect();
+    assert_eq!(names, vec!["b", "c"]);
+}
+
+#[test]
+fn test_failed_decl_does_not_cascade() {
+    let errors = errors("const int a = \"x\"\nexit(a + 1)\nexit(c)\n");
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert!(matches!(errors[0], ValidatorError::DeclTypeMismatch { .. }));
+    assert!(matches!(errors[1], ValidatorError::UndefinedVariable(_, ref name, _) if name == "c"));
+}
+
+#[test]
+fn test_continues_inside_function_and_condition_bodies() {
+    let source =
+        "func f(): int\n    exit(b)\n    exit(c)\n    return 1\nif 1 == 1\n    exit(d)\nexit(e)\n";
+    let errors = errors(source);
+    let lines: Vec<_> = errors
+        .iter()
+        .map(|e| e.span().and_then(|span| span.location()).map(|l| l.line))
+        .collect();
+    assert_eq!(lines, vec![Some(2), Some(3), Some(6), Some(7)]);
+}
+
+#[test]
+fn test_single_error_is_not_wrapped() {
+    let ast = parser::parse_file("recovery.az", "exit(b)\n").expect("parse edilmədi");
+    let error = Validator::default().validate(ast).unwrap_err();
+    assert!(matches!(error, ValidatorError::UndefinedVariable(..)));
+}
+
+#[test]
+fn test_empty_multiple_has_no_span() {
+    let error = ValidatorError::Multiple(Vec::new());
+    assert!(error.span().is_none());
+    assert_eq!(error.to_string(), "");
+}
+
+#[test]
+fn test_multiple_takes_the_first_span() {
+    let error = ValidatorError::collect(errors("exit(b)\nexit(c)\n"));
+    let line = error
+        .span()
+        .and_then(|span| span.location())
+        .map(|l| l.line);
+    assert_eq!(line, Some(1));
+}
@@ -0,0 +1,58 @@
+use crate::{Validator, errors::ValidatorError};
+
+fn error(source: &str) -> ValidatorError {
+    let ast = parser::parse_file("suggest.az", source).expect("parse edilmədi");
+    Validator::default()
+        .validate(ast)
+        .expect_err("xəta gözlənilirdi")
+}
+
+#[test]
+fn test_undefined_variable_suggests_similar_name() {
+    let error = error("const int value = 1\nexit(valeu)\n");
+    assert_eq!(
+        error,
+        ValidatorError::UndefinedVariable(
+            error.span().unwrap().clone(),
+            "valeu".to_string(),
+            Some("value".to_string())
+        )
+    );
+}
+
+#[test]
+fn test_undefined_variable_without_similar_name() {
+    let error = error("const int value = 1\nexit(count)\n");
+    assert!(matches!(
+        error,
+        ValidatorError::UndefinedVariable(_, _, None)
+    ));
+}
+
+#[test]
+fn test_function_not_found_suggests_user_and_builtin_functions() {
+    let source = "func square(const int x): int\n    return x * x\nexit(sqaure(2))\n";
+    let ValidatorError::FunctionNotFound(_, name, similar) = error(source) else {
+        panic!("FunctionNotFound gözlənilirdi");
+    };
+    assert_eq!(
+        (name.as_str(), similar.as_deref()),
+        ("sqaure", Some("square"))
+    );
+
+    let ValidatorError::FunctionNotFound(_, _, similar) = error("const str s = evn(\"HOME\")\n")
+    else {
+        panic!("FunctionNotFound gözlənilirdi");
+    };
+    assert_eq!(similar.as_deref(), Some("env"));
+}
+
+#[test]
+fn test_unknown_declared_type_suggests_builtin_type() {
+    let ValidatorError::UnknownType(span, name, similar) = error("const itn a = 1\nexit(a)\n")
+    else {
+        panic!("UnknownType gözlənilirdi");
+    };
+    assert_eq!((span.start, span.end), (6, 9));
+    assert_eq!((name.as_str(), similar.as_deref()), ("itn", Some("int")));
+}
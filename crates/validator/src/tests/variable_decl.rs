@@ -1,8 +1,7 @@
 use crate::Validator;
-#[cfg(test)]
 use crate::errors::ValidatorError;
 use parser::{
-    ast::{Atom, Expr, Symbol},
+    ast::{Atom, Expr, ExprKind, SourceSpan, Symbol},
     shared_ast::{StringEnum, Type},
 };
 use std::{collections::HashMap, rc::Rc};
@@ -23,8 +22,10 @@ fn test_variable_decl_integer() {
     let result = crate::decl::validate_decl(
         Atom::from("x"),
         Rc::new(Type::Integer),
+        SourceSpan::default(),
         false,
-        Expr::Number(42),
+        Expr::from(ExprKind::Number(42)),
+        SourceSpan::default(),
         &mut validator,
     );
     assert!(result.is_ok());
@@ -45,16 +46,25 @@ fn test_variable_decl_already_declared() {
             is_mutable: false,
             is_used: false,
             is_changed: false,
+            span: SourceSpan::default(),
         },
     );
     let result = crate::decl::validate_decl(
         Atom::from("x"),
         Rc::new(Type::Integer),
+        SourceSpan::default(),
         false,
-        Expr::Number(2),
+        Expr::from(ExprKind::Number(2)),
+        SourceSpan::default(),
         &mut validator,
     );
-    assert_eq!(result, Err(ValidatorError::AlreadyDecl("x".to_string())));
+    assert_eq!(
+        result,
+        Err(ValidatorError::AlreadyDecl(
+            SourceSpan::default(),
+            "x".to_string()
+        ))
+    );
 }
 
 // ─── String Declarations ────────────────────────────────────────────────────
@@ -65,8 +75,10 @@ fn test_string_decl_literal() {
     let result = crate::decl::validate_decl(
         Atom::from("s"),
         Rc::new(Type::String(StringEnum::LiteralString)),
+        SourceSpan::default(),
         false,
-        Expr::String(Atom::from("salam")),
+        Expr::from(ExprKind::String(Atom::from("salam"))),
+        SourceSpan::default(),
         &mut validator,
     );
     assert!(result.is_ok());
@@ -81,8 +93,12 @@ fn test_string_decl_dynamic() {
     let result = crate::decl::validate_decl(
         Atom::from("s"),
         Rc::new(Type::String(StringEnum::DynamicString)),
+        SourceSpan::default(),
         false,
-        Expr::DynamicString(Rc::new("dynamic salam".to_string())),
+        Expr::from(ExprKind::DynamicString(Rc::new(
+            "dynamic salam".to_string(),
+        ))),
+        SourceSpan::default(),
         &mut validator,
     );
     assert!(result.is_ok());
@@ -97,13 +113,16 @@ fn test_string_decl_type_mismatch_int() {
     let result = crate::decl::validate_decl(
         Atom::from("s"),
         Rc::new(Type::Integer),
+        SourceSpan::default(),
         false,
-        Expr::String(Atom::from("salam")),
+        Expr::from(ExprKind::String(Atom::from("salam"))),
+        SourceSpan::default(),
         &mut validator,
     );
     assert_eq!(
         result,
         Err(ValidatorError::DeclTypeMismatch {
+            span: SourceSpan::default(),
             name: "s".to_string(),
             expected: Type::Integer.to_string(),
             found: Type::String(StringEnum::LiteralString).to_string(),
@@ -121,16 +140,25 @@ fn test_variable_decl_already_declared_string() {
             is_mutable: false,
             is_used: false,
             is_changed: false,
+            span: SourceSpan::default(),
         },
     );
     let result = crate::decl::validate_decl(
         Atom::from("s"),
         Rc::new(Type::String(StringEnum::LiteralString)),
+        SourceSpan::default(),
         false,
-        Expr::String(Atom::from("bir")),
+        Expr::from(ExprKind::String(Atom::from("bir"))),
+        SourceSpan::default(),
         &mut validator,
     );
-    assert_eq!(result, Err(ValidatorError::AlreadyDecl("s".to_string())));
+    assert_eq!(
+        result,
+        Err(ValidatorError::AlreadyDecl(
+            SourceSpan::default(),
+            "s".to_string()
+        ))
+    );
 }
 
 // ─── Various Type Declarations ──────────────────────────────────────────────
@@ -141,8 +169,10 @@ fn test_decl_bool() {
     let result = crate::decl::validate_decl(
         Atom::from("b"),
         Rc::new(Type::Bool),
+        SourceSpan::default(),
         false,
-        Expr::Bool(true),
+        Expr::from(ExprKind::Bool(true)),
+        SourceSpan::default(),
         &mut validator,
     );
     assert!(result.is_ok());
@@ -154,8 +184,10 @@ fn test_decl_float() {
     let result = crate::decl::validate_decl(
         Atom::from("f"),
         Rc::new(Type::Float),
+        SourceSpan::default(),
         false,
-        Expr::Float(3.15),
+        Expr::from(ExprKind::Float(3.15)),
+        SourceSpan::default(),
         &mut validator,
     );
     assert!(result.is_ok());
@@ -167,8 +199,10 @@ fn test_decl_char() {
     let result = crate::decl::validate_decl(
         Atom::from("c"),
         Rc::new(Type::Char),
+        SourceSpan::default(),
         false,
-        Expr::Char('A'),
+        Expr::from(ExprKind::Char('A')),
+        SourceSpan::default(),
         &mut validator,
     );
     assert!(result.is_ok());
@@ -180,8 +214,10 @@ fn test_decl_natural() {
     let result = crate::decl::validate_decl(
         Atom::from("n"),
         Rc::new(Type::Natural),
+        SourceSpan::default(),
         false,
-        Expr::Number(5),
+        Expr::from(ExprKind::Number(5)),
+        SourceSpan::default(),
         &mut validator,
     );
     assert!(result.is_ok());
@@ -193,8 +229,10 @@ fn test_decl_any() {
     let result = crate::decl::validate_decl(
         Atom::from("a"),
         Rc::new(Type::Any),
+        SourceSpan::default(),
         false,
-        Expr::Number(42),
+        Expr::from(ExprKind::Number(42)),
+        SourceSpan::default(),
         &mut validator,
     );
     assert!(result.is_ok());
@@ -209,8 +247,10 @@ fn test_decl_any_with_string() {
     let result = crate::decl::validate_decl(
         Atom::from("a"),
         Rc::new(Type::Any),
+        SourceSpan::default(),
         false,
-        Expr::String(Atom::from("any string")),
+        Expr::from(ExprKind::String(Atom::from("any string"))),
+        SourceSpan::default(),
         &mut validator,
     );
     assert!(result.is_ok());
@@ -225,17 +265,26 @@ fn test_decl_big_integer() {
     let result = crate::decl::validate_decl(
         Atom::from("bi"),
         Rc::new(Type::BigInteger),
+        SourceSpan::default(),
         false,
-        Expr::Number(999),
+        Expr::from(ExprKind::Number(999)),
+        SourceSpan::default(),
         &mut validator,
     );
+    let Ok(crate::ast::Ast {
+        kind: crate::ast::AstKind::Decl { typ, value, .. },
+        ..
+    }) = result
+    else {
+        panic!("bigint elanı gözlənilirdi: {result:?}");
+    };
+    assert_eq!(typ, Type::BigInteger);
     assert_eq!(
-        result,
-        Err(ValidatorError::DeclTypeMismatch {
-            name: "bi".to_string(),
-            expected: Type::BigInteger.to_string(),
-            found: Type::Integer.to_string(),
-        })
+        value.kind,
+        crate::ast::ExprKind::TypedNumber {
+            value: 999,
+            typ: Type::BigInteger
+        }
     );
 }
 
@@ -245,17 +294,30 @@ fn test_decl_low_integer() {
     let result = crate::decl::validate_decl(
         Atom::from("li"),
         Rc::new(Type::LowInteger),
+        SourceSpan::default(),
         false,
-        Expr::Number(1),
+        Expr::from(ExprKind::Number(1)),
+        SourceSpan::default(),
+        &mut validator,
+    );
+    assert!(result.is_ok());
+
+    let result = crate::decl::validate_decl(
+        Atom::from("li2"),
+        Rc::new(Type::LowInteger),
+        SourceSpan::default(),
+        false,
+        Expr::from(ExprKind::Number(300)),
+        SourceSpan::default(),
         &mut validator,
     );
     assert_eq!(
         result,
-        Err(ValidatorError::DeclTypeMismatch {
-            name: "li".to_string(),
-            expected: Type::LowInteger.to_string(),
-            found: Type::Integer.to_string(),
-        })
+        Err(ValidatorError::NumberOutOfRange(
+            SourceSpan::default(),
+            300,
+            Type::LowInteger
+        ))
     );
 }
 //
@@ -267,13 +329,16 @@ fn test_decl_type_mismatch_int_bool() {
     let result = crate::decl::validate_decl(
         Atom::from("x"),
         Rc::new(Type::Integer),
+        SourceSpan::default(),
         false,
-        Expr::Bool(true),
+        Expr::from(ExprKind::Bool(true)),
+        SourceSpan::default(),
         &mut validator,
     );
     assert_eq!(
         result,
         Err(ValidatorError::DeclTypeMismatch {
+            span: SourceSpan::default(),
             name: "x".to_string(),
             expected: Type::Integer.to_string(),
             found: Type::Bool.to_string(),
@@ -287,13 +352,16 @@ fn test_decl_type_mismatch_bool_int() {
     let result = crate::decl::validate_decl(
         Atom::from("b"),
         Rc::new(Type::Bool),
+        SourceSpan::default(),
         false,
-        Expr::Number(1),
+        Expr::from(ExprKind::Number(1)),
+        SourceSpan::default(),
         &mut validator,
     );
     assert_eq!(
         result,
         Err(ValidatorError::DeclTypeMismatch {
+            span: SourceSpan::default(),
             name: "b".to_string(),
             expected: Type::Bool.to_string(),
             found: Type::Integer.to_string(),
@@ -307,13 +375,16 @@ fn test_decl_type_mismatch_int_float() {
     let result = crate::decl::validate_decl(
         Atom::from("x"),
         Rc::new(Type::Integer),
+        SourceSpan::default(),
         false,
-        Expr::Float(1.5),
+        Expr::from(ExprKind::Float(1.5)),
+        SourceSpan::default(),
         &mut validator,
     );
     assert_eq!(
         result,
         Err(ValidatorError::DeclTypeMismatch {
+            span: SourceSpan::default(),
             name: "x".to_string(),
             expected: Type::Integer.to_string(),
             found: Type::Float.to_string(),
@@ -327,13 +398,16 @@ fn test_decl_type_mismatch_bool_string() {
     let result = crate::decl::validate_decl(
         Atom::from("b"),
         Rc::new(Type::Bool),
+        SourceSpan::default(),
         false,
-        Expr::String(Atom::from("salam")),
+        Expr::from(ExprKind::String(Atom::from("salam"))),
+        SourceSpan::default(),
         &mut validator,
     );
     assert_eq!(
         result,
         Err(ValidatorError::DeclTypeMismatch {
+            span: SourceSpan::default(),
             name: "b".to_string(),
             expected: Type::Bool.to_string(),
             found: Type::String(StringEnum::LiteralString).to_string(),
@@ -347,13 +421,16 @@ fn test_decl_type_mismatch_float_bool() {
     let result = crate::decl::validate_decl(
         Atom::from("f"),
         Rc::new(Type::Float),
+        SourceSpan::default(),
         false,
-        Expr::Bool(false),
+        Expr::from(ExprKind::Bool(false)),
+        SourceSpan::default(),
         &mut validator,
     );
     assert_eq!(
         result,
         Err(ValidatorError::DeclTypeMismatch {
+            span: SourceSpan::default(),
             name: "f".to_string(),
             expected: Type::Float.to_string(),
             found: Type::Bool.to_string(),
@@ -367,13 +444,16 @@ fn test_decl_type_mismatch_char_int() {
     let result = crate::decl::validate_decl(
         Atom::from("c"),
         Rc::new(Type::Char),
+        SourceSpan::default(),
         false,
-        Expr::Number(65),
+        Expr::from(ExprKind::Number(65)),
+        SourceSpan::default(),
         &mut validator,
     );
     assert_eq!(
         result,
         Err(ValidatorError::DeclTypeMismatch {
+            span: SourceSpan::default(),
             name: "c".to_string(),
             expected: Type::Char.to_string(),
             found: Type::Integer.to_string(),
@@ -389,8 +469,14 @@ fn test_array_decl_integer() {
     let result = crate::decl::validate_decl(
         Atom::from("arr"),
         Rc::new(Type::Array(Box::new(Type::Integer))),
+        SourceSpan::default(),
         false,
-        Expr::List(vec![Expr::Number(1), Expr::Number(2), Expr::Number(3)]),
+        Expr::from(ExprKind::List(vec![
+            Expr::from(ExprKind::Number(1)),
+            Expr::from(ExprKind::Number(2)),
+            Expr::from(ExprKind::Number(3)),
+        ])),
+        SourceSpan::default(),
         &mut validator,
     );
     assert!(result.is_ok());
@@ -405,8 +491,10 @@ fn test_array_decl_empty() {
     let result = crate::decl::validate_decl(
         Atom::from("arr"),
         Rc::new(Type::Array(Box::new(Type::Any))),
+        SourceSpan::default(),
         false,
-        Expr::List(vec![]),
+        Expr::from(ExprKind::List(vec![])),
+        SourceSpan::default(),
         &mut validator,
     );
     assert!(result.is_ok());
@@ -423,11 +511,13 @@ fn test_array_decl_string() {
         Rc::new(Type::Array(Box::new(Type::String(
             StringEnum::LiteralString,
         )))),
+        SourceSpan::default(),
         false,
-        Expr::List(vec![
-            Expr::String(Atom::from("a")),
-            Expr::String(Atom::from("b")),
-        ]),
+        Expr::from(ExprKind::List(vec![
+            Expr::from(ExprKind::String(Atom::from("a"))),
+            Expr::from(ExprKind::String(Atom::from("b"))),
+        ])),
+        SourceSpan::default(),
         &mut validator,
     );
     assert!(result.is_ok());
@@ -439,8 +529,13 @@ fn test_array_decl_bool() {
     let result = crate::decl::validate_decl(
         Atom::from("arr"),
         Rc::new(Type::Array(Box::new(Type::Bool))),
+        SourceSpan::default(),
         false,
-        Expr::List(vec![Expr::Bool(true), Expr::Bool(false)]),
+        Expr::from(ExprKind::List(vec![
+            Expr::from(ExprKind::Bool(true)),
+            Expr::from(ExprKind::Bool(false)),
+        ])),
+        SourceSpan::default(),
         &mut validator,
     );
     assert!(result.is_ok());
@@ -452,8 +547,13 @@ fn test_array_decl_float() {
     let result = crate::decl::validate_decl(
         Atom::from("arr"),
         Rc::new(Type::Array(Box::new(Type::Float))),
+        SourceSpan::default(),
         false,
-        Expr::List(vec![Expr::Float(1.1), Expr::Float(2.2)]),
+        Expr::from(ExprKind::List(vec![
+            Expr::from(ExprKind::Float(1.1)),
+            Expr::from(ExprKind::Float(2.2)),
+        ])),
+        SourceSpan::default(),
         &mut validator,
     );
     assert!(result.is_ok());
@@ -465,13 +565,19 @@ fn test_array_decl_mixed_type_items() {
     let result = crate::decl::validate_decl(
         Atom::from("arr"),
         Rc::new(Type::Array(Box::new(Type::Integer))),
+        SourceSpan::default(),
         false,
-        Expr::List(vec![Expr::Number(1), Expr::String(Atom::from("x"))]),
+        Expr::from(ExprKind::List(vec![
+            Expr::from(ExprKind::Number(1)),
+            Expr::from(ExprKind::String(Atom::from("x"))),
+        ])),
+        SourceSpan::default(),
         &mut validator,
     );
     assert_eq!(
         result,
         Err(ValidatorError::TypeMismatch {
+            span: SourceSpan::default(),
             expected: Type::Integer,
             found: Type::String(StringEnum::LiteralString),
         })
@@ -484,13 +590,16 @@ fn test_array_decl_type_mismatch() {
     let result = crate::decl::validate_decl(
         Atom::from("arr"),
         Rc::new(Type::Integer),
+        SourceSpan::default(),
         false,
-        Expr::List(vec![Expr::Number(1)]),
+        Expr::from(ExprKind::List(vec![Expr::from(ExprKind::Number(1))])),
+        SourceSpan::default(),
         &mut validator,
     );
     assert_eq!(
         result,
         Err(ValidatorError::DeclTypeMismatch {
+            span: SourceSpan::default(),
             name: "arr".to_string(),
             expected: Type::Integer.to_string(),
             found: Type::Array(Box::new(Type::Integer)).to_string(),
@@ -2,7 +2,7 @@
 mod tests {
     use crate::{Validator, errors::ValidatorError};
     use parser::{
-        ast::{Atom, Expr, Statement},
+        ast::{Atom, Expr, ExprKind, SourceSpan, Statement, StatementKind},
         shared_ast::Type,
     };
     use std::rc::Rc;
@@ -10,10 +10,10 @@ mod tests {
     #[test]
     fn test_while_loop_valid_bool_condition() {
         let validator = Validator::default();
-        let program = vec![Statement::While {
-            condition: Box::new(Expr::Bool(true)),
-            body: vec![Statement::Expr(Expr::Number(1))],
-        }];
+        let program = vec![Statement::from(StatementKind::While {
+            condition: Box::new(Expr::from(ExprKind::Bool(true))),
+            body: vec![Statement::from(StatementKind::Expr(Expr::from(ExprKind::Number(1))))],
+        })];
         let result = validator.validate(program);
         assert!(result.is_ok());
     }
@@ -21,10 +21,10 @@ mod tests {
     #[test]
     fn test_while_loop_invalid_condition_type() {
         let validator = Validator::default();
-        let program = vec![Statement::While {
-            condition: Box::new(Expr::Number(42)),
+        let program = vec![Statement::from(StatementKind::While {
+            condition: Box::new(Expr::from(ExprKind::Number(42))),
             body: vec![],
-        }];
+        })];
         let result = validator.validate(program);
         assert!(matches!(
             result,
@@ -36,39 +36,41 @@ mod tests {
     fn test_while_loop_body_with_decl() {
         let validator = Validator::default();
         let program = vec![
-            Statement::Decl {
+            Statement::from(StatementKind::Decl {
                 name: Atom::from("x"),
                 typ: Rc::new(Type::Integer),
+                type_span: SourceSpan::default(),
                 is_mutable: true,
-                value: Box::new(Expr::Number(0)),
-            },
-            Statement::While {
-                condition: Box::new(Expr::BinaryOp {
-                    left: Box::new(Expr::VariableRef {
+                value: Box::new(Expr::from(ExprKind::Number(0))),
+                doc: None,
+            }),
+            Statement::from(StatementKind::While {
+                condition: Box::new(Expr::from(ExprKind::BinaryOp {
+                    left: Box::new(Expr::from(ExprKind::VariableRef {
                         name: Atom::from("x"),
                         symbol: None,
-                    }),
-                    right: Box::new(Expr::Number(5)),
+                    })),
+                    right: Box::new(Expr::from(ExprKind::Number(5))),
                     op: parser::ast::Operation::Less,
-                }),
+                })),
                 body: vec![
-                    Statement::Assignment {
+                    Statement::from(StatementKind::Assignment {
                         name: Atom::from("x"),
-                        value: Box::new(Expr::BinaryOp {
-                            left: Box::new(Expr::VariableRef {
+                        value: Box::new(Expr::from(ExprKind::BinaryOp {
+                            left: Box::new(Expr::from(ExprKind::VariableRef {
                                 name: Atom::from("x"),
                                 symbol: None,
-                            }),
-                            right: Box::new(Expr::Number(1)),
+                            })),
+                            right: Box::new(Expr::from(ExprKind::Number(1))),
                             op: parser::ast::Operation::Add,
-                        }),
-                    },
+                        })),
+                    }),
                 ],
-            },
-            Statement::Expr(Expr::VariableRef {
+            }),
+            Statement::from(StatementKind::Expr(Expr::from(ExprKind::VariableRef {
                 name: Atom::from("x"),
                 symbol: None,
-            }),
+            }))),
         ];
         let result = validator.validate(program);
         assert!(result.is_ok());
@@ -1,120 +1,112 @@
 use std::{collections::HashMap, rc::Rc};
 
 use parser::{
-    ast::{Statement, Symbol},
+    ast::{Statement, StatementKind, Symbol},
     shared_ast::{StringEnum, Type},
 };
 type ValidatorExpr = crate::ast::Expr;
 use crate::{
     Validator,
-    ast::{self, Ast, Else, Function, IF},
+    ast::{self, Ast, AstKind, Else, ExprKind, Function, IF},
     decl,
     errors::ValidatorError,
     expr::validate_expr,
-    helper::{get_type, type_checking},
+    helper::{get_type, type_checking, validate_body},
 };
 pub fn validate_statement(stmt: Statement, ctx: &mut Validator) -> Result<Ast, ValidatorError> {
-    match stmt {
-        Statement::Decl {
+    let span = stmt.span;
+    let kind = match stmt.kind {
+        StatementKind::Decl {
             name,
             typ,
+            type_span,
             is_mutable,
             value,
-        } => decl::validate_decl(name, typ, is_mutable, *value, ctx),
-        Statement::Assignment { name, value } => {
+            ..
+        } => {
+            return decl::validate_decl(name, typ, type_span, is_mutable, *value, span, ctx);
+        }
+        StatementKind::Assignment { name, value } => {
             let inferred = get_type(&value, ctx)?;
-            let symbol = ctx.lookup_variable_mut_with_err(name.as_ref())?;
+            let symbol = ctx.lookup_variable_mut_with_err(name.as_ref(), &span)?;
             symbol.is_changed = true;
             if !symbol.is_mutable {
                 return Err(ValidatorError::AssignmentToImmutableVariable(
+                    span,
                     name.to_string(),
                 ));
             }
 
-            type_checking(symbol.typ.clone(), inferred)?;
+            type_checking(symbol.typ.clone(), inferred, &value.span)?;
 
             let val = validate_expr(*value, ctx)?;
 
-            Ok(Ast::Assignment {
+            AstKind::Assignment {
                 name: name.to_string(),
                 value: Box::new(val),
-            })
+            }
         }
 
-        Statement::Condition { main, elif, other } => {
+        StatementKind::Condition { main, elif, other } => {
             let condition = validate_expr(*main.condition, ctx)?;
-            let mut validated_body = Vec::new();
-            for stmt in main.body {
-                validated_body.push(validate_statement(stmt, ctx)?);
-            }
             let validated_main = IF {
                 condition: Box::new(condition),
-                body: validated_body,
+                body: validate_body(main.body, ctx),
             };
 
             let mut validated_elif = Vec::new();
             for branch in elif {
                 let branch_condition = validate_expr(*branch.condition, ctx)?;
-                let mut branch_body = Vec::new();
-                for stmt in branch.body {
-                    branch_body.push(validate_statement(stmt, ctx)?);
-                }
                 validated_elif.push(IF {
                     condition: Box::new(branch_condition),
-                    body: branch_body,
+                    body: validate_body(branch.body, ctx),
                 });
             }
 
-            let validated_other = other
-                .map(|o| {
-                    let mut body = Vec::new();
-                    for stmt in o.body {
-                        body.push(validate_statement(stmt, ctx)?);
-                    }
-                    Ok::<Else, ValidatorError>(Else { body })
-                })
-                .transpose()?;
+            let validated_other = other.map(|o| Else {
+                body: validate_body(o.body, ctx),
+            });
 
-            Ok(Ast::Condition {
+            AstKind::Condition {
                 main: validated_main,
                 elif: validated_elif,
                 other: validated_other,
-            })
+            }
         }
-        Statement::Exit(e) => {
+        StatementKind::Exit(e) => {
             let typ = get_type(&e, ctx)?;
-            type_checking(typ, Type::Integer)?;
+            type_checking(Type::Integer, typ, &e.span)?;
             let expr = validate_expr(e, ctx)?;
-            Ok(Ast::Exit(expr))
+            AstKind::Exit(expr)
         }
-        Statement::While { condition, body } => {
+        StatementKind::While { condition, body } => {
             let condition_type = get_type(&condition, ctx)?;
-            if condition_type != Type::Bool {
+            if condition_type != Type::Bool && condition_type != Type::Error {
                 return Err(ValidatorError::TypeMismatch {
+                    span: condition.span,
                     expected: Type::Bool,
                     found: condition_type,
                 });
             }
             let condition = validate_expr(*condition, ctx)?;
-            let mut validated_body = Vec::new();
-            for stmt in body {
-                validated_body.push(validate_statement(stmt, ctx)?);
-            }
-            Ok(Ast::While {
+            AstKind::While {
                 condition: Box::new(condition),
-                body: validated_body,
-            })
+                body: validate_body(body, ctx),
+            }
         }
-        Statement::Expr(expr) => {
+        StatementKind::Expr(expr) => {
             let expr = validate_expr(expr, ctx)?;
-            Ok(Ast::Expr(expr))
+            AstKind::Expr(expr)
+        }
+        StatementKind::EnumDecl { .. }
+        | StatementKind::FunctionDef { .. }
+        | StatementKind::StructDef { .. }
+        | StatementKind::UnionType { .. }
+        | StatementKind::Match { .. }
+        | StatementKind::Loop { .. }
+        | StatementKind::ExternalFunctionDef { .. } => {
+            AstKind::Expr(ValidatorExpr::new(ExprKind::Void, span.clone()))
         }
-        Statement::EnumDecl { .. }
-        | Statement::FunctionDef { .. }
-        | Statement::StructDef { .. }
-        | Statement::UnionType { .. }
-        | Statement::Match { .. }
-        | Statement::Loop { .. }
-        | Statement::ExternalFunctionDef { .. } => Ok(Ast::Expr(ValidatorExpr::Void)),
-    }
+    };
+    Ok(Ast::new(kind, span))
 }
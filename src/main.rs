@@ -1,17 +1,107 @@
-use cli::{Commands, cli};
-use compiler::compiler;
+use cli::{Color, Commands, Emit, Keywords, Language, LintLevel, MessageFormat, Overflow, cli};
+use compiler::{
+    ColorChoice, CompilerOptions, KeywordSet, Lang, Level, LintLevels, OverflowMode, compiler,
+    demangle_text, explain, format_keywords, set_lang, t,
+};
 
 fn main() {
-    let command = cli().command;
-    match command {
-        Commands::Build { binary } => {
-            compiler(&binary).unwrap_or_else(|err| {
-                err.display();
+    let cli = cli();
+    let lang = match cli.lang {
+        Some(Language::Az) => Lang::Az,
+        Some(Language::En) => Lang::En,
+        None => Lang::from_env().unwrap_or_default(),
+    };
+    set_lang(lang);
+    let keywords = keyword_set(cli.keywords);
+    let color = match cli.color {
+        Color::Auto => ColorChoice::Auto,
+        Color::Always => ColorChoice::Always,
+        Color::Never => ColorChoice::Never,
+    };
+    match cli.command {
+        Commands::Build {
+            binary,
+            overflow,
+            lints,
+            message_format,
+            emit,
+            ..
+        } => {
+            let message_format = match message_format {
+                MessageFormat::Human => compiler::MessageFormat::Human,
+                MessageFormat::Json => compiler::MessageFormat::Json,
+            };
+            let mut levels = LintLevels::default();
+            for (level, name) in lints {
+                let level = match level {
+                    LintLevel::Allow => Level::Allow,
+                    LintLevel::Warn => Level::Warn,
+                    LintLevel::Deny => Level::Deny,
+                };
+                if !levels.set_by_name(&name, level) {
+                    println!("{}", t!("cli.unknown-lint", name = name));
+                    std::process::exit(2);
+                }
+            }
+            let options = CompilerOptions {
+                overflow: match overflow {
+                    Overflow::Trap => OverflowMode::Trap,
+                    Overflow::Wrap => OverflowMode::Wrap,
+                },
+                lints: levels,
+                color,
+                message_format,
+                keywords,
+                emit: match emit {
+                    Emit::Exe => compiler::Emit::Exe,
+                    Emit::Asm => compiler::Emit::Asm,
+                },
+            };
+            compiler(&binary, &options).unwrap_or_else(|err| {
+                match message_format {
+                    compiler::MessageFormat::Human => err.display(color),
+                    compiler::MessageFormat::Json => print!("{}", err.render_json()),
+                }
                 std::process::exit(err.code());
             });
         }
+        Commands::Fmt { file, to } => {
+            let options = CompilerOptions {
+                color,
+                keywords,
+                ..CompilerOptions::default()
+            };
+            format_keywords(&file, keyword_set(to), &options).unwrap_or_else(|err| {
+                err.display(color);
+                std::process::exit(err.code());
+            });
+        }
+        Commands::Demangle { symbols } if symbols.is_empty() => {
+            for line in std::io::stdin().lines().map_while(Result::ok) {
+                println!("{}", demangle_text(&line));
+            }
+        }
+        Commands::Demangle { symbols } => {
+            for symbol in symbols {
+                println!("{}", demangle_text(&symbol));
+            }
+        }
+        Commands::Explain { code } => match explain(&code) {
+            Some(text) => print!("{text}"),
+            None => {
+                println!("{}", t!("cli.unknown-code", code = code));
+                std::process::exit(2);
+            }
+        },
         Commands::Version {} => {
             println!("Version: 0.0.2");
         }
     }
 }
+
+fn keyword_set(keywords: Keywords) -> KeywordSet {
+    match keywords {
+        Keywords::En => KeywordSet::En,
+        Keywords::Az => KeywordSet::Az,
+    }
+}